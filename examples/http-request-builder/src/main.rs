@@ -2,12 +2,12 @@ use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
 use sycamore::prelude::*;
 use sycamore::web::tags::*;
-use sycamore::web::{Suspense, SuspenseProps};
+use sycamore::web::{create_client_resource, Suspense, SuspenseProps};
 
 // API that counts visits to the web-page
 const API_BASE_URL: &str = "https://abacus.jasoncameron.dev/hit";
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 struct Visits {
     value: u64,
 }
@@ -20,11 +20,28 @@ async fn fetch_visits(id: &str) -> Result<Visits, gloo_net::Error> {
 }
 
 #[component]
-async fn VisitsCount() -> View {
+fn VisitsCount() -> View {
     let id = "sycamore-builder-visits-counter";
-    let visits = fetch_visits(id).await.unwrap_or_default();
+    let visits = create_client_resource(move || async move {
+        fetch_visits(id).await.map_err(|err| err.to_string())
+    });
 
-    p().children(("Total Visits: ", span().children(move || visits.value)))
+    (
+        p().children((
+            "Total Visits: ",
+            span().children(move || {
+                visits
+                    .data()
+                    .map(|visits| visits.value.to_string())
+                    .unwrap_or_default()
+            }),
+        )),
+        move || {
+            visits
+                .error()
+                .map(|err| View::from(p().children(format!("Failed to fetch visits: {err}"))))
+        },
+    )
         .into()
 }
 
@@ -1,12 +1,12 @@
 use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
 use sycamore::prelude::*;
-use sycamore::web::Suspense;
+use sycamore::web::{create_client_resource, Suspense};
 
 // API that counts visits to the web-page
 const API_BASE_URL: &str = "https://abacus.jasoncameron.dev/hit";
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 struct Visits {
     value: u64,
 }
@@ -19,17 +19,20 @@ async fn fetch_visits(id: &str) -> Result<Visits, gloo_net::Error> {
 }
 
 #[component]
-async fn VisitsCount() -> View {
+fn VisitsCount() -> View {
     let id = "sycamore-visits-counter";
-    let visits = fetch_visits(id).await.unwrap_or_default();
+    let visits = create_client_resource(move || async move {
+        fetch_visits(id).await.map_err(|err| err.to_string())
+    });
 
     view! {
         p {
             "Total visits: "
             span {
-                (visits.value)
+                (visits.data().map(|visits| visits.value.to_string()).unwrap_or_default())
             }
         }
+        (visits.error().map(|err| view! { p { "Failed to fetch visits: " (err) } }))
     }
 }
 
@@ -1,6 +1,6 @@
 use sycamore::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Cat {
     id: &'static str,
     name: &'static str,
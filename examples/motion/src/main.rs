@@ -1,20 +1,23 @@
 use std::time::Duration;
 
 use sycamore::easing;
-use sycamore::motion::{create_raf, create_tweened_signal};
+use sycamore::motion::{create_raf_with_delta, create_tweened_signal};
 use sycamore::prelude::*;
 
 #[component]
 fn CreateRAF() -> View {
     let mut state = create_signal(0i32);
-    let (_running, start, stop) = create_raf(move || {
+    let mut elapsed = create_signal(0.0);
+    let (_running, start, stop, tick_once) = create_raf_with_delta(move |dt| {
         state += 1;
+        elapsed += dt;
     });
     view! {
         div {
-            p { (state) " frames" }
+            p { (state) " frames, " (elapsed.get().round()) "ms" }
             button(on:click=move |_| start()) { "Start" }
             button(on:click=move |_| stop()) { "Stop" }
+            button(on:click=move |_| tick_once()) { "Step" }
         }
     }
 }
@@ -36,9 +36,8 @@ fn App() -> View {
 #[tokio::main]
 async fn main() {
     // Create index.html from template.html and insert the rendered HTML.
-    let html = sycamore::render_to_string_await_suspense(App).await;
-    std::fs::write("index.html", format!("<!DOCTYPE html>{html}"))
-        .expect("failed to write index.html");
+    let html = sycamore::render_to_string_await_suspense_document(App).await;
+    std::fs::write("index.html", html).expect("failed to write index.html");
     println!("Wrote index.html");
 }
 
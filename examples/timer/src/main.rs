@@ -1,21 +1,27 @@
-use gloo_timers::future::TimeoutFuture;
-use sycamore::futures::spawn_local_scoped;
+use std::time::Duration;
+
 use sycamore::prelude::*;
+use sycamore::web::use_interval;
 
 #[component]
 fn TimerCounter() -> View {
     let mut state = create_signal(0);
 
-    spawn_local_scoped(async move {
-        loop {
-            TimeoutFuture::new(1000).await;
-            state += 1;
+    let interval = use_interval(Duration::from_secs(1), move || state += 1);
+    let toggle = move |_| {
+        if interval.is_running() {
+            interval.pause();
+        } else {
+            interval.resume();
         }
-    });
+    };
 
     view! {
         div {
             p { "Value: " (state) }
+            button(on:click=toggle) {
+                (if interval.is_running() { "Pause" } else { "Resume" })
+            }
         }
     }
 }
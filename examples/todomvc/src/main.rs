@@ -11,19 +11,14 @@ pub struct Todo {
     id: Uuid,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Filter {
+    #[default]
     All,
     Active,
     Completed,
 }
 
-impl Default for Filter {
-    fn default() -> Self {
-        Self::All
-    }
-}
-
 impl Filter {
     fn url(self) -> &'static str {
         match self {
@@ -212,7 +207,7 @@ pub fn Header() -> View {
 pub fn Item(todo: Signal<Todo>) -> View {
     let app_state = use_context::<AppState>();
 
-    let title = move || todo.with(|todo| todo.title.clone());
+    let title = todo.map_ref(|todo| &todo.title);
     let completed = create_selector(move || todo.with(|todo| todo.completed));
     let id = todo.with(|todo| todo.id);
 
@@ -229,7 +224,7 @@ pub fn Item(todo: Signal<Todo>) -> View {
             .unchecked_into::<HtmlInputElement>()
             .focus()
             .unwrap();
-        input_value.set(title());
+        input_value.set(title.get_clone());
     };
 
     let handle_blur = move || {
@@ -10,14 +10,21 @@ use std::task::{Context, Poll};
 
 use futures::future::abortable;
 use futures::stream::Abortable;
-use futures::Future;
+use futures::{Future, Stream, StreamExt};
 use pin_project::pin_project;
-use sycamore_reactive::{on_cleanup, use_current_scope, NodeHandle};
+use sycamore_reactive::{
+    create_effect, create_signal, on_cleanup, use_current_scope, NodeHandle, ReadSignal,
+};
 
 pub use self::suspense::*;
 
 /// If running on `wasm32` target, does nothing. Otherwise creates a new `tokio::task::LocalSet`
 /// scope.
+///
+/// On the server, any tasks spawned with [`spawn_local`] or [`spawn_local_scoped`] must be spawned
+/// inside such a scope. Note that `render_to_string_await_suspense` already does this internally so
+/// it is not necessary to call this function yourself when using it. Nesting executor scopes is
+/// allowed.
 pub async fn provide_executor_scope<U>(fut: impl Future<Output = U>) -> U {
     #[cfg(all(target_arch = "wasm32", not(sycamore_force_ssr)))]
     {
@@ -34,6 +41,11 @@ pub async fn provide_executor_scope<U>(fut: impl Future<Output = U>) -> U {
 ///
 /// This will not auto cancel the task if the scope in which it is created is destroyed.
 /// For this purpose, use [`spawn_local_scoped`] instead.
+///
+/// # Panics
+///
+/// When not running on `wasm32`, this panics if called outside of a `tokio::task::LocalSet`. See
+/// [`provide_executor_scope`].
 pub fn spawn_local(fut: impl Future<Output = ()> + 'static) {
     #[cfg(any(not(target_arch = "wasm32"), sycamore_force_ssr))]
     tokio::task::spawn_local(fut);
@@ -46,12 +58,102 @@ pub fn spawn_local(fut: impl Future<Output = ()> + 'static) {
 /// If the scope is destroyed before the future is completed, it is aborted immediately. This
 /// ensures that it is impossible to access any values referencing the scope after they are
 /// destroyed.
+///
+/// # Panics
+///
+/// When not running on `wasm32`, this panics if called outside of a `tokio::task::LocalSet`. See
+/// [`provide_executor_scope`].
 #[cfg_attr(debug_assertions, track_caller)]
 pub fn spawn_local_scoped(fut: impl Future<Output = ()> + 'static) {
     let scoped = ScopedFuture::new_in_current_scope(fut);
     spawn_local(scoped);
 }
 
+/// Creates a memo whose value is computed asynchronously.
+///
+/// `f` is called immediately to create a future, and is called again to create a new future
+/// whenever any of the signals accessed synchronously in `f` change, just like with
+/// [`create_memo`](sycamore_reactive::create_memo). Signals that are only accessed inside the
+/// future are not tracked. Use [`on`](sycamore_reactive::on) to make the dependencies explicit.
+///
+/// The value is `None` until the first future resolves and is then updated with the output of
+/// every future. While a new future is pending, the previous value is kept. If `f` is called again
+/// before the previous future has resolved, the previous future is aborted so that a stale value
+/// can never overwrite a newer one.
+///
+/// Unlike the resources in `sycamore-web`, this does not integrate with suspense.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore_futures::*;
+/// # use sycamore_reactive::*;
+/// # async fn fetch_user(id: u32) -> String { String::new() }
+/// # let _ = create_root(|| {
+/// let id = create_signal(1);
+/// let user = create_async_memo(on(id, move || fetch_user(id.get())));
+/// # });
+/// ```
+///
+/// # Panics
+///
+/// When not running on `wasm32`, this panics if called outside of a `tokio::task::LocalSet`. See
+/// [`provide_executor_scope`].
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_async_memo<T, Fut>(mut f: impl FnMut() -> Fut + 'static) -> ReadSignal<Option<T>>
+where
+    T: 'static,
+    Fut: Future<Output = T> + 'static,
+{
+    let value = create_signal(None);
+    create_effect(move || {
+        let fut = f();
+        // The future is spawned in the scope of the effect, so it is aborted when the effect is
+        // re-run.
+        spawn_local_scoped(async move {
+            let new = fut.await;
+            value.set(Some(new));
+        });
+    });
+    *value
+}
+
+/// Creates a signal that is updated with every item of `stream`, e.g. the messages received from a
+/// WebSocket.
+///
+/// The signal holds `initial` until the first item arrives. The stream is polled in a task that is
+/// spawned on the current scope, so it is dropped when the scope is disposed. Once the stream
+/// ends, the signal keeps the last item.
+///
+/// # Example
+/// ```no_run
+/// # use futures::channel::mpsc;
+/// # use sycamore_futures::*;
+/// # use sycamore_reactive::*;
+/// # let _ = create_root(|| {
+/// let (_tx, rx) = mpsc::unbounded::<String>();
+/// let last_message = create_signal_from_stream(String::new(), rx);
+/// # });
+/// ```
+///
+/// # Panics
+///
+/// When not running on `wasm32`, this panics if called outside of a `tokio::task::LocalSet`. See
+/// [`provide_executor_scope`].
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_signal_from_stream<T: 'static>(
+    initial: T,
+    stream: impl Stream<Item = T> + 'static,
+) -> ReadSignal<T> {
+    let value = create_signal(initial);
+    spawn_local_scoped(async move {
+        futures::pin_mut!(stream);
+        while let Some(item) = stream.next().await {
+            value.set(item);
+        }
+    });
+    *value
+}
+
 /// A wrapper that runs the future on the current scope.
 #[pin_project]
 struct ScopedFuture<T> {
@@ -83,3 +185,73 @@ impl<T: Future> ScopedFuture<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use futures::channel::{mpsc, oneshot};
+    use sycamore_reactive::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn async_memo_aborts_stale_future() {
+        let (tx1, rx1) = oneshot::channel();
+        let (tx2, rx2) = oneshot::channel();
+        let receivers = Rc::new(RefCell::new(vec![rx2, rx1]));
+
+        let local = tokio::task::LocalSet::new();
+        let ((id, memo), root) = local
+            .run_until(async {
+                create_root_get(move || {
+                    let id = create_signal(0);
+                    let memo = create_async_memo(move || {
+                        id.track();
+                        let rx = receivers.borrow_mut().pop().unwrap();
+                        async move { rx.await.unwrap() }
+                    });
+                    (id, memo)
+                })
+            })
+            .await;
+        root.run_in(|| assert_eq!(memo.get(), None));
+
+        // Re-running the memo aborts the first future.
+        local.run_until(async { root.run_in(|| id.set(1)) }).await;
+        tx2.send(2).unwrap();
+        local.await;
+
+        root.run_in(|| assert_eq!(memo.get(), Some(2)));
+        assert!(
+            tx1.send(1).is_err(),
+            "first future should have been dropped"
+        );
+        root.dispose();
+    }
+
+    #[tokio::test]
+    async fn signal_from_stream() {
+        let (tx, rx) = mpsc::unbounded();
+
+        let local = tokio::task::LocalSet::new();
+        let (signal, root) = local
+            .run_until(async { create_root_get(move || create_signal_from_stream(0, rx)) })
+            .await;
+        root.run_in(|| assert_eq!(signal.get(), 0));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        local.run_until(tokio::task::yield_now()).await;
+        root.run_in(|| assert_eq!(signal.get(), 2));
+
+        // The stream is dropped when the scope is disposed.
+        root.dispose();
+        local.run_until(tokio::task::yield_now()).await;
+        assert!(
+            tx.unbounded_send(3).is_err(),
+            "stream should have been dropped"
+        );
+    }
+}
@@ -19,7 +19,9 @@ mod props;
 pub fn view(input: TokenStream) -> TokenStream {
     let root = parse_macro_input!(input as sycamore_view_parser::ir::Root);
 
-    sycamore_view_parser::codegen::Codegen {}.root(&root).into()
+    sycamore_view_parser::codegen::Codegen::default()
+        .root(&root)
+        .into()
 }
 
 /// A macro for creating components from functions.
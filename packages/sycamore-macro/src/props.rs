@@ -25,6 +25,7 @@ pub fn impl_derive_props(ast: &DeriveInput) -> Result<TokenStream> {
                     .map(|f| struct_info.required_field_impl(f))
                     .collect::<Result<Vec<_>>>()?;
                 let build_method = struct_info.build_method_impl();
+                let custom_element = struct_info.custom_element_impl();
 
                 quote! {
                     #builder_creation
@@ -32,6 +33,7 @@ pub fn impl_derive_props(ast: &DeriveInput) -> Result<TokenStream> {
                     #( #fields )*
                     #( #required_fields )*
                     #build_method
+                    #custom_element
                 }
             }
             syn::Fields::Unnamed(_) => {
@@ -262,6 +264,13 @@ mod struct_info {
                         fn set_attribute(&mut self, name: &'static ::std::primitive::str, value: impl ::sycamore::web::AttributeValue) {
                             self.attributes.set_attribute(name, value);
                         }
+                        fn set_attribute_owned(
+                            &mut self,
+                            name: ::std::borrow::Cow<'static, ::std::primitive::str>,
+                            value: impl ::sycamore::web::AttributeValue,
+                        ) {
+                            self.attributes.set_attribute_owned(name, value);
+                        }
                         fn set_event_handler(
                             &mut self,
                             name: &'static ::std::primitive::str,
@@ -269,6 +278,20 @@ mod struct_info {
                         ) {
                             self.attributes.set_event_handler(name, handler);
                         }
+                        fn set_event_handler_owned(
+                            &mut self,
+                            name: ::std::borrow::Cow<'static, ::std::primitive::str>,
+                            handler: impl ::std::ops::FnMut(::sycamore::rt::Event) + 'static,
+                        ) {
+                            self.attributes.set_event_handler_owned(name, handler);
+                        }
+                        fn set_event_handler_once(
+                            &mut self,
+                            name: ::std::borrow::Cow<'static, ::std::primitive::str>,
+                            handler: impl ::std::ops::FnMut(::sycamore::rt::Event) + 'static,
+                        ) {
+                            self.attributes.set_event_handler_once(name, handler);
+                        }
                     }
                 }
             } else {
@@ -713,6 +736,87 @@ mod struct_info {
                 }
             )
         }
+
+        /// Implements `CustomElementProps` if `#[prop(custom_element)]` is set on the struct. Every
+        /// field except `children` and skipped fields is read from the attribute with the
+        /// kebab-case name of the field.
+        pub fn custom_element_impl(&self) -> TokenStream {
+            if !self.builder_attr.custom_element {
+                return quote!();
+            }
+            let name = &self.name;
+            let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+            let is_attribute = |field: &FieldInfo| {
+                field.builder_attr.setter.skip.is_none() && field.name != "children"
+            };
+
+            let observed_attributes = self
+                .fields
+                .iter()
+                .filter(|field| is_attribute(field))
+                .map(|field| strip_raw_ident_prefix(field.name.to_string()).replace('_', "-"));
+            let mut predicates = Vec::new();
+            // Fields are assigned in order so that default values can refer to earlier fields,
+            // just like in the `build` method.
+            let assignments = self.fields.iter().map(|field| {
+                let (field_name, ty) = (field.name, field.ty);
+                let default = match &field.builder_attr.default {
+                    Some(default) => quote!(#default),
+                    None => {
+                        predicates.push(quote!(#ty: ::std::default::Default));
+                        quote!(::std::default::Default::default())
+                    }
+                };
+                if is_attribute(field) {
+                    predicates.push(quote!(#ty: ::sycamore::rt::FromAttribute));
+                    let attribute =
+                        strip_raw_ident_prefix(field_name.to_string()).replace('_', "-");
+                    quote! {
+                        let #field_name: #ty = match ::sycamore::rt::FromAttribute::from_attribute_signal(
+                            attribute(#attribute),
+                        ) {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => #default,
+                        };
+                    }
+                } else {
+                    quote!(let #field_name: #ty = #default;)
+                }
+            }).collect::<Vec<_>>();
+            let field_names = self.fields.iter().map(|field| field.name);
+            let attributes_field = if self.attributes.is_some() {
+                quote! { attributes: ::std::default::Default::default(), }
+            } else {
+                quote! {}
+            };
+
+            let mut where_clause = where_clause.cloned().unwrap_or_else(|| syn::WhereClause {
+                where_token: Default::default(),
+                predicates: Default::default(),
+            });
+            for predicate in predicates {
+                where_clause.predicates.push(syn::parse_quote!(#predicate));
+            }
+
+            quote! {
+                impl #impl_generics ::sycamore::rt::CustomElementProps for #name #ty_generics #where_clause {
+                    const OBSERVED_ATTRIBUTES: &'static [&'static ::std::primitive::str] = &[#( #observed_attributes ),*];
+
+                    #[allow(clippy::default_trait_access)]
+                    fn from_attributes(
+                        attribute: &dyn ::std::ops::Fn(
+                            &::std::primitive::str,
+                        ) -> ::sycamore::rt::ReadSignal<::std::option::Option<::std::string::String>>,
+                    ) -> Self {
+                        #( #assignments )*
+                        #name {
+                            #( #field_names, )*
+                            #attributes_field
+                        }
+                    }
+                }
+            }
+        }
     }
 
     #[derive(Debug, Default)]
@@ -731,6 +835,10 @@ mod struct_info {
         /// just specify `doc` instead and a default value will be filled in here.
         pub build_method_doc: Option<syn::Expr>,
 
+        /// Whether to implement `CustomElementProps` so that the props can be created from the
+        /// attributes of a custom element.
+        pub custom_element: bool,
+
         pub field_defaults: FieldBuilderAttr,
     }
 
@@ -785,6 +893,10 @@ mod struct_info {
                             self.doc = true;
                             Ok(())
                         }
+                        "custom_element" => {
+                            self.custom_element = true;
+                            Ok(())
+                        }
                         _ => Err(Error::new_spanned(
                             &path,
                             format!("Unknown parameter {:?}", name),
@@ -851,7 +963,7 @@ mod field_info {
             ordinal: usize,
             field: &syn::Field,
             field_defaults: FieldBuilderAttr,
-        ) -> Result<FieldInfo, Error> {
+        ) -> Result<FieldInfo<'_>, Error> {
             if let Some(ref name) = field.ident {
                 let mut builder_attr = field_defaults.with(&field.attrs)?;
 
@@ -13,6 +13,12 @@ fn compile_pass() {
         let _: View = view! { p("attr-42"="my-value") };
 
         let _: View = view! { button(class="my-btn", on:click=|_| {}) };
+        let _: View = view! { my-element(on:value-changed=|_| {}) };
+        let _: View = view! { my-element(on:valuechanged=|_| {}) };
+
+        let level = 2;
+        let _: View = view! { (dyn_tag(format!("h{level}"))) { "Heading" } };
+        let _: View = view! { (dyn_tag("section"))(class="my-class") };
         let _: View = view! { button(class="my-btn", aria-hidden="true") };
 
         let _: View = view! { p(dangerously_set_inner_html="<span>Test</span>") };
@@ -20,6 +26,21 @@ fn compile_pass() {
         let attributes = Attributes::default();
         let _: View = view! { p(..attributes) };
 
+        // Tags inside `svg` are created in the SVG namespace, except inside `foreignObject`.
+        let _: View = view! {
+            svg {
+                a(href="#") { title { "SVG title" } }
+                style { "circle { fill: red; }" }
+                foreignObject { a(href="#", hreflang="en") { "HTML link" } }
+            }
+        };
+        let _: View = view! {
+            math(display="block") {
+                mfrac { mi { "x" } mn { "2" } }
+                semantics { annotation-xml(encoding="text/html") {} }
+            }
+        };
+
         // view! should correctly parenthesize the (1 + 2) when borrowing.
         let _: View = view! { p { (1 + 2) } };
 
@@ -102,6 +102,26 @@ pub fn use_context<T: Clone + 'static>() -> T {
 
 /// Try to get a context with the given type. If no context is found, returns the value of the
 /// function and sets the value of the context in the current scope.
+///
+/// The function is only called if no context is found. Since the default value is provided in the
+/// current scope, it is visible to child scopes just like a value set with [`provide_context`].
+/// This is useful for libraries that should work whether or not the app provides a context.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Theme(&'static str);
+///
+/// # let _ = create_root(|| {
+/// let theme = use_context_or_else(|| Theme("light"));
+/// assert_eq!(theme, Theme("light"));
+///
+/// create_child_scope(|| {
+///     assert_eq!(use_context::<Theme>(), Theme("light"));
+/// });
+/// # });
+/// ```
 pub fn use_context_or_else<T: Clone + 'static, F: FnOnce() -> T>(f: F) -> T {
     try_use_context().unwrap_or_else(|| {
         let value = f();
@@ -146,4 +166,27 @@ mod tests {
             trigger.set(());
         });
     }
+
+    #[test]
+    fn use_context_or_else_provides_default() {
+        let _ = create_root(|| {
+            assert_eq!(use_context_or_else(|| 123), 123);
+            assert_eq!(use_context::<i32>(), 123);
+
+            create_child_scope(|| {
+                assert_eq!(use_context::<i32>(), 123);
+                // The default is not used if a context exists in a parent scope.
+                assert_eq!(use_context_or_else(|| 456), 123);
+            });
+        });
+    }
+
+    #[test]
+    fn use_context_or_else_is_lazy() {
+        let _ = create_root(|| {
+            provide_context(123);
+            let value = use_context_or_else::<i32, _>(|| panic!("default should not be called"));
+            assert_eq!(value, 123);
+        });
+    }
 }
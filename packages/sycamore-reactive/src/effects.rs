@@ -26,6 +26,10 @@ use crate::create_memo;
 /// `create_effect` should only be used for creating **side-effects**. It is generally not
 /// recommended to update signal states inside an effect. You probably should be using a
 /// [`create_memo`](crate::create_memo) instead.
+///
+/// An effect is not re-run when it updates a signal that it has already read in the same run. In
+/// debug builds, a warning is printed when this happens. Effects that keep re-running each other
+/// are stopped after a limit, see [`set_max_reruns`](crate::set_max_reruns).
 #[cfg_attr(debug_assertions, track_caller)]
 pub fn create_effect(f: impl FnMut() + 'static) {
     create_memo(f);
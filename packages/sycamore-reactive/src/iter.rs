@@ -1,8 +1,10 @@
 //! Reactive utilities for dealing with lists and iterables.
 
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::mem;
+use std::rc::Rc;
 
 use crate::*;
 
@@ -14,6 +16,12 @@ use crate::*;
 ///
 /// This function is the underlying utility behind `Keyed`.
 ///
+/// If the input list is updated but is equal to the previous list, the mapped list is not
+/// recomputed and dependents are not notified. This means that a list of signals (e.g.
+/// `Vec<Signal<T>>`) can be used to get fine-grained updates for each item: updating an item's
+/// signal only updates the parts of the view that depend on that item instead of re-diffing the
+/// whole list, even if the input list itself is a memo that tracks the item signals.
+///
 /// # Params
 /// * `list` - The list to be mapped. The list must be a [`ReadSignal`] (obtained from a [`Signal`])
 ///   and therefore reactive.
@@ -26,6 +34,63 @@ pub fn map_keyed<T, K, U>(
     mut map_fn: impl FnMut(T) -> U + 'static,
     key_fn: impl Fn(&T) -> K + 'static,
 ) -> ReadSignal<Vec<U>>
+where
+    T: PartialEq + Clone + 'static,
+    K: Eq + Hash,
+    U: Clone,
+{
+    map_keyed_inner(list, move |item, _| map_fn(item), key_fn, false)
+}
+
+/// Like [`map_keyed`], but also passes the index of the item in the list to the map function.
+///
+/// The index is a signal that is updated whenever the item is moved to a different position.
+/// Moving an item does not map it again, so only the parts that depend on the index are updated.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let list = create_signal(vec!["a", "b", "c"]);
+/// let indexes = create_signal(Vec::new());
+/// let _mapped = map_keyed_with_index(
+///     list,
+///     move |item, index| indexes.update(|indexes| indexes.push((item, index))),
+///     |item| *item,
+/// );
+/// let (_, index_of_c) = indexes.get_clone()[2];
+/// assert_eq!(index_of_c.get(), 2);
+///
+/// list.set(vec!["c", "a", "b"]);
+/// assert_eq!(index_of_c.get(), 0);
+/// # });
+/// ```
+pub fn map_keyed_with_index<T, K, U>(
+    list: impl Into<MaybeDyn<Vec<T>>> + 'static,
+    mut map_fn: impl FnMut(T, ReadSignal<usize>) -> U + 'static,
+    key_fn: impl Fn(&T) -> K + 'static,
+) -> ReadSignal<Vec<U>>
+where
+    T: PartialEq + Clone + 'static,
+    K: Eq + Hash,
+    U: Clone,
+{
+    map_keyed_inner(
+        list,
+        move |item, index| map_fn(item, index.expect("index should be tracked")),
+        key_fn,
+        true,
+    )
+}
+
+/// Internal implementation for [`map_keyed`] and [`map_keyed_with_index`]. If `with_index` is
+/// `true`, a signal holding the index of each item is created and passed to `map_fn`.
+fn map_keyed_inner<T, K, U>(
+    list: impl Into<MaybeDyn<Vec<T>>> + 'static,
+    mut map_fn: impl FnMut(T, Option<ReadSignal<usize>>) -> U + 'static,
+    key_fn: impl Fn(&T) -> K + 'static,
+    with_index: bool,
+) -> ReadSignal<Vec<U>>
 where
     T: PartialEq + Clone + 'static,
     K: Eq + Hash,
@@ -41,25 +106,49 @@ where
     let mut disposers: Vec<Option<NodeHandle>> = Vec::new();
     let mut disposers_tmp: Vec<Option<NodeHandle>> = Vec::new();
 
+    let mut indexes: Vec<Option<Signal<usize>>> = Vec::new();
+    let mut indexes_tmp: Vec<Option<Signal<usize>>> = Vec::new();
+
+    // Whether the mapped list changed in the last update.
+    let changed = Rc::new(Cell::new(true));
+    // Indexes of moved items that still need to be updated. They are set after the mapped list is
+    // computed instead of while computing it.
+    let moved_indexes = Rc::new(RefCell::new(Vec::<(Signal<usize>, usize)>::new()));
+
     // Diff and update signal each time list is updated.
     let _list = list.clone();
+    let _changed = changed.clone();
+    let _moved_indexes = moved_indexes.clone();
     let mut update = move || {
         let new_items = _list.get_clone();
+        // Fast path for when nothing changed.
+        let is_unchanged = new_items == items;
+        _changed.set(!is_unchanged);
+        if is_unchanged {
+            return mapped.clone();
+        }
+
         if new_items.is_empty() {
             // Fast path for removing all items.
             for dis in mem::take(&mut disposers) {
                 dis.unwrap().dispose();
             }
             mapped = Vec::new();
+            indexes = Vec::new();
         } else if items.is_empty() {
             // Fast path for new create.
             mapped.reserve(new_items.len());
             disposers.reserve(new_items.len());
 
-            for new_item in new_items.iter().cloned() {
+            for (j, new_item) in new_items.iter().cloned().enumerate() {
                 let map_fn = &mut map_fn;
                 let mapped = &mut mapped;
-                let new_disposer = create_child_scope(move || mapped.push(map_fn(new_item)));
+                let indexes = &mut indexes;
+                let new_disposer = create_child_scope(move || {
+                    let index = with_index.then(|| create_signal(j));
+                    indexes.push(index);
+                    mapped.push(map_fn(new_item, index.map(|index| *index)));
+                });
                 disposers.push(Some(new_disposer));
             }
         } else {
@@ -69,6 +158,9 @@ where
             disposers_tmp.clear();
             disposers_tmp.resize_with(new_items.len(), || None);
 
+            indexes_tmp.clear();
+            indexes_tmp.resize(new_items.len(), None);
+
             // Skip common prefix.
             let min_len = usize::min(items.len(), new_items.len());
             let start = items
@@ -90,6 +182,7 @@ where
                 new_end -= 1;
                 mapped_tmp[new_end] = Some(mapped[end].clone());
                 disposers_tmp[new_end] = disposers[end].take();
+                indexes_tmp[new_end] = indexes[end];
             }
             debug_assert!(
                     if end != 0 && new_end != 0 {
@@ -125,6 +218,7 @@ where
                     // Moved. j is index of item in new_items.
                     mapped_tmp[j] = Some(mapped[i].clone());
                     disposers_tmp[j] = disposers[i].take();
+                    indexes_tmp[j] = indexes[i];
                     new_indices_next[j - start].and_then(|j| new_indices.insert(key, j));
                 } else {
                     // Create new.
@@ -141,21 +235,29 @@ where
                         debug_assert_eq!(mapped.len(), j);
                         mapped.push(mapped_tmp[j].clone().unwrap());
                         disposers.push(disposers_tmp[j].take());
+                        indexes.push(indexes_tmp[j]);
                     } else {
                         mapped[j] = mapped_tmp[j].clone().unwrap();
                         disposers[j] = disposers_tmp[j].take();
+                        indexes[j] = indexes_tmp[j];
                     }
                 } else {
                     // Create new value.
                     let mut tmp = None;
+                    let mut index = None;
                     let new_item = new_items[j].clone();
-                    let new_disposer = create_child_scope(|| tmp = Some(map_fn(new_item)));
+                    let new_disposer = create_child_scope(|| {
+                        index = with_index.then(|| create_signal(j));
+                        tmp = Some(map_fn(new_item, index.map(|index| *index)));
+                    });
                     if mapped.len() > j {
                         mapped[j] = tmp.unwrap();
                         disposers[j] = Some(new_disposer);
+                        indexes[j] = index;
                     } else {
                         mapped.push(tmp.unwrap());
                         disposers.push(Some(new_disposer));
+                        indexes.push(index);
                     }
                 }
             }
@@ -164,9 +266,17 @@ where
         // 3) In case the new set is shorter than the old, set the length of the mapped array.
         mapped.truncate(new_items.len());
         disposers.truncate(new_items.len());
+        indexes.truncate(new_items.len());
 
-        // 4) Save a copy of the mapped items for the next update.
-        debug_assert!([mapped.len(), disposers.len()]
+        // 4) Queue updating the indexes of the items that have moved.
+        _moved_indexes.borrow_mut().extend(
+            indexes.iter().enumerate().filter_map(|(j, index)| {
+                Some((index.filter(|index| index.get_untracked() != j)?, j))
+            }),
+        );
+
+        // 5) Save a copy of the mapped items for the next update.
+        debug_assert!([mapped.len(), disposers.len(), indexes.len()]
             .iter()
             .all(|l| *l == new_items.len()));
         items = new_items;
@@ -174,7 +284,21 @@ where
         mapped.clone()
     };
     let scope = use_current_scope();
-    create_memo(on(list, move || scope.run_in(&mut update)))
+    let mapped = create_selector_with(on(list, move || scope.run_in(&mut update)), move |_, _| {
+        !changed.get()
+    });
+    if with_index {
+        create_effect(move || {
+            mapped.track();
+            let moved_indexes = moved_indexes.take();
+            batch(move || {
+                for (index, j) in moved_indexes {
+                    index.set(j);
+                }
+            });
+        });
+    }
+    mapped
 }
 
 /// Function that maps a `Vec` to another `Vec` via a map function.
@@ -188,6 +312,9 @@ where
 ///
 /// This function is the underlying utility behind `Indexed`.
 ///
+/// Just like [`map_keyed`], dependents are not notified if the input list is updated but is equal
+/// to the previous list.
+///
 /// # Params
 /// * `list` - The list to be mapped. The list must be a [`ReadSignal`] (obtained from a [`Signal`])
 ///   and therefore reactive.
@@ -206,10 +333,20 @@ where
     let mut mapped = Vec::new();
     let mut disposers: Vec<NodeHandle> = Vec::new();
 
+    // Whether the mapped list changed in the last update.
+    let changed = Rc::new(Cell::new(true));
+
     // Diff and update signal each time list is updated.
     let _list = list.clone();
+    let _changed = changed.clone();
     let mut update = move || {
         let new_items = _list.get_clone();
+        // Fast path for when nothing changed.
+        let is_unchanged = new_items == items;
+        _changed.set(!is_unchanged);
+        if is_unchanged {
+            return mapped.clone();
+        }
 
         if new_items.is_empty() {
             // Fast path for removing all items.
@@ -265,14 +402,106 @@ where
         mapped.clone()
     };
     let scope = use_current_scope();
-    create_memo(on(list, move || scope.run_in(&mut update)))
+    create_selector_with(on(list, move || scope.run_in(&mut update)), move |_, _| {
+        !changed.get()
+    })
+}
+
+/// Function that maps a `Vec` to another `Vec` via a map function, memoizing the result for each
+/// key.
+///
+/// Unlike [`map_keyed`], which reuses the mapped value of an item as long as its key is still in
+/// the list, this also recomputes the mapped value if the item itself has changed. An item is only
+/// mapped again if it is new or if it is not equal to the previous item with the same key. This
+/// makes it suitable for deriving data (e.g. view models) from a list where items are updated in
+/// place.
+///
+/// Each mapped value is computed in its own child scope, which is disposed when the key is
+/// removed from the list or when the item is mapped again. Dependents are only notified if the
+/// mapped list has changed.
+///
+/// # Params
+/// * `list` - The list to be mapped. The list must be a [`ReadSignal`] (obtained from a [`Signal`])
+///   and therefore reactive.
+/// * `key_fn` - A closure that returns an _unique_ key to each entry.
+/// * `map_fn` - A closure that maps from the input type to the output type.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// #[derive(Clone, PartialEq)]
+/// struct Todo {
+///     id: u32,
+///     title: String,
+/// }
+///
+/// let todos = create_signal(vec![
+///     Todo { id: 1, title: "Write docs".to_string() },
+///     Todo { id: 2, title: "Write tests".to_string() },
+/// ]);
+/// let titles = create_selector_keyed(todos, |todo| todo.id, |todo| todo.title.to_uppercase());
+/// assert_eq!(titles.get_clone(), ["WRITE DOCS", "WRITE TESTS"]);
+///
+/// // Only the second todo is mapped again.
+/// todos.update(|todos| todos[1].title.push('!'));
+/// assert_eq!(titles.get_clone(), ["WRITE DOCS", "WRITE TESTS!"]);
+/// # });
+/// ```
+pub fn create_selector_keyed<T, K, U>(
+    list: impl Into<MaybeDyn<Vec<T>>> + 'static,
+    key_fn: impl Fn(&T) -> K + 'static,
+    mut map_fn: impl FnMut(T) -> U + 'static,
+) -> ReadSignal<Vec<U>>
+where
+    T: PartialEq + Clone + 'static,
+    K: Eq + Hash + 'static,
+    U: PartialEq + Clone + 'static,
+{
+    let list = list.into();
+    // The previous item, mapped value and scope for each key.
+    let mut cache: HashMap<K, (T, U, NodeHandle)> = HashMap::new();
+
+    let _list = list.clone();
+    let mut update = move || {
+        let new_items = _list.get_clone();
+        let mut new_cache = HashMap::with_capacity(new_items.len());
+        let mut mapped = Vec::with_capacity(new_items.len());
+
+        for item in new_items {
+            let key = key_fn(&item);
+            let entry = match cache.remove(&key) {
+                // Reuse the previous computation if the item has not changed.
+                Some(entry) if entry.0 == item => entry,
+                prev => {
+                    if let Some((_, _, scope)) = prev {
+                        scope.dispose();
+                    }
+                    let mut tmp = None;
+                    let scope = create_child_scope(|| tmp = Some(map_fn(item.clone())));
+                    (item, tmp.unwrap(), scope)
+                }
+            };
+            mapped.push(entry.1.clone());
+            if let Some((_, _, scope)) = new_cache.insert(key, entry) {
+                // Keys should be unique. Dispose the duplicate instead of leaking its scope.
+                scope.dispose();
+            }
+        }
+
+        // Dispose the scopes of removed keys.
+        for (_, _, scope) in mem::replace(&mut cache, new_cache).into_values() {
+            scope.dispose();
+        }
+
+        mapped
+    };
+    let scope = use_current_scope();
+    create_selector(on(list, move || scope.run_in(&mut update)))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::cell::Cell;
-    use std::rc::Rc;
-
     use super::*;
 
     #[test]
@@ -302,6 +531,68 @@ mod tests {
         });
     }
 
+    #[test]
+    fn keyed_with_index() {
+        let _ = create_root(|| {
+            let a = create_signal(vec![1, 2, 3, 4]);
+            let counter = create_signal(0);
+            let mapped = map_keyed_with_index(
+                a,
+                move |x, index| {
+                    counter.set(counter.get_untracked() + 1);
+                    (x, index)
+                },
+                |x| *x,
+            );
+            let indexes = move || {
+                mapped.with(|mapped| {
+                    mapped
+                        .iter()
+                        .map(|(x, index)| (*x, index.get()))
+                        .collect::<Vec<_>>()
+                })
+            };
+            assert_eq!(indexes(), [(1, 0), (2, 1), (3, 2), (4, 3)]);
+            assert_eq!(counter.get(), 4);
+
+            // Moved items are not mapped again but their indexes are updated.
+            a.set(vec![4, 2, 3, 1]);
+            assert_eq!(indexes(), [(4, 0), (2, 1), (3, 2), (1, 3)]);
+            assert_eq!(counter.get(), 4);
+
+            // Index of new items.
+            a.set(vec![5, 4, 2, 3, 1]);
+            assert_eq!(indexes(), [(5, 0), (4, 1), (2, 2), (3, 3), (1, 4)]);
+            assert_eq!(counter.get(), 5);
+
+            // Removing items shifts the indexes.
+            a.set(vec![2, 1]);
+            assert_eq!(indexes(), [(2, 0), (1, 1)]);
+            assert_eq!(counter.get(), 5);
+        });
+    }
+
+    #[test]
+    fn keyed_with_index_is_reactive() {
+        let _ = create_root(|| {
+            let a = create_signal(vec!["a", "b", "c"]);
+            let labels = create_signal(Vec::new());
+            map_keyed_with_index(
+                a,
+                move |x, index| {
+                    let label = create_memo(move || format!("{}. {x}", index.get() + 1));
+                    labels.update(|labels| labels.push(label));
+                },
+                |x| *x,
+            );
+            let label_of_c = labels.get_clone()[2];
+            assert_eq!(label_of_c.get_clone(), "3. c");
+
+            a.set(vec!["c", "a", "b"]);
+            assert_eq!(label_of_c.get_clone(), "1. c");
+        });
+    }
+
     /// Test fast path for clearing Vec.
     #[test]
     fn keyed_clear() {
@@ -400,6 +691,63 @@ mod tests {
         });
     }
 
+    /// Updating an item signal should not cause the mapped list to notify its dependents, even if
+    /// the list itself is recomputed.
+    #[test]
+    fn keyed_nested_signal_update_does_not_notify() {
+        let _ = create_root(|| {
+            let items = create_signal(vec![create_signal(1), create_signal(2)]);
+            // Tracks the nested signals, similar to filtering a list based on the item values.
+            let filtered = create_memo(move || {
+                items.with(|items| {
+                    items
+                        .iter()
+                        .filter(|x| x.get() > 0)
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+            });
+            let mapped = map_keyed(filtered, |x| x, |x| x.get_untracked());
+            let first = items.with(|items| items[0]);
+
+            let counter = create_signal(0);
+            create_effect(move || {
+                mapped.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            first.set(3);
+            assert_eq!(counter.get(), 1);
+
+            first.set(-1);
+            assert_eq!(counter.get(), 2);
+            assert_eq!(mapped.with(Vec::len), 1);
+        });
+    }
+
+    #[test]
+    fn keyed_equal_list_does_not_notify() {
+        let _ = create_root(|| {
+            let a = create_signal(vec![1, 2, 3]);
+            let mapped = map_keyed(a, |x| x * 2, |x| *x);
+
+            let counter = create_signal(0);
+            create_effect(move || {
+                mapped.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            a.set(vec![1, 2, 3]);
+            assert_eq!(counter.get(), 1);
+
+            a.set(vec![1, 2]);
+            assert_eq!(counter.get(), 2);
+            assert_eq!(mapped.get_clone(), vec![2, 4]);
+        });
+    }
+
     #[test]
     fn indexed() {
         let _ = create_root(|| {
@@ -446,6 +794,28 @@ mod tests {
         });
     }
 
+    #[test]
+    fn indexed_equal_list_does_not_notify() {
+        let _ = create_root(|| {
+            let a = create_signal(vec![1, 2, 3]);
+            let mapped = map_indexed(a, |x| x * 2);
+
+            let counter = create_signal(0);
+            create_effect(move || {
+                mapped.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            a.set(vec![1, 2, 3]);
+            assert_eq!(counter.get(), 1);
+
+            a.set(vec![]);
+            assert_eq!(counter.get(), 2);
+            assert_eq!(mapped.get_clone(), Vec::<i32>::new());
+        });
+    }
+
     /// Test that using [`map_indexed`] will reuse previous computations.
     #[test]
     fn indexed_use_previous_computation() {
@@ -539,4 +909,81 @@ mod tests {
             items.set(items.get_clone()[1..].to_vec());
         });
     }
+
+    #[test]
+    fn selector_keyed_only_maps_changed_items() {
+        let _ = create_root(|| {
+            let a = create_signal(vec![(1, "a"), (2, "b"), (3, "c")]);
+            let counter = create_signal(0);
+            let mapped = create_selector_keyed(
+                a,
+                |(key, _)| *key,
+                move |(_, value)| {
+                    counter.set(counter.get_untracked() + 1);
+                    value.to_uppercase()
+                },
+            );
+            assert_eq!(mapped.get_clone(), ["A", "B", "C"]);
+            assert_eq!(counter.get(), 3);
+
+            // Only the changed item is mapped again.
+            a.set(vec![(1, "a"), (2, "x"), (3, "c")]);
+            assert_eq!(mapped.get_clone(), ["A", "X", "C"]);
+            assert_eq!(counter.get(), 4);
+
+            // Moving and removing items does not map them again.
+            a.set(vec![(3, "c"), (1, "a")]);
+            assert_eq!(mapped.get_clone(), ["C", "A"]);
+            assert_eq!(counter.get(), 4);
+
+            // Added items are mapped.
+            a.set(vec![(3, "c"), (1, "a"), (4, "d")]);
+            assert_eq!(mapped.get_clone(), ["C", "A", "D"]);
+            assert_eq!(counter.get(), 5);
+        });
+    }
+
+    #[test]
+    fn selector_keyed_disposes_removed_keys() {
+        let _ = create_root(|| {
+            let a = create_signal(vec![(1, 1), (2, 2), (3, 3)]);
+            let counter = create_signal(0);
+            let _mapped = create_selector_keyed(
+                a,
+                |(key, _)| *key,
+                move |(_, value)| on_cleanup(move || counter.set(counter.get_untracked() + value)),
+            );
+            assert_eq!(counter.get(), 0, "no cleanup yet");
+
+            // Scope of the changed item is disposed.
+            a.set(vec![(1, 1), (2, 20), (3, 3)]);
+            assert_eq!(counter.get(), 2);
+
+            // Scope of the removed item is disposed.
+            a.set(vec![(1, 1), (2, 20)]);
+            assert_eq!(counter.get(), 5);
+
+            a.set(vec![]);
+            assert_eq!(counter.get(), 26);
+        });
+    }
+
+    #[test]
+    fn selector_keyed_does_not_notify_if_unchanged() {
+        let _ = create_root(|| {
+            let a = create_signal(vec![1, 2, 3]);
+            let mapped = create_selector_keyed(a, |x| *x, |x| x % 2);
+            let counter = create_signal(0);
+            create_effect(move || {
+                mapped.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            // Mapped list is unchanged, even though the input list has changed.
+            a.set(vec![3, 2, 1]);
+            assert_eq!(mapped.get_clone(), [1, 0, 1]);
+            assert_eq!(counter.get(), 1);
+        });
+    }
 }
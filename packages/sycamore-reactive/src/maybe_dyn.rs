@@ -209,6 +209,8 @@ impl_into_maybe_dyn!(u64);
 impl_into_maybe_dyn!(u128);
 impl_into_maybe_dyn!(usize);
 
+impl_into_maybe_dyn!(std::time::Duration);
+
 impl<T> From<Option<T>> for MaybeDyn<Option<T>> {
     fn from(val: Option<T>) -> Self {
         MaybeDyn::Static(val)
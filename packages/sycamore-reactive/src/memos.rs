@@ -2,7 +2,7 @@
 
 use std::cell::RefCell;
 
-use crate::{create_empty_signal, create_signal, ReadSignal, Root};
+use crate::{create_empty_signal, create_signal, untrack, ReadSignal, Root, Signal};
 
 /// Creates a memoized value from some signals.
 /// Unlike [`create_memo`], this function will not notify dependents of a
@@ -11,8 +11,25 @@ use crate::{create_empty_signal, create_signal, ReadSignal, Root};
 /// It takes a comparison function to compare the old and new value, which returns `true` if
 /// they are the same and `false` otherwise.
 ///
+/// This is useful for types that do not implement [`PartialEq`] or when approximate equality is
+/// desired, e.g. comparing floats with a tolerance.
+///
 /// To use the type's [`PartialEq`] implementation instead of a custom function, use
 /// [`create_selector`].
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let position = create_signal(0.0f64);
+/// let rounded = create_selector_with(move || position.get(), |a, b| (a - b).abs() < 1.0);
+///
+/// position.set(0.5); // Does not notify dependents of `rounded`.
+/// assert_eq!(rounded.get(), 0.0);
+/// position.set(1.5);
+/// assert_eq!(rounded.get(), 1.5);
+/// # });
+/// ```
 #[cfg_attr(debug_assertions, track_caller)]
 pub fn create_selector_with<T>(
     mut f: impl FnMut() -> T + 'static,
@@ -24,6 +41,8 @@ pub fn create_selector_with<T>(
     let (initial, tracker) = root.tracked_scope(&mut f);
     root.current_node.set(prev);
 
+    #[cfg(debug_assertions)]
+    root.check_updated_while_running(signal.id, &tracker);
     tracker.create_dependency_link(root, signal.id);
 
     let mut signal_mut = signal.get_mut();
@@ -73,6 +92,9 @@ pub fn create_selector_with<T>(
 /// Memos also incur a slightly higher performance penalty than simple derived signals, so unless
 /// there is some computation involved, it will likely be faster to just use a derived signal.
 ///
+/// To only notify dependents when the output changes, use [`create_selector`] or
+/// [`create_memo_eq`].
+///
 /// # Example
 /// ```
 /// # use sycamore_reactive::*;
@@ -122,6 +144,134 @@ where
     create_selector_with(f, PartialEq::eq)
 }
 
+/// Creates a memoized computation that only notifies dependents of a change if `eq` returns
+/// `false` for the old and new output.
+///
+/// This is the same as [`create_selector_with`]. It is useful for outputs that do not implement
+/// [`PartialEq`] or that should be compared approximately.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let volume = create_signal(0.5f64);
+/// let percent = create_memo_eq(move || volume.get() * 100.0, |a, b| (a - b).abs() < 1.0);
+///
+/// volume.set(0.505); // Does not notify dependents of `percent`.
+/// assert_eq!(percent.get(), 50.0);
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_memo_eq<T>(
+    f: impl FnMut() -> T + 'static,
+    eq: impl FnMut(&T, &T) -> bool + 'static,
+) -> ReadSignal<T> {
+    create_selector_with(f, eq)
+}
+
+/// Creates a memo that can also be set directly, like a [`Signal`].
+///
+/// The value is computed from `f` like with [`create_memo`], but it can be overridden with
+/// [`Signal::set`] and the other setters. The override sticks until one of the dependencies of `f`
+/// changes, at which point the value is computed again. Dependents are notified both when the
+/// value is overridden and when it is recomputed.
+///
+/// This is useful for optimistic updates, where the UI shows the expected value before it is
+/// confirmed by the source of truth.
+///
+/// If a dependency is changed and the value is set in the same [`batch`](crate::batch), the value
+/// is recomputed at the end of the batch and the override is discarded.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let server_likes = create_signal(10);
+/// let likes = create_writable_memo(move || server_likes.get());
+///
+/// // Optimistically show the new value.
+/// likes.set(11);
+/// assert_eq!(likes.get(), 11);
+///
+/// // Once the server responds, the computed value wins again.
+/// server_likes.set(12);
+/// assert_eq!(likes.get(), 12);
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_writable_memo<T>(f: impl FnMut() -> T + 'static) -> Signal<T> {
+    Signal(create_memo(f))
+}
+
+/// A tuple whose elements can be split into separate memoized signals.
+///
+/// This is implemented for tuples of up to 6 elements where each element implements [`Clone`] and
+/// [`PartialEq`]. See [`create_memo_split`] for more information.
+pub trait MemoSplit: 'static {
+    /// The tuple of [`ReadSignal`]s that is returned by [`create_memo_split`].
+    type Signals;
+
+    /// Splits the tuple memo into one selector per element.
+    fn split_memo(memo: ReadSignal<Self>) -> Self::Signals
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_memo_split_for_tuple {
+    ($($T:ident => $idx:tt),*) => {
+        impl<$($T,)*> MemoSplit for ($($T,)*)
+        where
+            $($T: Clone + PartialEq + 'static,)*
+        {
+            type Signals = ($(ReadSignal<$T>,)*);
+
+            #[cfg_attr(debug_assertions, track_caller)]
+            fn split_memo(memo: ReadSignal<Self>) -> Self::Signals {
+                ($(create_selector(move || memo.with(|value| value.$idx.clone())),)*)
+            }
+        }
+    };
+}
+
+impl_memo_split_for_tuple!(A => 0);
+impl_memo_split_for_tuple!(A => 0, B => 1);
+impl_memo_split_for_tuple!(A => 0, B => 1, C => 2);
+impl_memo_split_for_tuple!(A => 0, B => 1, C => 2, D => 3);
+impl_memo_split_for_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_memo_split_for_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+
+/// Creates a memoized computation that returns a tuple and splits it into one signal per element.
+///
+/// The computation itself only runs once per update of its dependencies. However, each element is
+/// exposed as its own [`ReadSignal`] that only notifies its dependents when that particular element
+/// changes. This is useful when a single (possibly expensive) computation produces several related
+/// values that are consumed independently.
+///
+/// This is implemented for tuples of up to 6 elements. Each element must implement [`Clone`] and
+/// [`PartialEq`].
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let numbers = create_signal(vec![1, 2, 3]);
+/// let (sum, len) = create_memo_split(move || {
+///     numbers.with(|numbers| (numbers.iter().sum::<i32>(), numbers.len()))
+/// });
+/// assert_eq!(sum.get(), 6);
+/// assert_eq!(len.get(), 3);
+///
+/// // Only `sum` will notify its dependents here since `len` is unchanged.
+/// numbers.set(vec![2, 2, 3]);
+/// assert_eq!(sum.get(), 7);
+/// assert_eq!(len.get(), 3);
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_memo_split<T: MemoSplit>(f: impl FnMut() -> T + 'static) -> T::Signals {
+    T::split_memo(create_memo(f))
+}
+
 /// An alternative to [`create_signal`] that uses a reducer to get the next
 /// value.
 ///
@@ -130,6 +280,11 @@ where
 ///
 /// Returns a [`ReadSignal`] and a dispatch function to send messages to the reducer.
 ///
+/// The reducer is run immediately when a message is dispatched. Signals accessed inside the
+/// reducer are not tracked, so dispatching a message from inside an effect or memo does not make
+/// it depend on them. Dispatching several messages inside a [`batch`](crate::batch) only updates dependents
+/// once.
+///
 /// # Params
 /// * `initial` - The initial value of the state.
 /// * `reducer` - A function that takes the previous value and a message and returns the next value.
@@ -162,7 +317,9 @@ pub fn create_reducer<T, Msg>(
 ) -> (ReadSignal<T>, impl Fn(Msg)) {
     let reduce = RefCell::new(reduce);
     let signal = create_signal(initial);
-    let dispatch = move |msg| signal.update(|value| *value = reduce.borrow_mut()(value, msg));
+    let dispatch = move |msg| {
+        signal.update(|value| *value = untrack(|| reduce.borrow_mut()(value, msg)));
+    };
     (*signal, dispatch)
 }
 
@@ -294,6 +451,46 @@ mod tests {
         });
     }
 
+    #[test]
+    fn writable_memo() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let double = create_writable_memo(move || state.get() * 2);
+            let counter = create_signal(0);
+            create_effect(move || {
+                double.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(double.get(), 2);
+
+            double.set(5);
+            assert_eq!(double.get(), 5);
+            assert_eq!(counter.get(), 2);
+
+            // The override sticks until a dependency changes.
+            double.update(|value| *value += 1);
+            assert_eq!(double.get(), 6);
+
+            state.set(2);
+            assert_eq!(double.get(), 4);
+            assert_eq!(counter.get(), 4);
+        });
+    }
+
+    #[test]
+    fn writable_memo_in_memo() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let memo = create_writable_memo(move || state.get());
+            let plus_one = create_memo(move || memo.get() + 1);
+
+            memo.set(10);
+            assert_eq!(plus_one.get(), 11);
+            state.set(2);
+            assert_eq!(plus_one.get(), 3);
+        });
+    }
+
     #[test]
     fn selector() {
         let _ = create_root(|| {
@@ -321,6 +518,99 @@ mod tests {
         });
     }
 
+    #[test]
+    fn memo_split() {
+        let _ = create_root(|| {
+            let state = create_signal((1, 2));
+
+            let compute_counter = create_signal(0);
+            let (first, second) = create_memo_split(move || {
+                compute_counter.set_silent(compute_counter.get_untracked() + 1);
+                state.get()
+            });
+
+            let first_counter = create_signal(0);
+            create_effect(move || {
+                first.track();
+                first_counter.set_silent(first_counter.get_untracked() + 1);
+            });
+            let second_counter = create_signal(0);
+            create_effect(move || {
+                second.track();
+                second_counter.set_silent(second_counter.get_untracked() + 1);
+            });
+
+            assert_eq!(compute_counter.get(), 1);
+            assert_eq!((first.get(), second.get()), (1, 2));
+            assert_eq!((first_counter.get(), second_counter.get()), (1, 1));
+
+            state.set((3, 2));
+            assert_eq!(compute_counter.get(), 2);
+            assert_eq!((first.get(), second.get()), (3, 2));
+            assert_eq!((first_counter.get(), second_counter.get()), (2, 1));
+
+            state.set((3, 4));
+            assert_eq!(compute_counter.get(), 3);
+            assert_eq!((first.get(), second.get()), (3, 4));
+            assert_eq!((first_counter.get(), second_counter.get()), (2, 2));
+        });
+    }
+
+    #[test]
+    fn memo_eq() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let parity = create_memo_eq(move || state.get(), |a, b| a % 2 == b % 2);
+
+            let counter = create_signal(0);
+            create_effect(move || {
+                counter.set(counter.get_untracked() + 1);
+                parity.track();
+            });
+            assert_eq!(counter.get(), 1);
+
+            state.set(3);
+            assert_eq!(parity.get(), 1);
+            assert_eq!(counter.get(), 1);
+
+            state.set(4);
+            assert_eq!(parity.get(), 4);
+            assert_eq!(counter.get(), 2);
+        });
+    }
+
+    #[test]
+    fn selector_with_custom_eq() {
+        let _ = create_root(|| {
+            // `Vec<f64>` does not implement `Eq` and floats are compared with a tolerance.
+            let state = create_signal(vec![0.0f64]);
+            let approx = create_selector_with(
+                move || state.get_clone(),
+                |a: &Vec<f64>, b: &Vec<f64>| {
+                    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).abs() < 0.01)
+                },
+            );
+
+            let counter = create_signal(0);
+            create_effect(move || {
+                counter.set(counter.get_untracked() + 1);
+                approx.track();
+            });
+            assert_eq!(counter.get(), 1);
+
+            state.set(vec![0.001]);
+            assert_eq!(approx.get_clone(), vec![0.0]);
+            assert_eq!(counter.get(), 1);
+
+            state.set(vec![0.5]);
+            assert_eq!(approx.get_clone(), vec![0.5]);
+            assert_eq!(counter.get(), 2);
+
+            state.set(vec![0.5, 0.5]);
+            assert_eq!(counter.get(), 3);
+        });
+    }
+
     #[test]
     fn reducer() {
         let _ = create_root(|| {
@@ -345,6 +635,51 @@ mod tests {
         });
     }
 
+    #[test]
+    fn reducer_batch() {
+        let _ = create_root(|| {
+            let (state, dispatch) = create_reducer(0, |state, n: i32| *state + n);
+            let counter = create_signal(0);
+            create_effect(move || {
+                state.track();
+                counter.set_silent(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            batch(|| {
+                dispatch(1);
+                dispatch(2);
+                assert_eq!(state.get(), 3);
+            });
+            assert_eq!(state.get(), 3);
+            assert_eq!(counter.get(), 2);
+        });
+    }
+
+    #[test]
+    fn reducer_dispatch_does_not_track() {
+        let _ = create_root(|| {
+            let step = create_signal(1);
+            let trigger = create_signal(());
+            let (state, dispatch) = create_reducer(0, move |state, _: ()| *state + step.get());
+            let counter = create_signal(0);
+            create_effect(move || {
+                trigger.track();
+                dispatch(());
+                counter.set_silent(counter.get_untracked() + 1);
+            });
+            assert_eq!(state.get_untracked(), 1);
+            assert_eq!(counter.get(), 1);
+
+            // Signals read inside the reducer are not tracked by the effect calling `dispatch`.
+            step.set(2);
+            assert_eq!(counter.get(), 1);
+            trigger.set(());
+            assert_eq!(counter.get(), 2);
+            assert_eq!(state.get_untracked(), 3);
+        });
+    }
+
     #[test]
     fn memo_reducer() {
         let _ = create_root(|| {
@@ -366,4 +701,45 @@ mod tests {
             assert_eq!(doubled.get(), 0);
         });
     }
+
+    #[test]
+    fn force_recompute_memo() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let counter = create_signal(0);
+            let double = create_memo(move || {
+                counter.set_silent(counter.get_untracked() + 1);
+                state.get() * 2
+            });
+            let quadruple = create_memo(move || double.get() * 2);
+            assert_eq!(counter.get(), 1);
+
+            // Dependencies have not changed but the closure is still re-run.
+            assert_eq!(double.force_recompute(), 2);
+            assert_eq!(counter.get(), 2);
+            assert_eq!(quadruple.get(), 4);
+
+            // Dependencies are still tracked after recomputing.
+            state.set(2);
+            assert_eq!(counter.get(), 3);
+            assert_eq!(quadruple.get(), 8);
+        });
+    }
+
+    #[test]
+    fn force_recompute_selector_does_not_notify_if_unchanged() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let double = create_selector(move || state.get() * 2);
+            let counter = create_signal(0);
+            create_effect(move || {
+                double.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            assert_eq!(double.force_recompute(), 2);
+            assert_eq!(counter.get(), 1);
+        });
+    }
 }
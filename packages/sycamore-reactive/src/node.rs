@@ -28,8 +28,8 @@ pub(crate) struct ReactiveNode {
     pub dependents: Vec<NodeId>,
     /// Nodes that this node depends on.
     pub dependencies: SmallVec<[NodeId; 1]>,
-    /// Callbacks called when node is disposed.
-    pub cleanups: Vec<Box<dyn FnOnce()>>,
+    /// Callbacks called when node is disposed, along with their priority.
+    pub cleanups: Vec<(i32, Box<dyn FnOnce()>)>,
     /// Context values stored in this node.
     pub context: Vec<Box<dyn Any>>,
     /// Used for keeping track of dirty state of node value.
@@ -38,8 +38,10 @@ pub(crate) struct ReactiveNode {
     pub mark: Mark,
     /// Keep track of where the signal was created for diagnostics.
     #[cfg(debug_assertions)]
-    #[allow(dead_code)]
     pub created_at: &'static std::panic::Location<'static>,
+    /// The value of [`Root::write_count`] when this node was last updated.
+    #[cfg(debug_assertions)]
+    pub last_write: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -74,15 +76,22 @@ impl NodeHandle {
         self.dispose_children();
         let mut nodes = self.1.nodes.borrow_mut();
         // Release memory.
-        if let Some(this) = nodes.remove(self.0) {
+        let this = nodes.remove(self.0);
+        let eq = self.1.signal_eqs.borrow_mut().remove(self.0);
+        if let Some(this) = &this {
             // Remove self from all dependencies.
-            for dependent in this.dependents {
+            for dependent in &this.dependents {
                 // dependent might have been removed if it is a child node.
-                if let Some(dependent) = nodes.get_mut(dependent) {
+                if let Some(dependent) = nodes.get_mut(*dependent) {
                     dependent.dependencies.retain(|&mut id| id != self.0);
                 }
             }
         }
+        // Drop the value after releasing the borrow since dropping it can dispose other nodes
+        // (e.g. if it holds an `RcSignal`).
+        drop(nodes);
+        drop(this);
+        drop(eq);
     }
 
     /// Dispose all the children of the node but not the node itself.
@@ -93,13 +102,17 @@ impl NodeHandle {
         if self.1.nodes.borrow().get(self.0).is_none() {
             return;
         }
-        let cleanup = std::mem::take(&mut self.1.nodes.borrow_mut()[self.0].cleanups);
+        let mut cleanup = std::mem::take(&mut self.1.nodes.borrow_mut()[self.0].cleanups);
+        // Higher priorities run first. Since the sort is stable, callbacks with the same priority
+        // run in reverse registration order.
+        cleanup.reverse();
+        cleanup.sort_by_key(|(priority, _)| std::cmp::Reverse(*priority));
         let children = std::mem::take(&mut self.1.nodes.borrow_mut()[self.0].children);
 
         // Run the cleanup functions in an untracked scope so that we don't track dependencies.
         untrack_in_scope(
             move || {
-                for cb in cleanup {
+                for (_, cb) in cleanup {
                     cb();
                 }
             },
@@ -109,8 +122,22 @@ impl NodeHandle {
             Self(child, self.1).dispose();
         }
 
-        // Clear context values.
-        self.1.nodes.borrow_mut()[self.0].context.clear();
+        // Clear context values. These are also dropped after releasing the borrow.
+        let context = std::mem::take(&mut self.1.nodes.borrow_mut()[self.0].context);
+        drop(context);
+    }
+
+    /// Returns the location in the source code where the node was created, or `None` if the node
+    /// has already been disposed.
+    ///
+    /// This is only available in debug builds.
+    #[cfg(debug_assertions)]
+    pub fn creation_location(self) -> Option<&'static std::panic::Location<'static>> {
+        self.1
+            .nodes
+            .borrow()
+            .get(self.0)
+            .map(|node| node.created_at)
     }
 
     /// Run a closure under this reactive node.
@@ -1,8 +1,9 @@
 //! [`Root`] and [`Scope`].
 
+use std::any::Any;
 use std::cell::{Cell, RefCell};
 
-use slotmap::{Key, SlotMap};
+use slotmap::{Key, SecondaryMap, SlotMap};
 use smallvec::SmallVec;
 
 use crate::*;
@@ -33,8 +34,45 @@ pub(crate) struct Root {
     /// Whether we are currently batching signal updates. If this is true, we do not run
     /// `effect_queue` and instead wait until the end of the batch.
     pub batching: Cell<bool>,
+    /// The number of flushes (creating the root, batches and update propagations) that are
+    /// currently in progress. Callbacks in `settled_queue` are run once this drops back to zero.
+    pub flush_depth: Cell<u32>,
+    /// Callbacks registered with [`on_settled`], along with the node that was current when they
+    /// were registered.
+    pub settled_queue: RefCell<Vec<SettledCallback>>,
+    /// The maximum number of consecutive rounds of [`on_settled`] callbacks that a single scope
+    /// can queue. See [`set_max_settled_rounds`].
+    pub max_settled_rounds: Cell<u32>,
+    /// How many times each node has been re-run in the current flush. Cleared whenever the
+    /// outermost flush ends and before every round of [`on_settled`] callbacks.
+    pub rerun_counts: RefCell<SecondaryMap<NodeId, u32>>,
+    /// The maximum number of times that a single node can be re-run in a flush. See
+    /// [`set_max_reruns`].
+    pub max_reruns: Cell<u32>,
+    /// Nodes that could not be disposed because `nodes` was borrowed at the time, e.g. an
+    /// [`RcSignal`](crate::RcSignal) dropped while reading a signal. See [`Root::dispose_pending`].
+    pub pending_disposals: RefCell<Vec<NodeId>>,
+    /// Custom equality functions of the signals created with [`create_signal_with`]. Writes that
+    /// replace the value with an equal value do not notify dependents.
+    pub signal_eqs: RefCell<SecondaryMap<NodeId, SignalEq>>,
+    /// The number of node updates so far. Used to detect nodes that are updated while a node that
+    /// depends on them is still running.
+    #[cfg(debug_assertions)]
+    pub write_count: Cell<u64>,
 }
 
+/// The default value of [`Root::max_settled_rounds`].
+const DEFAULT_MAX_SETTLED_ROUNDS: u32 = 100;
+
+/// The default value of [`Root::max_reruns`].
+const DEFAULT_MAX_RERUNS: u32 = 1000;
+
+/// A custom equality function of a signal. See [`Root::signal_eqs`].
+pub(crate) type SignalEq = Box<dyn FnMut(&dyn Any, &dyn Any) -> bool>;
+
+/// A callback registered with [`on_settled`], along with the node that owns it.
+type SettledCallback = (NodeId, Box<dyn FnOnce()>);
+
 thread_local! {
     /// The current reactive root.
     static GLOBAL_ROOT: Cell<Option<&'static Root>> = const { Cell::new(None) };
@@ -62,6 +100,15 @@ impl Root {
             nodes: RefCell::new(SlotMap::default()),
             node_update_queue: RefCell::new(Vec::new()),
             batching: Cell::new(false),
+            flush_depth: Cell::new(0),
+            settled_queue: RefCell::new(Vec::new()),
+            max_settled_rounds: Cell::new(DEFAULT_MAX_SETTLED_ROUNDS),
+            rerun_counts: RefCell::new(SecondaryMap::new()),
+            max_reruns: Cell::new(DEFAULT_MAX_RERUNS),
+            pending_disposals: RefCell::new(Vec::new()),
+            signal_eqs: RefCell::new(SecondaryMap::new()),
+            #[cfg(debug_assertions)]
+            write_count: Cell::new(0),
         };
         let _ref = Box::leak(Box::new(this));
         _ref.reinit();
@@ -80,6 +127,11 @@ impl Root {
         let _ = self.root_node.take();
         let _ = self.nodes.take();
         self.batching.set(false);
+        self.flush_depth.set(0);
+        let _ = self.settled_queue.take();
+        let _ = self.rerun_counts.take();
+        let _ = self.pending_disposals.take();
+        let _ = self.signal_eqs.take();
 
         // Create a new root node.
         Root::set_global(Some(self));
@@ -89,7 +141,22 @@ impl Root {
         self.current_node.set(root_node.0);
     }
 
+    /// Disposes the nodes in `pending_disposals` if `nodes` is no longer borrowed. Otherwise, they
+    /// stay queued until the next call.
+    pub fn dispose_pending(&'static self) {
+        loop {
+            if self.nodes.try_borrow_mut().is_err() {
+                return;
+            }
+            let Some(id) = self.pending_disposals.borrow_mut().pop() else {
+                return;
+            };
+            NodeHandle(id, self).dispose();
+        }
+    }
+
     /// Create a new child scope. Implementation detail for [`create_child_scope`].
+    #[cfg_attr(debug_assertions, track_caller)]
     pub fn create_child_scope(&'static self, f: impl FnOnce()) -> NodeHandle {
         let node = create_signal(()).id;
         let prev = self.current_node.replace(node);
@@ -101,20 +168,21 @@ impl Root {
     /// Run the provided closure in a tracked scope. This will detect all the signals that are
     /// accessed and track them in a dependency list.
     pub fn tracked_scope<T>(&self, f: impl FnOnce() -> T) -> (T, DependencyTracker) {
-        let prev = self.tracker.replace(Some(DependencyTracker::default()));
+        let guard = TrackerGuard::new(self, Some(DependencyTracker::default()));
         let ret = f();
-        (ret, self.tracker.replace(prev).unwrap())
+        (ret, guard.restore().unwrap())
     }
 
     /// Run the update callback of the signal, also recreating any dependencies found by
     /// tracking signal accesses inside the function.
     ///
-    /// Also marks all the dependencies as dirty and marks the current node as clean.
+    /// Also marks all the dependencies as dirty and marks the current node as clean. Returns `true`
+    /// if the value of the node has changed.
     ///
     /// # Params
     /// * `root` - The reactive root.
     /// * `id` - The id associated with the reactive node. `SignalId` inside the state itself.
-    fn run_node_update(&'static self, current: NodeId) {
+    fn run_node_update(&'static self, current: NodeId) -> bool {
         debug_assert_eq!(
             self.nodes.borrow()[current].state,
             NodeState::Dirty,
@@ -140,6 +208,8 @@ impl Root {
         let (changed, tracker) = self.tracked_scope(|| callback(&mut value));
         self.current_node.set(prev);
 
+        #[cfg(debug_assertions)]
+        self.check_updated_while_running(current, &tracker);
         tracker.create_dependency_link(self, current);
 
         let mut nodes_mut = self.nodes.borrow_mut();
@@ -148,11 +218,64 @@ impl Root {
 
         // Mark this node as clean.
         nodes_mut[current].state = NodeState::Clean;
+        #[cfg(debug_assertions)]
+        if changed {
+            self.write_count.set(self.write_count.get() + 1);
+            nodes_mut[current].last_write = self.write_count.get();
+        }
         drop(nodes_mut);
 
         if changed {
             self.mark_dependents_dirty(current);
         }
+        changed
+    }
+
+    /// Re-runs the callback of `current` even if none of its dependencies have changed, and then
+    /// propagates the update to its dependents if the value has changed.
+    ///
+    /// Does nothing if the node does not have a callback, i.e. if it is a plain signal.
+    pub(crate) fn force_node_update(&'static self, current: NodeId) {
+        let mut nodes_mut = self.nodes.borrow_mut();
+        if nodes_mut[current].callback.is_none() {
+            return;
+        }
+        nodes_mut[current].state = NodeState::Dirty;
+        drop(nodes_mut);
+
+        let prev = Root::set_global(Some(self));
+        let changed = self.run_node_update(current);
+        Root::set_global(prev);
+
+        if changed {
+            self.propagate_updates(current);
+        }
+    }
+
+    /// Warns if one of the dependencies of `current` was updated after `current` read it, while
+    /// `current` was still running, e.g. an effect that writes to a signal that it depends on.
+    ///
+    /// The dependency links of a running node are only created once it has finished running, so
+    /// such an update does not mark it as dirty again and it is not re-run with the new value.
+    #[cfg(debug_assertions)]
+    pub(crate) fn check_updated_while_running(&self, current: NodeId, tracker: &DependencyTracker) {
+        let nodes = self.nodes.borrow();
+        let updated = tracker.dependencies.iter().zip(&tracker.read_at).find_map(
+            |(&dependency, &read_at)| {
+                nodes
+                    .get(dependency)
+                    .filter(|dependency| dependency.last_write > read_at)
+            },
+        );
+        if let Some(dependency) = updated {
+            warn(&format!(
+                "the reactive node created at {} was updated while the effect or memo created at \
+                {} was running, after the effect or memo had read it. The effect or memo is not \
+                re-run for this update. This usually means that it writes to one of its own \
+                dependencies",
+                dependency.created_at, nodes[current].created_at
+            ));
+        }
     }
 
     // Mark any dependent node of the current node as dirty.
@@ -201,8 +324,50 @@ impl Root {
             // Check if this node needs to be updated.
             if nodes_mut[node].state == NodeState::Dirty {
                 drop(nodes_mut); // End RefMut borrow.
-                self.run_node_update(node)
-            };
+                if self.count_rerun(node) {
+                    self.run_node_update(node);
+                }
+            }
+        }
+    }
+
+    /// Counts a re-run of `node` in the current flush. Returns `false` if `node` must not be re-run
+    /// because it has already been re-run more than [`Root::max_reruns`] times.
+    ///
+    /// Effects that keep invalidating each other would otherwise loop forever. This panics in
+    /// debug builds. In release builds, the node is marked as clean without being re-run instead,
+    /// which stops the propagation of the update.
+    fn count_rerun(&'static self, node: NodeId) -> bool {
+        if self.flush_depth.get() == 0 {
+            return true;
+        }
+        let mut rerun_counts = self.rerun_counts.borrow_mut();
+        let Some(count) = rerun_counts.entry(node).map(|entry| entry.or_insert(0)) else {
+            return true;
+        };
+        *count += 1;
+        if *count <= self.max_reruns.get() {
+            return true;
+        }
+        rerun_counts.clear();
+        drop(rerun_counts);
+        #[cfg(debug_assertions)]
+        {
+            self.flush_depth.set(0);
+            let location = NodeHandle(node, self)
+                .creation_location()
+                .map_or_else(|| "<disposed>".to_string(), ToString::to_string);
+            panic!(
+                "reactive update loop: the effect or memo created at {location} was re-run more \
+                than {} times while propagating updates. This usually means that effects write to \
+                each other's dependencies",
+                self.max_reruns.get()
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            self.nodes.borrow_mut()[node].state = NodeState::Clean;
+            false
         }
     }
 
@@ -211,13 +376,22 @@ impl Root {
     ///
     /// If we are currently batching, defers updating the signal until the end of the batch.
     pub fn propagate_updates(&'static self, start_node: NodeId) {
+        #[cfg(debug_assertions)]
+        {
+            self.write_count.set(self.write_count.get() + 1);
+            if let Some(node) = self.nodes.borrow_mut().get_mut(start_node) {
+                node.last_write = self.write_count.get();
+            }
+        }
         if self.batching.get() {
             self.node_update_queue.borrow_mut().push(start_node);
         } else {
             // Set the global root.
             let prev = Root::set_global(Some(self));
             // Propagate any signal updates.
+            self.start_flush();
             self.propagate_node_updates(&[start_node]);
+            self.end_flush();
             Root::set_global(prev);
         }
     }
@@ -250,6 +424,7 @@ impl Root {
     /// Sets the batch flag to `true`.
     fn start_batch(&self) {
         self.batching.set(true);
+        self.start_flush();
     }
 
     /// Sets the batch flag to `false` and run all the queued effects.
@@ -257,6 +432,94 @@ impl Root {
         self.batching.set(false);
         let nodes = self.node_update_queue.take();
         self.propagate_node_updates(&nodes);
+        self.end_flush();
+    }
+
+    /// Marks the start of a flush. Callbacks registered with [`on_settled`] are deferred until
+    /// the outermost flush has ended.
+    fn start_flush(&self) {
+        self.flush_depth.set(self.flush_depth.get() + 1);
+    }
+
+    /// Marks the end of a flush. If this was the outermost flush, runs the callbacks registered
+    /// with [`on_settled`].
+    fn end_flush(&'static self) {
+        let depth = self.flush_depth.get() - 1;
+        self.flush_depth.set(depth);
+        if depth == 0 {
+            self.rerun_counts.borrow_mut().clear();
+            self.dispose_pending();
+            self.run_settled_callbacks();
+        }
+    }
+
+    /// Runs all the callbacks in `settled_queue`, including any that are queued by the callbacks
+    /// themselves.
+    ///
+    /// A scope that queues callbacks in more than [`Root::max_settled_rounds`] consecutive rounds,
+    /// e.g. an effect that writes to its own dependencies in an `on_settled` callback, would
+    /// otherwise loop forever. This panics in debug builds and drops the remaining callbacks in
+    /// release builds.
+    fn run_settled_callbacks(&'static self) {
+        // Updates caused by the callbacks are flushed immediately, but should not run the
+        // callbacks again recursively.
+        self.flush_depth.set(1);
+        let mut rounds = SecondaryMap::<NodeId, u32>::new();
+        loop {
+            let queue = self.settled_queue.take();
+            if queue.is_empty() {
+                break;
+            }
+            // Every round is a separate flush.
+            self.rerun_counts.borrow_mut().clear();
+            let mut owners = queue
+                .iter()
+                .map(|(node, _)| *node)
+                .filter(|node| !node.is_null())
+                .collect::<Vec<_>>();
+            owners.sort_unstable();
+            owners.dedup();
+            for owner in owners {
+                let Some(count) = rounds.entry(owner).map(|entry| entry.or_insert(0)) else {
+                    continue;
+                };
+                *count += 1;
+                if *count > self.max_settled_rounds.get() {
+                    self.flush_depth.set(0);
+                    self.settled_loop_detected(owner);
+                    return;
+                }
+            }
+            for (node, f) in queue {
+                // Skip the callback if its scope was disposed in the meantime.
+                if !node.is_null() && !self.nodes.borrow().contains_key(node) {
+                    continue;
+                }
+                let prev = self.current_node.replace(node);
+                untrack(f);
+                self.current_node.set(prev);
+            }
+        }
+        self.flush_depth.set(0);
+    }
+
+    /// Called when `owner` has queued [`on_settled`] callbacks in too many consecutive rounds.
+    fn settled_loop_detected(&'static self, owner: NodeId) {
+        let _ = self.settled_queue.take();
+        #[cfg(debug_assertions)]
+        {
+            let location = NodeHandle(owner, self)
+                .creation_location()
+                .map_or_else(|| "<disposed>".to_string(), ToString::to_string);
+            panic!(
+                "reactive update loop: the effect created at {location} was re-run in more than \
+                {} consecutive rounds of `on_settled` callbacks. This usually means that it \
+                writes to one of its own dependencies in an `on_settled` callback",
+                self.max_settled_rounds.get()
+            );
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = owner;
     }
 }
 
@@ -283,11 +546,47 @@ impl RootHandle {
     }
 }
 
+/// Replaces the dependency tracker of a [`Root`] and restores the previous tracker when dropped.
+///
+/// This ensures that the previous tracker is restored even if the closure that is run with the
+/// new tracker panics, e.g. when the panic is caught by an error boundary.
+struct TrackerGuard<'a> {
+    root: &'a Root,
+    prev: Option<Option<DependencyTracker>>,
+}
+
+impl<'a> TrackerGuard<'a> {
+    fn new(root: &'a Root, tracker: Option<DependencyTracker>) -> Self {
+        let prev = root.tracker.replace(tracker);
+        Self {
+            root,
+            prev: Some(prev),
+        }
+    }
+
+    /// Restores the previous tracker and returns the tracker that was replaced.
+    fn restore(mut self) -> Option<DependencyTracker> {
+        let prev = self.prev.take().unwrap();
+        self.root.tracker.replace(prev)
+    }
+}
+
+impl Drop for TrackerGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(prev) = self.prev.take() {
+            self.root.tracker.replace(prev);
+        }
+    }
+}
+
 /// Tracks nodes that are accessed inside a reactive scope.
 #[derive(Default)]
 pub(crate) struct DependencyTracker {
     /// A list of reactive nodes that were accessed.
     pub dependencies: SmallVec<[NodeId; 1]>,
+    /// The value of [`Root::write_count`] at the time of each access in `dependencies`.
+    #[cfg(debug_assertions)]
+    pub read_at: SmallVec<[u64; 1]>,
 }
 
 impl DependencyTracker {
@@ -319,6 +618,23 @@ impl DependencyTracker {
 /// ```
 #[must_use = "root should be disposed"]
 pub fn create_root(f: impl FnOnce()) -> RootHandle {
+    create_root_get(f).1
+}
+
+/// Creates a new reactive root like [`create_root`], but also returns the value returned by `f`.
+///
+/// This is useful when setting up a root, using the result and then tearing it down
+/// deterministically, e.g. in tests or when rendering on the server.
+///
+/// # Example
+/// ```rust
+/// # use sycamore_reactive::*;
+/// let (signal, root) = create_root_get(|| create_signal(123));
+/// assert_eq!(root.run_in(|| signal.get()), 123);
+/// root.dispose();
+/// ```
+#[must_use = "root should be disposed"]
+pub fn create_root_get<R>(f: impl FnOnce() -> R) -> (R, RootHandle) {
     let _ref = Root::new_static();
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -340,9 +656,11 @@ pub fn create_root(f: impl FnOnce()) -> RootHandle {
     }
 
     Root::set_global(Some(_ref));
-    NodeHandle(_ref.root_node.get(), _ref).run_in(f);
+    _ref.start_flush();
+    let ret = NodeHandle(_ref.root_node.get(), _ref).run_in(f);
+    _ref.end_flush();
     Root::set_global(None);
-    RootHandle { _ref }
+    (ret, RootHandle { _ref })
 }
 
 /// Create a child scope.
@@ -355,6 +673,9 @@ pub fn create_child_scope(f: impl FnOnce()) -> NodeHandle {
 
 /// Adds a callback that is called when the scope is destroyed.
 ///
+/// Cleanup callbacks of a scope are run in reverse registration order (LIFO), before any of its
+/// child scopes are disposed. Use [`on_cleanup_priority`] if registration order is not enough.
+///
 /// # Example
 /// ```rust
 /// # use sycamore_reactive::*;
@@ -369,17 +690,112 @@ pub fn create_child_scope(f: impl FnOnce()) -> NodeHandle {
 /// ```
 #[cfg_attr(debug_assertions, track_caller)]
 pub fn on_cleanup(f: impl FnOnce() + 'static) {
+    on_cleanup_priority(0, f);
+}
+
+/// Adds a callback with the given priority that is called when the scope is destroyed.
+///
+/// Cleanup callbacks with a higher priority are run first. Callbacks with the same priority are run
+/// in reverse registration order. Callbacks registered with [`on_cleanup`] have a priority of `0`.
+///
+/// The priority only orders callbacks within the same scope. Cleanup callbacks of child scopes
+/// are always run after the callbacks of the parent scope, regardless of their priority.
+///
+/// # Example
+/// ```rust
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let child_scope = create_child_scope(|| {
+///     on_cleanup_priority(1, || println!("Runs first"));
+///     on_cleanup(|| println!("Runs third"));
+///     on_cleanup(|| println!("Runs second"));
+/// });
+/// child_scope.dispose();
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn on_cleanup_priority(priority: i32, f: impl FnOnce() + 'static) {
     let root = Root::global();
     if !root.current_node.get().is_null() {
         root.nodes.borrow_mut()[root.current_node.get()]
             .cleanups
-            .push(Box::new(f));
+            .push((priority, Box::new(f)));
+    }
+}
+
+/// Adds a callback that is run once after the reactive graph has settled, i.e. once the current
+/// flush of updates is complete and all the effects that it triggered have run.
+///
+/// A flush is in progress while the closure passed to [`create_root`] is running, inside a
+/// [`batch`], and while the dependents of an updated signal are being updated. If `on_settled` is
+/// called while none of these are in progress, the graph has already settled and `f` is run
+/// immediately.
+///
+/// Unlike an effect, `f` is only run once and does not track any signals. If the current scope is
+/// disposed before the graph settles, `f` is not run at all.
+///
+/// Callbacks that update signals cause another round of effects, which can queue more callbacks.
+/// To prevent an infinite loop, a single scope can only queue callbacks in a limited number of
+/// consecutive rounds. See [`set_max_settled_rounds`].
+///
+/// # Example
+/// ```rust
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let count = create_signal(0);
+/// let double = create_signal(0);
+/// on_settled(move || {
+///     // All the effects have already run.
+///     assert_eq!(double.get(), 2);
+/// });
+/// create_effect(move || double.set(count.get() * 2));
+/// count.set(1);
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn on_settled(f: impl FnOnce() + 'static) {
+    let root = Root::global();
+    if root.flush_depth.get() == 0 {
+        untrack(f);
+    } else {
+        root.settled_queue
+            .borrow_mut()
+            .push((root.current_node.get(), Box::new(f)));
     }
 }
 
+/// Sets the maximum number of consecutive rounds of [`on_settled`] callbacks that a single scope
+/// can queue in the current root. Defaults to 100.
+///
+/// An effect that writes to one of its own dependencies in an `on_settled` callback re-runs after
+/// every round of callbacks, and queues a new callback every time, so the graph would never
+/// settle. When a scope exceeds this limit, this panics in debug builds with the location where
+/// the effect was created. In release builds, the remaining callbacks are dropped instead.
+pub fn set_max_settled_rounds(max: u32) {
+    Root::global().max_settled_rounds.set(max);
+}
+
+/// Sets the maximum number of times that a single effect or memo can be re-run while propagating
+/// an update in the current root. Defaults to 1000.
+///
+/// Effects that write to each other's dependencies keep invalidating each other, so the update
+/// would never finish propagating. When an effect or memo exceeds this limit, this panics in debug
+/// builds with the location where it was created. In release builds, the effect or memo is not
+/// re-run anymore for this update instead, which stops the propagation.
+///
+/// The limit is per flush: it is reset once the update has finished propagating, and before every
+/// round of [`on_settled`] callbacks.
+pub fn set_max_reruns(max: u32) {
+    Root::global().max_reruns.set(max);
+}
+
 /// Batch updates from related signals together and only run memos and effects at the end of the
 /// scope.
 ///
+/// Returns the value returned by `f`. The queued memos and effects have already been run by the
+/// time the value is returned. Nested calls to `batch` are part of the outermost batch, so the
+/// queued updates are only run once at the end of the outermost batch.
+///
 /// # Example
 ///
 /// ```
@@ -387,22 +803,32 @@ pub fn on_cleanup(f: impl FnOnce() + 'static) {
 /// # let _ = create_root(|| {
 /// let state = create_signal(1);
 /// let double = create_memo(move || state.get() * 2);
-/// batch(move || {
-///     state.set(2);
+/// let old = batch(move || {
+///     let old = state.replace(2);
 ///     assert_eq!(double.get(), 2);
+///     old
 /// });
+/// assert_eq!(old, 1);
 /// assert_eq!(double.get(), 4);
 /// # });
 /// ```
 pub fn batch<T>(f: impl FnOnce() -> T) -> T {
     let root = Root::global();
+    if root.batching.get() {
+        return f();
+    }
     root.start_batch();
     let ret = f();
     root.end_batch();
     ret
 }
 
-/// Run the passed closure inside an untracked dependency scope.
+/// Run the passed closure inside an untracked dependency scope and return its value.
+///
+/// Signals that are accessed inside the closure are not tracked by the enclosing memo or effect.
+/// Memos and effects that are created inside the closure still track their own dependencies. Once
+/// the closure returns, or panics, the previous tracking state is restored, so `untrack` can be
+/// nested arbitrarily.
 ///
 /// See also [`ReadSignal::get_untracked`].
 ///
@@ -426,10 +852,8 @@ pub fn untrack<T>(f: impl FnOnce() -> T) -> T {
 
 /// Same as [`untrack`] but for a specific [`Root`].
 pub(crate) fn untrack_in_scope<T>(f: impl FnOnce() -> T, root: &'static Root) -> T {
-    let prev = root.tracker.replace(None);
-    let ret = f();
-    root.tracker.replace(prev);
-    ret
+    let _guard = TrackerGuard::new(root, None);
+    f()
 }
 
 /// Get a handle to the current reactive scope.
@@ -444,10 +868,40 @@ pub fn use_global_scope() -> NodeHandle {
     NodeHandle(root.root_node.get(), root)
 }
 
+/// Prints a warning to the browser console, or to stderr when not running in the browser.
+#[cfg(debug_assertions)]
+fn warn(message: &str) {
+    #[cfg(all(target_arch = "wasm32", feature = "wasm-bindgen"))]
+    {
+        #[wasm_bindgen::prelude::wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = console, js_name = warn)]
+            fn console_warn(message: &str);
+        }
+        console_warn(message);
+    }
+    #[cfg(not(all(target_arch = "wasm32", feature = "wasm-bindgen")))]
+    eprintln!("warning: {message}");
+    #[cfg(test)]
+    tests::WARNINGS.with(|warnings| warnings.borrow_mut().push(message.to_string()));
+}
+
 #[cfg(test)]
 mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
     use crate::*;
 
+    thread_local! {
+        /// The warnings printed by [`super::warn`].
+        pub(super) static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn take_warnings() -> Vec<String> {
+        WARNINGS.with(|warnings| warnings.take())
+    }
+
     #[test]
     fn cleanup() {
         let _ = create_root(|| {
@@ -463,6 +917,59 @@ mod tests {
         });
     }
 
+    #[test]
+    fn cleanup_order_is_lifo() {
+        let _ = create_root(|| {
+            let order = create_signal(Vec::new());
+            let scope = create_child_scope(|| {
+                on_cleanup(move || order.update(|order| order.push("outer 1")));
+                create_child_scope(|| {
+                    on_cleanup(move || order.update(|order| order.push("inner 1")));
+                    on_cleanup(move || order.update(|order| order.push("inner 2")));
+                });
+                on_cleanup(move || order.update(|order| order.push("outer 2")));
+            });
+            scope.dispose();
+            assert_eq!(
+                order.get_clone(),
+                ["outer 2", "outer 1", "inner 2", "inner 1"]
+            );
+        });
+    }
+
+    #[test]
+    fn cleanup_priority() {
+        let _ = create_root(|| {
+            let order = create_signal(Vec::new());
+            let scope = create_child_scope(|| {
+                on_cleanup(move || order.update(|order| order.push("a")));
+                on_cleanup_priority(-1, move || order.update(|order| order.push("low")));
+                on_cleanup_priority(1, move || order.update(|order| order.push("high 1")));
+                on_cleanup(move || order.update(|order| order.push("b")));
+                on_cleanup_priority(1, move || order.update(|order| order.push("high 2")));
+            });
+            scope.dispose();
+            assert_eq!(order.get_clone(), ["high 2", "high 1", "b", "a", "low"]);
+        });
+    }
+
+    #[test]
+    fn create_root_get_value_and_dispose() {
+        let cleanup_called = Rc::new(Cell::new(false));
+        let (value, root) = create_root_get({
+            let cleanup_called = Rc::clone(&cleanup_called);
+            move || {
+                let signal = create_signal(123);
+                on_cleanup(move || cleanup_called.set(true));
+                signal.get()
+            }
+        });
+        assert_eq!(value, 123);
+        assert!(!cleanup_called.get());
+        root.dispose();
+        assert!(cleanup_called.get());
+    }
+
     #[test]
     fn cleanup_in_effect() {
         let _ = create_root(|| {
@@ -510,6 +1017,85 @@ mod tests {
         });
     }
 
+    #[test]
+    fn untrack_returns_value() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            assert_eq!(untrack(|| untrack(|| state.get() * 2)), 2);
+        });
+    }
+
+    #[test]
+    fn nested_untrack_restores_tracking() {
+        let _ = create_root(|| {
+            let tracked = create_signal(0);
+            let untracked = create_signal(0);
+            let counter = create_signal(0);
+
+            create_effect(move || {
+                untrack(|| {
+                    untrack(|| untracked.track());
+                    untracked.track();
+                });
+                tracked.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            untracked.set(1);
+            assert_eq!(counter.get(), 1);
+
+            tracked.set(1);
+            assert_eq!(counter.get(), 2);
+        });
+    }
+
+    #[test]
+    fn untrack_in_memo_in_effect() {
+        let _ = create_root(|| {
+            let tracked = create_signal(0);
+            let untracked = create_signal(0);
+            let effect_counter = create_signal(0);
+            let memo_counter = create_signal(0);
+
+            create_effect(move || {
+                let memo = create_memo(move || {
+                    memo_counter.set(memo_counter.get_untracked() + 1);
+                    untrack(|| untracked.get()) + tracked.get()
+                });
+                // The memo tracks its own dependencies, which are not tracked by the effect.
+                untrack(|| memo.get());
+                effect_counter.set(effect_counter.get_untracked() + 1);
+            });
+            assert_eq!((effect_counter.get(), memo_counter.get()), (1, 1));
+
+            untracked.set(1);
+            assert_eq!((effect_counter.get(), memo_counter.get()), (1, 1));
+
+            tracked.set(1);
+            assert_eq!((effect_counter.get(), memo_counter.get()), (1, 2));
+        });
+    }
+
+    #[test]
+    fn untrack_restores_tracking_after_panic() {
+        let _ = create_root(|| {
+            let trigger = create_signal(0);
+            let counter = create_signal(0);
+
+            create_effect(move || {
+                let res = std::panic::catch_unwind(|| untrack(|| panic!("untracked panic")));
+                assert!(res.is_err());
+                trigger.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            assert_eq!(counter.get(), 1);
+
+            trigger.set(1);
+            assert_eq!(counter.get(), 2);
+        });
+    }
+
     #[test]
     fn batch_memo() {
         let _ = create_root(|| {
@@ -546,4 +1132,236 @@ mod tests {
             assert_eq!(counter.get(), 4);
         });
     }
+
+    #[test]
+    fn batch_returns_value_after_effects() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let counter = create_signal(0);
+            create_effect(move || {
+                state.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            let value = batch(move || {
+                state.set(2);
+                state.set(3);
+                counter.get_untracked()
+            });
+            assert_eq!(value, 1);
+            assert_eq!(counter.get(), 2);
+        });
+    }
+
+    #[test]
+    fn on_settled_runs_after_initial_effects() {
+        let (log, root) = create_root_get(|| {
+            let log = create_signal(Vec::new());
+            on_settled(move || log.update_silent(|log| log.push("settled")));
+            create_effect(move || log.update_silent(|log| log.push("effect 1")));
+            create_effect(move || log.update_silent(|log| log.push("effect 2")));
+            log
+        });
+        root.run_in(|| {
+            assert_eq!(log.get_clone(), ["effect 1", "effect 2", "settled"]);
+        });
+        root.dispose();
+    }
+
+    #[test]
+    fn on_settled_runs_after_propagation() {
+        let ((state, settled), root) = create_root_get(|| {
+            let state = create_signal(0);
+            let double = create_signal(0);
+            let settled = create_signal(None);
+            create_effect(move || {
+                if state.get() > 0 {
+                    on_settled(move || settled.set(Some(double.get_untracked())));
+                }
+            });
+            create_effect(move || double.set(state.get() * 2));
+            (state, settled)
+        });
+        root.run_in(|| {
+            state.set(1);
+            assert_eq!(settled.get(), Some(2));
+        });
+        root.dispose();
+    }
+
+    #[test]
+    fn on_settled_runs_immediately_when_settled() {
+        let (_, root) = create_root_get(|| {});
+        root.run_in(|| {
+            let counter = create_signal(0);
+            on_settled(move || counter.set(counter.get() + 1));
+            assert_eq!(counter.get(), 1);
+        });
+        root.dispose();
+    }
+
+    #[test]
+    fn on_settled_skipped_when_scope_disposed() {
+        let _ = create_root(|| {
+            let counter = create_signal(0);
+            batch(move || {
+                let scope = create_child_scope(move || {
+                    on_settled(move || counter.set(counter.get() + 1));
+                });
+                scope.dispose();
+            });
+            assert_eq!(counter.get(), 0);
+        });
+    }
+
+    #[test]
+    fn on_settled_rounds_below_limit() {
+        let (state, _root) = create_root_get(|| {
+            set_max_settled_rounds(10);
+            let state = create_signal(0);
+            create_effect(move || {
+                let value = state.get();
+                if value < 10 {
+                    on_settled(move || state.set(value + 1));
+                }
+            });
+            state
+        });
+        assert_eq!(state.get(), 10);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "reactive update loop: the effect created at")]
+    fn on_settled_loop_panics() {
+        let _ = create_root(|| {
+            set_max_settled_rounds(10);
+            let state = create_signal(0);
+            create_effect(move || {
+                let value = state.get();
+                on_settled(move || state.set(value + 1));
+            });
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn warn_when_effect_writes_own_dependency() {
+        let _ = take_warnings();
+        let _ = create_root(|| {
+            let state = create_signal(0);
+            create_effect(move || {
+                if state.get() < 10 {
+                    state.set(state.get() + 1);
+                }
+            });
+            // The effect is not re-run for its own update.
+            assert_eq!(state.get(), 1);
+        });
+        let warnings = take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains(&format!("created at {}:", file!())));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn warn_when_effects_write_each_others_dependencies() {
+        let _ = take_warnings();
+        let _ = create_root(|| {
+            let a = create_signal(0);
+            let b = create_signal(0);
+            let doubled = create_memo(move || b.get() * 2);
+            create_effect(move || a.set(doubled.get() + 1));
+            create_effect(move || b.set(a.get()));
+        });
+        assert_eq!(take_warnings().len(), 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn no_warning_when_write_comes_before_read() {
+        let _ = take_warnings();
+        let _ = create_root(|| {
+            let state = create_signal(0);
+            let other = create_signal(0);
+            create_effect(move || {
+                state.set(1);
+                other.set(state.get());
+            });
+            state.set(2);
+            assert_eq!(other.get(), 1);
+        });
+        assert!(take_warnings().is_empty());
+    }
+
+    /// Creates an effect that writes to a signal `writes` times in a single run, and an effect
+    /// that depends on that signal. Returns the signal that triggers the first effect and the
+    /// number of times that the second effect has run.
+    fn create_rerunning_effects(writes: u32) -> (Signal<()>, Signal<u32>) {
+        let trigger = create_signal(());
+        let state = create_signal(0);
+        let runs = create_signal(0);
+        create_effect(move || {
+            state.track();
+            runs.set(runs.get_untracked() + 1);
+        });
+        create_effect(move || {
+            trigger.track();
+            for _ in 0..writes {
+                state.set(state.get_untracked() + 1);
+            }
+        });
+        (trigger, runs)
+    }
+
+    #[test]
+    fn reruns_below_limit() {
+        let ((trigger, runs), _root) = create_root_get(|| {
+            set_max_reruns(5);
+            create_rerunning_effects(5)
+        });
+        assert_eq!(runs.get(), 6);
+        // The limit is reset for every flush.
+        trigger.set(());
+        assert_eq!(runs.get(), 11);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "reactive update loop: the effect or memo created at")]
+    fn rerun_loop_panics() {
+        let _ = create_root(|| {
+            set_max_reruns(5);
+            create_rerunning_effects(6);
+        });
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn rerun_loop_stops_propagation() {
+        let ((trigger, runs), _root) = create_root_get(|| {
+            set_max_reruns(5);
+            create_rerunning_effects(6)
+        });
+        assert_eq!(runs.get(), 6);
+        trigger.set(());
+        assert_eq!(runs.get(), 11);
+    }
+
+    #[test]
+    fn nested_batch_runs_effects_once() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let counter = create_signal(0);
+            create_effect(move || {
+                state.track();
+                counter.set(counter.get_untracked() + 1);
+            });
+            batch(move || {
+                batch(move || state.set(2));
+                assert_eq!(counter.get(), 1);
+                state.set(3);
+            });
+            assert_eq!(counter.get(), 2);
+        });
+    }
 }
@@ -1,11 +1,12 @@
 //! Reactive signals.
 
-use std::cell::{Ref, RefMut};
+use std::cell::{Cell, Ref, RefMut};
 use std::fmt;
 use std::fmt::Formatter;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::ops::{AddAssign, Deref, DivAssign, MulAssign, RemAssign, SubAssign};
+use std::rc::Rc;
 
 use slotmap::Key;
 use smallvec::SmallVec;
@@ -118,6 +119,60 @@ pub fn create_signal<T>(value: T) -> Signal<T> {
     signal
 }
 
+/// Create a new [`Signal`] with a custom equality function.
+///
+/// When the value of the signal is replaced, e.g. with [`Signal::set`], `eq` is called with the
+/// previous and the new value. If it returns `true`, the new value is still stored but dependents
+/// are not notified. This is useful when the [`PartialEq`] implementation of the type is not
+/// suitable for change detection, e.g. if it compares a field that should be ignored. This is the
+/// writable counterpart of [`create_selector_with`].
+///
+/// Only writes that replace the value use `eq`: [`Signal::set`], [`Signal::replace`],
+/// [`Signal::take`] and [`Signal::set_fn`]. Since [`Signal::update`] mutates the value in place,
+/// there is no previous value to compare to, so it always notifies dependents.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// #[derive(Clone, PartialEq)]
+/// struct Reading {
+///     value: i32,
+///     timestamp: u64,
+/// }
+///
+/// // Only notify if the value changes, not the timestamp.
+/// let reading = create_signal_with(Reading { value: 1, timestamp: 0 }, |a, b| a.value == b.value);
+/// let runs = create_signal(0);
+/// create_effect(move || {
+///     reading.track();
+///     runs.set_silent(runs.get_untracked() + 1);
+/// });
+///
+/// reading.set(Reading { value: 1, timestamp: 1 });
+/// assert_eq!(runs.get(), 1);
+/// reading.set(Reading { value: 2, timestamp: 2 });
+/// assert_eq!(runs.get(), 2);
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_signal_with<T>(value: T, mut eq: impl FnMut(&T, &T) -> bool + 'static) -> Signal<T> {
+    let signal = create_signal(value);
+    let eq: SignalEq = Box::new(move |old, new| {
+        eq(
+            old.downcast_ref().expect("wrong signal type"),
+            new.downcast_ref().expect("wrong signal type"),
+        )
+    });
+    signal
+        .0
+        .root
+        .signal_eqs
+        .borrow_mut()
+        .insert(signal.0.id, eq);
+    signal
+}
+
 /// Creates a new [`Signal`] with the `value` field set to `None`.
 #[cfg_attr(debug_assertions, track_caller)]
 pub(crate) fn create_empty_signal<T>() -> Signal<T> {
@@ -135,6 +190,8 @@ pub(crate) fn create_empty_signal<T>() -> Signal<T> {
         mark: Mark::None,
         #[cfg(debug_assertions)]
         created_at: std::panic::Location::caller(),
+        #[cfg(debug_assertions)]
+        last_write: 0,
     });
     // Add the signal to the parent's `children` list.
     let current_node = root.current_node.get();
@@ -187,6 +244,25 @@ impl<T> ReadSignal<T> {
         self.root.nodes.borrow().get(self.id).is_some()
     }
 
+    /// Returns the location in the source code where the signal was created. This is also
+    /// available for memos and for signals that have already been disposed.
+    ///
+    /// This is only available in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let signal = create_signal(0);
+    /// # #[cfg(debug_assertions)]
+    /// println!("signal was created at {}", signal.creation_location());
+    /// # });
+    /// ```
+    #[cfg(debug_assertions)]
+    pub fn creation_location(self) -> &'static std::panic::Location<'static> {
+        self.created_at
+    }
+
     /// Disposes the signal, i.e. frees up the memory held on by this signal. Accessing a signal
     /// after it has been disposed immediately causes a panic.
     pub fn dispose(self) {
@@ -194,11 +270,14 @@ impl<T> ReadSignal<T> {
     }
 
     fn get_disposed_panic_message(self) -> String {
+        const MESSAGE: &str = "signal was disposed, most likely because the scope that created it \
+            was disposed. Use `try_get` or `is_alive` if the signal might be accessed after that";
+
         #[cfg(not(debug_assertions))]
-        return "signal was disposed".to_string();
+        return MESSAGE.to_string();
 
         #[cfg(debug_assertions)]
-        return format!("signal was disposed. Created at {}", self.created_at);
+        return format!("{MESSAGE}. Created at {}", self.created_at);
     }
 
     /// Get the value of the signal without tracking it. The type must implement [`Copy`]. If this
@@ -228,6 +307,17 @@ impl<T> ReadSignal<T> {
     /// Get the value of the signal without tracking it. The type is [`Clone`]-ed automatically.
     ///
     /// This is the cloned equivalent of [`ReadSignal::get_untracked`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let name = create_signal("Sycamore".to_string());
+    /// let greeting = create_memo(move || format!("Hello {}!", name.get_clone_untracked()));
+    /// name.set("World".to_string());
+    /// assert_eq!(greeting.get_clone(), "Hello Sycamore!");
+    /// # });
+    /// ```
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn get_clone_untracked(self) -> T
     where
@@ -236,6 +326,55 @@ impl<T> ReadSignal<T> {
         self.with_untracked(Clone::clone)
     }
 
+    /// Get the value of the signal without tracking it. The type must implement [`Copy`].
+    ///
+    /// This is an alias for [`ReadSignal::get_untracked`], following the terminology used by
+    /// other signal libraries. For types that only implement [`Clone`], use
+    /// [`ReadSignal::peek_clone`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let state = create_signal(0);
+    /// let doubled = create_memo(move || state.peek() * 2);
+    /// state.set(1);
+    /// assert_eq!(state.peek(), 1);
+    /// assert_eq!(doubled.get(), 0);
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn peek(self) -> T
+    where
+        T: Copy,
+    {
+        self.get_untracked()
+    }
+
+    /// Get the value of the signal without tracking it. The type is [`Clone`]-ed automatically.
+    ///
+    /// This is an alias for [`ReadSignal::get_clone_untracked`] and the cloned equivalent of
+    /// [`ReadSignal::peek`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let name = create_signal("Sycamore".to_string());
+    /// let greeting = create_memo(move || format!("Hello {}!", name.peek_clone()));
+    /// name.set("World".to_string());
+    /// assert_eq!(name.peek_clone(), "World");
+    /// assert_eq!(greeting.get_clone(), "Hello Sycamore!");
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn peek_clone(self) -> T
+    where
+        T: Clone,
+    {
+        self.get_clone_untracked()
+    }
+
     /// Get the value of the signal. The type must implement [`Copy`]. If this is not the case, use
     /// [`ReadSignal::get_clone_untracked`] or [`ReadSignal::with_untracked`] instead.
     ///
@@ -296,6 +435,20 @@ impl<T> ReadSignal<T> {
     }
 
     /// Get a value from the signal without tracking it.
+    ///
+    /// This is useful for accessing the value without cloning it.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let names = create_signal(vec!["Alice".to_string()]);
+    /// let len = create_memo(move || names.with_untracked(Vec::len));
+    /// names.update(|names| names.push("Bob".to_string()));
+    /// assert_eq!(names.with_untracked(Vec::len), 2);
+    /// assert_eq!(len.get(), 1);
+    /// # });
+    /// ```
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn with_untracked<U>(self, f: impl FnOnce(&T) -> U) -> U {
         let node = self.get_ref();
@@ -304,21 +457,94 @@ impl<T> ReadSignal<T> {
             .as_ref()
             .expect("cannot read signal while updating");
         let ret = f(value.downcast_ref().expect("wrong signal type"));
+        drop(node);
+        // Dispose any `RcSignal` that was dropped in `f`.
+        self.root.dispose_pending();
         ret
     }
 
     /// Get a value from the signal.
     ///
     /// When called inside a reactive scope, the signal will be automatically tracked.
+    ///
+    /// This is useful for accessing the value without cloning it.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let names = create_signal(vec!["Alice".to_string()]);
+    /// let len = create_memo(move || names.with(Vec::len));
+    /// assert_eq!(len.get(), 1);
+    /// names.update(|names| names.push("Bob".to_string()));
+    /// assert_eq!(len.get(), 2);
+    /// # });
+    /// ```
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn with<U>(self, f: impl FnOnce(&T) -> U) -> U {
         self.track();
         self.with_untracked(f)
     }
 
+    /// Get a value from the signal, or `None` if the signal has been disposed.
+    ///
+    /// This is the non-panicking equivalent of [`ReadSignal::with`] and is useful in code that
+    /// might run after the scope that owns the signal has been disposed, e.g. in a callback of a
+    /// timer or of a JS API.
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn try_with<U>(self, f: impl FnOnce(&T) -> U) -> Option<U> {
+        if !self.is_alive() {
+            return None;
+        }
+        Some(self.with(f))
+    }
+
+    /// Get the value of the signal, or `None` if the signal has been disposed. The type must
+    /// implement [`Copy`].
+    ///
+    /// This is the non-panicking equivalent of [`ReadSignal::get`]. When called inside a reactive
+    /// scope, the signal will be automatically tracked if it is still alive.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let state = create_signal(0);
+    /// assert_eq!(state.try_get(), Some(0));
+    ///
+    /// state.dispose();
+    /// assert_eq!(state.try_get(), None);
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn try_get(self) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.try_with(|value| *value)
+    }
+
+    /// Get the value of the signal, or `None` if the signal has been disposed. The type is
+    /// [`Clone`]-ed automatically.
+    ///
+    /// This is the cloned equivalent of [`ReadSignal::try_get`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn try_get_clone(self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.try_with(Clone::clone)
+    }
+
     /// Creates a new [memo](create_memo) from this signal and a function. The resulting memo will
     /// be created in the current reactive scope.
     ///
+    /// This is also available on [`Signal`] and is useful for passing a read-only projection of a
+    /// larger value (e.g. a single field of a struct) to a component. Like any memo, the
+    /// projection is computed eagerly whenever the signal changes. Since a memo notifies its
+    /// dependents every time it is recomputed, use [`create_selector`] instead if dependents
+    /// should only be notified when the projected value actually changes.
+    ///
     /// # Example
     /// ```
     /// # use sycamore_reactive::*;
@@ -328,6 +554,15 @@ impl<T> ReadSignal<T> {
     /// assert_eq!(doubled.get(), 0);
     /// state.set(1);
     /// assert_eq!(doubled.get(), 2);
+    ///
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    /// let user = create_signal(User { name: "Alice".to_string(), age: 30 });
+    /// let age = user.map(|user| user.age);
+    /// user.update(|user| user.age += 1);
+    /// assert_eq!(age.get(), 31);
     /// # });
     /// ```
     #[cfg_attr(debug_assertions, track_caller)]
@@ -335,6 +570,75 @@ impl<T> ReadSignal<T> {
         create_memo(move || self.with(&mut f))
     }
 
+    /// Creates a new [memo](create_memo) that holds a clone of a part of this signal's value.
+    ///
+    /// This is a shorthand for `signal.map(|v| f(v).clone())`, which is convenient for projecting
+    /// a field that is not [`Copy`]. The memo tracks the whole signal, so the field is cloned
+    /// again whenever any part of the value changes.
+    ///
+    /// If the field is [`Copy`], or if it is only needed for a single read, there is no need to
+    /// create a memo at all: `signal.with(|v| v.field)` reads the field without cloning the rest of
+    /// the value.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// struct Todo {
+    ///     title: String,
+    ///     completed: bool,
+    /// }
+    /// let todo = create_signal(Todo { title: "Write docs".to_string(), completed: false });
+    /// let title = todo.map_ref(|todo| &todo.title);
+    /// assert_eq!(title.get_clone(), "Write docs");
+    ///
+    /// todo.update(|todo| todo.title.push('!'));
+    /// assert_eq!(title.get_clone(), "Write docs!");
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn map_ref<U: Clone>(self, f: impl Fn(&T) -> &U + 'static) -> ReadSignal<U> {
+        create_memo(move || self.with(|value| f(value).clone()))
+    }
+
+    /// Immediately re-runs the computation of this memo, even if none of its dependencies have
+    /// changed, and returns the new value. The returned value is not tracked.
+    ///
+    /// This bypasses the dirty-tracking of the reactive graph and is meant for tests and for the
+    /// rare cases where a memo reads from an impure source that was changed outside of the
+    /// reactive system. Dependents are updated as usual if the memo notifies them, e.g. for a
+    /// [selector](create_selector) only if the recomputed value is different.
+    ///
+    /// If the signal is not a memo, this simply returns the current value.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use std::rc::Rc;
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let external = Rc::new(Cell::new(1));
+    /// let memo = create_memo({
+    ///     let external = Rc::clone(&external);
+    ///     move || external.get() * 2
+    /// });
+    /// external.set(2);
+    /// assert_eq!(memo.get(), 2); // The memo does not know that `external` has changed.
+    /// assert_eq!(memo.force_recompute(), 4);
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn force_recompute(self) -> T
+    where
+        T: Clone,
+    {
+        if !self.is_alive() {
+            panic!("{}", self.get_disposed_panic_message());
+        }
+        self.root.force_node_update(self.id);
+        self.get_clone_untracked()
+    }
+
     /// Track the signal in the current reactive scope. This is done automatically when calling
     /// [`ReadSignal::get`] and other similar methods.
     ///
@@ -353,6 +657,8 @@ impl<T> ReadSignal<T> {
     pub fn track(self) {
         if let Some(tracker) = &mut *self.root.tracker.borrow_mut() {
             tracker.dependencies.push(self.id);
+            #[cfg(debug_assertions)]
+            tracker.read_at.push(self.root.write_count.get());
         }
     }
 }
@@ -362,6 +668,12 @@ impl<T> Signal<T> {
     /// signals. As such, this is generally not recommended as it can easily lead to state
     /// inconsistencies.
     ///
+    /// Dependents keep their old values until the signal is updated again non-silently, so only
+    /// use this if nothing needs to react to the change. One such case is syncing a value from
+    /// an external source that already reflects the new value, e.g. storing the value of an
+    /// uncontrolled input or the currently focused element. Triggering the dependents there would
+    /// only write the same value back into the DOM.
+    ///
     /// # Example
     /// ```
     /// # use sycamore_reactive::*;
@@ -396,6 +708,42 @@ impl<T> Signal<T> {
         self.replace(new);
     }
 
+    /// Set a new value for the signal only if it is different from the current value. Dependents
+    /// are only updated if the value was changed. Returns whether the value was changed.
+    ///
+    /// Unlike [`set`](Self::set), which always updates the dependents, this avoids needlessly
+    /// re-running effects when writing a value that is equal to the current one. This is the
+    /// write-side counterpart of [`create_selector`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let state = create_signal(0);
+    /// let runs = create_signal(0);
+    /// create_effect(move || {
+    ///     state.track();
+    ///     runs.set_silent(runs.get_untracked() + 1);
+    /// });
+    /// assert!(!state.set_if_changed(0));
+    /// assert_eq!(runs.get(), 1);
+    /// assert!(state.set_if_changed(1));
+    /// assert_eq!(runs.get(), 2);
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn set_if_changed(self, new: T) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.with_untracked(|value| *value == new) {
+            false
+        } else {
+            self.set(new);
+            true
+        }
+    }
+
     /// Silently set a new value for the signal and return the previous value.
     ///
     /// This is the silent version of [`Signal::replace`].
@@ -418,7 +766,31 @@ impl<T> Signal<T> {
     /// ```
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn replace(self, new: T) -> T {
-        self.update(|val| std::mem::replace(val, new))
+        let prev = self.replace_silent(new);
+        self.notify_replaced(&prev);
+        prev
+    }
+
+    /// Update any dependents after the value was replaced, unless the signal has a custom equality
+    /// function (see [`create_signal_with`]) which considers the previous and new value equal.
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn notify_replaced(self, prev: &T) {
+        let eqs = &self.0.root.signal_eqs;
+        // Take the equality function out of the table while it runs, since it could write to
+        // another signal with a custom equality function.
+        let eq = eqs.borrow_mut().remove(self.0.id);
+        let is_equal = if let Some(mut eq) = eq {
+            let is_equal = self.with_untracked(|new| eq(prev, new));
+            if self.is_alive() {
+                eqs.borrow_mut().insert(self.0.id, eq);
+            }
+            is_equal
+        } else {
+            false
+        };
+        if !is_equal {
+            self.0.root.propagate_updates(self.0.id);
+        }
     }
 
     /// Silently gets the value of the signal and sets the new value to the default value.
@@ -454,9 +826,22 @@ impl<T> Signal<T> {
 
     /// Update the value of the signal silently. This will not trigger any updates in dependent
     /// signals. As such, this is generally not recommended as it can easily lead to state
-    /// inconsistencies.
+    /// inconsistencies. See [`Signal::set_silent`] for when this can be useful.
     ///
     /// This is the silent version of [`Signal::update`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let state = create_signal(vec![1]);
+    /// let len = create_memo(move || state.with(Vec::len));
+    /// state.update_silent(|val| val.push(2));
+    /// assert_eq!(len.get(), 1); // The memo was not updated.
+    /// state.update(|val| val.push(3));
+    /// assert_eq!(len.get(), 3);
+    /// # });
+    /// ```
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn update_silent<U>(self, f: impl FnOnce(&mut T) -> U) -> U {
         let mut value = self
@@ -512,23 +897,290 @@ impl<T> Signal<T> {
     /// ```
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn set_fn(self, f: impl FnOnce(&T) -> T) {
-        self.update(move |val| *val = f(val));
+        let prev = self.update_silent(move |val| {
+            let new = f(val);
+            std::mem::replace(val, new)
+        });
+        self.notify_replaced(&prev);
     }
 
     /// Split the signal into a reader/writer pair.
     ///
+    /// Both halves refer to the same signal, so writing to the [`WriteSignal`] notifies everything
+    /// that depends on the [`ReadSignal`]. This is useful for only giving read or write access to
+    /// a component.
+    ///
     /// # Example
     /// ```
     /// # use sycamore_reactive::*;
     /// # create_root(|| {
-    /// let (read_signal, mut write_signal) = create_signal(0).split();
+    /// let (read_signal, write_signal) = create_signal(0).split();
     /// assert_eq!(read_signal.get(), 0);
-    /// write_signal(1);
+    /// write_signal.set(1);
     /// assert_eq!(read_signal.get(), 1);
     /// # });
     /// ```
-    pub fn split(self) -> (ReadSignal<T>, impl Fn(T) -> T) {
-        (*self, move |value| self.replace(value))
+    pub fn split(self) -> (ReadSignal<T>, WriteSignal<T>) {
+        (*self, WriteSignal(self))
+    }
+
+    /// Detaches the signal from its reactive scope and returns an [`RcSignal`] that owns it.
+    ///
+    /// The signal is no longer disposed together with the scope in which it was created. Instead,
+    /// it is disposed once the last [`RcSignal`] is dropped. Existing copies of this `Signal` can
+    /// still be used as long as the signal is alive.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// let scope_signal = create_signal(None);
+    /// let scope = create_child_scope(|| scope_signal.set(Some(create_signal(123).into_rc())));
+    /// scope.dispose();
+    /// // The signal outlives the scope in which it was created.
+    /// let signal = scope_signal.get_clone().unwrap();
+    /// assert_eq!(signal.get(), 123);
+    /// # });
+    /// ```
+    pub fn into_rc(self) -> RcSignal<T> {
+        let root = self.0.root;
+        let mut nodes = root.nodes.borrow_mut();
+        let parent = std::mem::replace(&mut nodes[self.0.id].parent, root.root_node.get());
+        if let Some(parent) = nodes.get_mut(parent) {
+            parent.children.retain(|&id| id != self.0.id);
+        }
+        if let Some(root_node) = nodes.get_mut(root.root_node.get()) {
+            root_node.children.push(self.0.id);
+        }
+        RcSignal(Rc::new(RcSignalInner { signal: self }))
+    }
+
+    /// Creates a writable projection of a part of this signal's value, e.g. a single field of a
+    /// struct. The projection is a new signal in the current reactive scope.
+    ///
+    /// `get` extracts the projected value and `set` writes it back into the parent value. The two
+    /// signals are kept in sync: when this signal changes, the projection is updated with `get`,
+    /// and when the projection is set, this signal is updated once with `set`. This makes it
+    /// possible to use `bind:value` on a nested field.
+    ///
+    /// Like [`map`](ReadSignal::map), the projection tracks the whole signal and is recomputed
+    /// whenever any part of the value changes.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// # create_root(|| {
+    /// struct Form {
+    ///     name: String,
+    ///     email: String,
+    /// }
+    /// let form = create_signal(Form { name: String::new(), email: String::new() });
+    /// let name = form.focus(|form| form.name.clone(), |form, name| form.name = name);
+    ///
+    /// name.set("Alice".to_string());
+    /// assert_eq!(form.with(|form| form.name.clone()), "Alice");
+    ///
+    /// form.update(|form| form.name = "Bob".to_string());
+    /// assert_eq!(name.get_clone(), "Bob");
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn focus<U: Clone>(
+        self,
+        get: impl Fn(&T) -> U + 'static,
+        set: impl Fn(&mut T, U) + 'static,
+    ) -> Signal<U> {
+        let focused = create_signal(self.with_untracked(&get));
+        // Set while one side is being written from the other to prevent the change from being
+        // propagated back.
+        let is_syncing = Rc::new(Cell::new(false));
+
+        create_effect({
+            let is_syncing = Rc::clone(&is_syncing);
+            move || {
+                let value = self.with(&get);
+                if !is_syncing.get() {
+                    is_syncing.set(true);
+                    untrack(|| focused.set(value));
+                    is_syncing.set(false);
+                }
+            }
+        });
+        let mut is_initial = true;
+        create_effect(move || {
+            focused.track();
+            if std::mem::replace(&mut is_initial, false) || is_syncing.get() {
+                return;
+            }
+            is_syncing.set(true);
+            untrack(|| self.update(|value| set(value, focused.get_clone_untracked())));
+            is_syncing.set(false);
+        });
+
+        focused
+    }
+}
+
+/// The write half of a [`Signal`], obtained with [`Signal::split`].
+///
+/// Unlike a [`Signal`], the value cannot be read, except through [`update`](Self::update).
+pub struct WriteSignal<T: 'static>(Signal<T>);
+
+impl<T> WriteSignal<T> {
+    /// Returns `true` if the signal is still alive, i.e. has not yet been disposed.
+    pub fn is_alive(self) -> bool {
+        self.0.is_alive()
+    }
+
+    /// Silently set a new value for the signal. See [`Signal::set_silent`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn set_silent(self, new: T) {
+        self.0.set_silent(new);
+    }
+
+    /// Set a new value for the signal and automatically update any dependents. See
+    /// [`Signal::set`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn set(self, new: T) {
+        self.0.set(new);
+    }
+
+    /// Set a new value for the signal only if it is different from the current value. See
+    /// [`Signal::set_if_changed`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn set_if_changed(self, new: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.set_if_changed(new)
+    }
+
+    /// Update the value of the signal silently. See [`Signal::update_silent`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn update_silent<U>(self, f: impl FnOnce(&mut T) -> U) -> U {
+        self.0.update_silent(f)
+    }
+
+    /// Update the value of the signal and automatically update any dependents. See
+    /// [`Signal::update`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn update<U>(self, f: impl FnOnce(&mut T) -> U) -> U {
+        self.0.update(f)
+    }
+
+    /// Use a function to produce a new value and sets the value silently. See
+    /// [`Signal::set_fn_silent`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn set_fn_silent(self, f: impl FnOnce(&T) -> T) {
+        self.0.set_fn_silent(f);
+    }
+
+    /// Use a function to produce a new value and sets the value. See [`Signal::set_fn`].
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn set_fn(self, f: impl FnOnce(&T) -> T) {
+        self.0.set_fn(f);
+    }
+}
+
+impl<T> Clone for WriteSignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for WriteSignal<T> {}
+
+impl<T> fmt::Debug for WriteSignal<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteSignal").finish_non_exhaustive()
+    }
+}
+
+/// A reference-counted [`Signal`] that is not tied to the reactive scope in which it was created.
+///
+/// This is useful for sharing state between scopes that are disposed independently of each other,
+/// e.g. a signal for a row that is shared between a list and a detail view. An `RcSignal` is
+/// created with [`create_rc_signal`] or [`Signal::into_rc`] and dereferences to a [`Signal`], so it
+/// can be read and written to just like one.
+///
+/// # Drop semantics
+/// The signal is disposed when the last clone of the `RcSignal` is dropped, or when the reactive
+/// root is disposed, whichever happens first. If the last clone is dropped while the reactive graph
+/// is borrowed, e.g. inside [`ReadSignal::with`], disposing the signal is deferred until the
+/// borrow is released. A [`Signal`] handle obtained from an `RcSignal` with
+/// [`RcSignal::signal`] does not keep the signal alive, so it must not be used after the last
+/// `RcSignal` is dropped.
+pub struct RcSignal<T: 'static>(Rc<RcSignalInner<T>>);
+
+struct RcSignalInner<T: 'static> {
+    signal: Signal<T>,
+}
+
+impl<T> Drop for RcSignalInner<T> {
+    fn drop(&mut self) {
+        let root = self.signal.0.root;
+        if root.nodes.try_borrow_mut().is_ok() {
+            if self.signal.is_alive() {
+                self.signal.dispose();
+            }
+        } else {
+            // The reactive graph is currently borrowed, e.g. because the `RcSignal` was dropped
+            // while reading another signal. Dispose the signal once the borrow is released.
+            root.pending_disposals.borrow_mut().push(self.signal.0.id);
+        }
+    }
+}
+
+/// Create a new [`RcSignal`] that is not tied to the current reactive scope.
+///
+/// See [`RcSignal`] for more information.
+///
+/// # Example
+/// ```
+/// # use sycamore_reactive::*;
+/// # create_root(|| {
+/// let row = create_rc_signal("Row".to_string());
+///
+/// let list_scope = create_child_scope({
+///     let row = row.clone();
+///     move || { let _ = create_memo(move || row.get_clone()); }
+/// });
+/// list_scope.dispose();
+///
+/// // The signal is still alive since `row` has not been dropped yet.
+/// row.set("Updated".to_string());
+/// # });
+/// ```
+#[cfg_attr(debug_assertions, track_caller)]
+pub fn create_rc_signal<T>(value: T) -> RcSignal<T> {
+    create_signal(value).into_rc()
+}
+
+impl<T> RcSignal<T> {
+    /// Returns a [`Signal`] handle to the underlying signal. The handle does not keep the signal
+    /// alive.
+    pub fn signal(&self) -> Signal<T> {
+        self.0.signal
+    }
+}
+
+impl<T> Clone for RcSignal<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T> Deref for RcSignal<T> {
+    type Target = Signal<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.signal
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RcSignal<T> {
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.signal.fmt(f)
     }
 }
 
@@ -727,6 +1379,15 @@ impl<T: Copy> FnOnce<(T,)> for Signal<T> {
     }
 }
 
+#[cfg(feature = "nightly")]
+impl<T> FnOnce<(T,)> for WriteSignal<T> {
+    type Output = ();
+
+    extern "rust-call" fn call_once(self, (val,): (T,)) -> Self::Output {
+        self.set(val)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -772,6 +1433,125 @@ mod tests {
         });
     }
 
+    #[test]
+    fn set_if_changed_signal() {
+        let _ = create_root(|| {
+            let state = create_signal("a".to_string());
+            let runs = create_signal(0);
+            create_effect(move || {
+                state.track();
+                runs.set_silent(runs.get_untracked() + 1);
+            });
+
+            assert!(!state.set_if_changed("a".to_string()));
+            assert_eq!(runs.get(), 1);
+            assert!(state.set_if_changed("b".to_string()));
+            assert_eq!(runs.get(), 2);
+            assert_eq!(state.get_clone(), "b");
+
+            let (_, write) = state.split();
+            assert!(!write.set_if_changed("b".to_string()));
+            assert_eq!(runs.get(), 2);
+        });
+    }
+
+    #[test]
+    fn signal_with_custom_eq() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Reading {
+            value: i32,
+            timestamp: u64,
+        }
+
+        let _ = create_root(|| {
+            let reading = create_signal_with(
+                Reading {
+                    value: 1,
+                    timestamp: 0,
+                },
+                |a, b| a.value == b.value,
+            );
+            let runs = create_signal(0);
+            create_effect(move || {
+                reading.track();
+                runs.set_silent(runs.get_untracked() + 1);
+            });
+            assert_eq!(runs.get(), 1);
+
+            // Not equal according to `PartialEq`, but equal according to the custom function.
+            reading.set(Reading {
+                value: 1,
+                timestamp: 1,
+            });
+            assert_eq!(runs.get(), 1);
+            // The new value is still stored.
+            assert_eq!(reading.get_clone_untracked().timestamp, 1);
+
+            reading.set(Reading {
+                value: 2,
+                timestamp: 2,
+            });
+            assert_eq!(runs.get(), 2);
+
+            reading.set_fn(|reading| Reading {
+                timestamp: 3,
+                ..reading.clone()
+            });
+            assert_eq!(runs.get(), 2);
+            reading.set_fn(|reading| Reading {
+                value: 3,
+                ..reading.clone()
+            });
+            assert_eq!(runs.get(), 3);
+
+            // `update` has no previous value to compare to, so it always notifies.
+            reading.update(|reading| reading.timestamp = 4);
+            assert_eq!(runs.get(), 4);
+        });
+    }
+
+    #[test]
+    fn update_silent_signal() {
+        let _ = create_root(|| {
+            let state = create_signal(vec![1]);
+            let runs = create_signal(0);
+            create_effect(move || {
+                state.track();
+                runs.set_silent(runs.get_untracked() + 1);
+            });
+            assert_eq!(runs.get(), 1);
+
+            state.update_silent(|val| val.push(2));
+            assert_eq!(runs.get(), 1); // effect did not run.
+            assert_eq!(state.get_clone(), [1, 2]);
+
+            state.update(|val| val.push(3));
+            assert_eq!(runs.get(), 2);
+        });
+    }
+
+    #[test]
+    fn peek_signal() {
+        let _ = create_root(|| {
+            let state = create_signal(0);
+            let name = create_signal("a".to_string());
+
+            let counter = create_signal(0);
+            create_effect(move || {
+                counter.set_silent(counter.get_untracked() + 1);
+                let _ = state.peek();
+                let _ = name.peek_clone();
+            });
+            assert_eq!(counter.get(), 1);
+
+            state.set(1);
+            name.set("b".to_string());
+            assert_eq!(counter.get(), 1); // not tracked
+            assert_eq!(state.peek(), 1);
+            assert_eq!(name.peek_clone(), "b");
+        });
+    }
+
     #[test]
     fn read_signal() {
         let _ = create_root(|| {
@@ -796,6 +1576,94 @@ mod tests {
         });
     }
 
+    #[test]
+    fn map_signal_field() {
+        let _ = create_root(|| {
+            struct State {
+                count: i32,
+                name: String,
+            }
+            let state = create_signal(State {
+                count: 0,
+                name: "a".to_string(),
+            });
+            let count = state.map(|state| state.count);
+            let name = state.map(|state| state.name.clone());
+
+            state.update(|state| state.count += 1);
+            assert_eq!(count.get(), 1);
+            assert_eq!(name.get_clone(), "a");
+
+            state.update(|state| state.name.push('b'));
+            assert_eq!(count.get(), 1);
+            assert_eq!(name.get_clone(), "ab");
+        });
+    }
+
+    #[test]
+    fn map_ref_signal_field() {
+        let _ = create_root(|| {
+            struct State {
+                count: i32,
+                name: String,
+            }
+            let state = create_signal(State {
+                count: 0,
+                name: "a".to_string(),
+            });
+            let name = state.map_ref(|state| &state.name);
+            let runs = create_signal(0);
+            create_effect(move || {
+                name.track();
+                runs.set_silent(runs.get_untracked() + 1);
+            });
+            assert_eq!(name.get_clone(), "a");
+            assert_eq!(runs.get(), 1);
+
+            state.update(|state| state.name.push('b'));
+            assert_eq!(name.get_clone(), "ab");
+            assert_eq!(runs.get(), 2);
+
+            // The whole signal is tracked.
+            state.update(|state| state.count += 1);
+            assert_eq!(name.get_clone(), "ab");
+            assert_eq!(runs.get(), 3);
+        });
+    }
+
+    #[test]
+    fn focus_signal_field() {
+        let _ = create_root(|| {
+            struct Form {
+                name: String,
+                age: u32,
+            }
+            let form = create_signal(Form {
+                name: "a".to_string(),
+                age: 0,
+            });
+            let name = form.focus(|form| form.name.clone(), |form, name| form.name = name);
+            let updates = create_signal(0);
+            create_effect(move || {
+                form.track();
+                updates.set_silent(updates.get_untracked() + 1);
+            });
+            assert_eq!(updates.get(), 1);
+
+            name.set("b".to_string());
+            assert_eq!(form.with(|form| form.name.clone()), "b");
+            assert_eq!(updates.get(), 2);
+
+            form.update(|form| form.name = "c".to_string());
+            assert_eq!(name.get_clone(), "c");
+            assert_eq!(updates.get(), 3);
+
+            form.update(|form| form.age += 1);
+            assert_eq!(name.get_clone(), "c");
+            assert_eq!(updates.get(), 4);
+        });
+    }
+
     #[test]
     fn take_signal() {
         let _ = create_root(|| {
@@ -826,8 +1694,12 @@ mod tests {
             let (state, set_state) = create_signal(0).split();
             assert_eq!(state.get(), 0);
 
-            set_state(1);
+            set_state.set(1);
             assert_eq!(state.get(), 1);
+
+            let double = create_memo(move || state.get() * 2);
+            set_state.update(|value| *value += 1);
+            assert_eq!(double.get(), 4);
         });
     }
 
@@ -886,4 +1758,138 @@ mod tests {
             assert_eq!(counter.get(), 2);
         });
     }
+
+    #[test]
+    fn rc_signal_shared_between_scopes() {
+        let _ = create_root(|| {
+            let signal = create_rc_signal(0);
+            let handle = signal.signal();
+
+            let a = create_child_scope({
+                let signal = signal.clone();
+                move || on_cleanup(move || signal.set(1))
+            });
+            let b = create_child_scope({
+                let signal = signal.clone();
+                move || on_cleanup(move || drop(signal))
+            });
+            drop(signal);
+
+            a.dispose();
+            assert_eq!(handle.get(), 1);
+            // The last `RcSignal` is dropped when `b` is disposed.
+            b.dispose();
+            assert!(!handle.is_alive());
+        });
+    }
+
+    #[test]
+    fn rc_signal_outlives_scope() {
+        let _ = create_root(|| {
+            let rc = create_signal(None);
+            let scope = create_child_scope(|| rc.set(Some(create_signal(123).into_rc())));
+            scope.dispose();
+            let handle = rc.with(|rc| rc.as_ref().unwrap().signal());
+            assert_eq!(handle.get(), 123);
+
+            // Dropping the `RcSignal` when disposing the signal holding it also disposes it.
+            rc.dispose();
+            assert!(!handle.is_alive());
+        });
+    }
+
+    #[test]
+    fn rc_signal_disposed_with_root() {
+        let mut handle = None;
+        let root = create_root(|| handle = Some(create_rc_signal(0)));
+        let handle = handle.unwrap();
+        root.dispose();
+        assert!(!handle.is_alive());
+        // Dropping the `RcSignal` after the root is disposed does nothing.
+        drop(handle);
+    }
+
+    #[test]
+    fn rc_signal_dropped_while_reading() {
+        let _ = create_root(|| {
+            let rc = create_rc_signal(0);
+            let handle = rc.signal();
+            let other = create_signal(1);
+            // The graph is borrowed while reading `other`, so disposing is deferred.
+            other.with(move |_| drop(rc));
+            assert!(!handle.is_alive());
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn creation_location() {
+        let _ = create_root(|| {
+            let (signal, line) = (create_signal(0), line!());
+            assert_eq!(signal.creation_location().file(), file!());
+            assert_eq!(signal.creation_location().line(), line);
+
+            let (memo, line) = (create_memo(move || signal.get()), line!());
+            assert_eq!(memo.creation_location().line(), line);
+
+            let effect_scope = create_signal(None);
+            let line = line!() + 1;
+            create_effect(move || effect_scope.set(Some(use_current_scope())));
+            let effect_location = effect_scope.get().unwrap().creation_location().unwrap();
+            assert_eq!(effect_location.line(), line);
+
+            let (scope, line) = (create_child_scope(|| {}), line!());
+            assert_eq!(scope.creation_location().unwrap().line(), line);
+
+            // The location is still available on the signal after it is disposed.
+            signal.dispose();
+            assert_eq!(signal.creation_location().file(), file!());
+            scope.dispose();
+            assert!(scope.creation_location().is_none());
+        });
+    }
+
+    #[test]
+    fn try_get_disposed_signal() {
+        let _ = create_root(|| {
+            let signals = create_signal(None);
+            let scope = create_child_scope(move || {
+                signals.set(Some((create_signal(1), create_signal("a".to_string()))));
+            });
+            let (number, name) = signals.get_clone().unwrap();
+            assert_eq!(number.try_get(), Some(1));
+            assert_eq!(name.try_get_clone().as_deref(), Some("a"));
+            assert_eq!(name.try_with(String::len), Some(1));
+
+            scope.dispose();
+            assert_eq!(number.try_get(), None);
+            assert_eq!(name.try_get_clone(), None);
+            assert_eq!(name.try_with(String::len), None);
+        });
+    }
+
+    #[test]
+    fn try_get_tracks_signal() {
+        let _ = create_root(|| {
+            let state = create_signal(1);
+            let doubled = create_memo(move || state.try_get().map(|state| state * 2));
+            assert_eq!(doubled.get(), Some(2));
+            state.set(2);
+            assert_eq!(doubled.get(), Some(4));
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn disposed_signal_panic_message_has_creation_location() {
+        let _ = create_root(|| {
+            let (signal, line) = (create_signal(0), line!());
+            signal.dispose();
+            let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| signal.get()))
+                .unwrap_err();
+            let message = err.downcast_ref::<String>().unwrap();
+            assert!(message.starts_with("signal was disposed"));
+            assert!(message.contains(&format!("Created at {}:{line}:", file!())));
+        });
+    }
 }
@@ -68,9 +68,13 @@ impl_trackable_deps_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
 ///
 /// # Params
 /// * `deps` - A list of signals/memos that are tracked. This can be a single signal or it can be a
-///   tuple of signals.
+///   tuple of up to 12 signals, which can be of different types.
 /// * `f` - The callback function. Any dependencies that are accessed in here will not be tracked.
 ///
+/// The returned closure is not run by `on` itself. When passed to [`create_effect`] or
+/// [`create_memo`], it runs immediately like any other effect or memo, and then re-runs whenever
+/// one of `deps` changes.
+///
 /// # Example
 /// ```
 /// # use sycamore_reactive::*;
@@ -84,6 +88,13 @@ impl_trackable_deps_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
 ///
 /// state.set(1);
 /// // Prints "State changed. New state value = 1"
+///
+/// // Track several signals with a tuple.
+/// let name = create_signal("Alice".to_string());
+/// let age = create_signal(30);
+/// create_effect(on((name, age), move || {
+///     println!("{} is {} years old", name.get_clone(), age.get());
+/// }));
 /// # });
 /// ```
 pub fn on<T>(
@@ -120,4 +131,29 @@ mod tests {
             assert_eq!(counter.get(), 2);
         });
     }
+
+    #[test]
+    fn on_tuple_deps() {
+        let _ = create_root(move || {
+            let a = create_signal(0);
+            let b = create_signal("b".to_string());
+            let c = create_memo(move || a.get() * 2);
+            let untracked = create_signal(());
+
+            let mut counter = create_signal(0);
+            create_effect(on((a, b, c), move || {
+                untracked.track();
+                counter += 1;
+            }));
+            assert_eq!(counter.get(), 1);
+
+            b.set("c".to_string());
+            assert_eq!(counter.get(), 2);
+            // Updates both `a` and `c`, but the effect only runs once.
+            a.set(1);
+            assert_eq!(counter.get(), 3);
+            untracked.set(());
+            assert_eq!(counter.get(), 3);
+        });
+    }
 }
@@ -3,6 +3,7 @@ pub enum SegmentAst {
     Param(String),
     DynParam(String),
     DynSegments(String),
+    RawSegments(String),
 }
 
 #[derive(Debug)]
@@ -14,7 +15,14 @@ impl RoutePathAst {
     pub fn dyn_segments(&self) -> Vec<SegmentAst> {
         self.segments
             .iter()
-            .filter(|x| matches!(x, SegmentAst::DynParam(_) | &SegmentAst::DynSegments(_)))
+            .filter(|x| {
+                matches!(
+                    x,
+                    SegmentAst::DynParam(_)
+                        | SegmentAst::DynSegments(_)
+                        | SegmentAst::RawSegments(_)
+                )
+            })
             .cloned()
             .collect()
     }
@@ -33,8 +41,23 @@ pub fn parse_route(i: &str) -> Result<RoutePathAst> {
     let mut segments_ast = Vec::with_capacity(segments.size_hint().0);
 
     for segment in segments {
+        if matches!(segments_ast.last(), Some(SegmentAst::RawSegments(_))) {
+            return Err(ParseError {
+                message: "raw segments capture must be the last segment".to_string(),
+            });
+        }
         if segment.starts_with('<') {
-            if segment.ends_with("..>") {
+            if segment.ends_with("...>") {
+                if matches!(segments_ast.last(), Some(SegmentAst::DynSegments(_))) {
+                    return Err(ParseError {
+                        message: "raw segments capture cannot follow a dynamic segments capture"
+                            .to_string(),
+                    });
+                }
+                segments_ast.push(SegmentAst::RawSegments(
+                    segment[1..segment.len() - 4].to_string(),
+                ));
+            } else if segment.ends_with("..>") {
                 segments_ast.push(SegmentAst::DynSegments(
                     segment[1..segment.len() - 3].to_string(),
                 ));
@@ -228,4 +251,28 @@ mod tests {
                 }"#]],
         );
     }
+
+    #[test]
+    fn raw_segments() {
+        check(
+            "/proxy/<rest...>",
+            expect![[r#"
+                RoutePathAst {
+                    segments: [
+                        Param(
+                            "proxy",
+                        ),
+                        RawSegments(
+                            "rest",
+                        ),
+                    ],
+                }"#]],
+        );
+    }
+
+    #[test]
+    fn raw_segments_must_be_last() {
+        let err = parse_route("/<rest...>/end").unwrap_err();
+        assert_eq!(err.message, "raw segments capture must be the last segment");
+    }
 }
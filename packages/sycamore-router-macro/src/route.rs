@@ -1,13 +1,56 @@
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{DeriveInput, Fields, Ident, LitStr, Token, Variant};
+use syn::{DeriveInput, Fields, Ident, LitStr, Path, Token, Type, Variant};
 
 use crate::parser::{parse_route, RoutePathAst, SegmentAst};
 
+/// Arguments of the `#[to(_)]` attribute, e.g. `#[to("/docs/<id>", preload = docs_preload)]`.
+struct ToArgs {
+    route: LitStr,
+    preload: Option<Path>,
+}
+
+impl Parse for ToArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let route = input.parse()?;
+        let mut preload = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "preload" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("unknown argument `{key}` (expected `preload`)"),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            preload = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Self { route, preload })
+    }
+}
+
 pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
-    let mut quoted = TokenStream::new();
+    // The matching code of every `#[to(_)]` variant, in declaration order, along with its first
+    // segment if it is static.
+    let mut variants_quoted: Vec<(Option<String>, TokenStream)> = Vec::new();
+    // The arms of the `match` in `Route::preload` for variants with a preload function.
+    let mut preload_arms = Vec::new();
+    // The first preload function, used for reporting a missing `#[preload_data(_)]` attribute.
+    let mut first_preload: Option<Path> = None;
+    // The type of the data loaded by the preload functions, from the `#[preload_data(_)]`
+    // attribute.
+    let mut preload_data: Option<Type> = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("preload_data") {
+            preload_data = Some(attr.parse_args()?);
+        }
+    }
+    // The arms of the `match` in `Route::route_path`.
+    let mut route_path_arms = Vec::new();
     let mut err_quoted = TokenStream::new();
     // When the `#[not_found]` handler is found, this will store its name so we can use that as the
     // `Default` implementation
@@ -34,7 +77,10 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                     match attr_name.as_str() {
                         "to" => {
                             // region: parse route
-                            let route_litstr: LitStr = attr.parse_args()?;
+                            let ToArgs {
+                                route: route_litstr,
+                                preload,
+                            } = attr.parse_args()?;
                             let route_str = route_litstr.value();
                             let route = match parse_route(&route_str) {
                                 Ok(route_ast) => route_ast,
@@ -43,6 +89,16 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                                 }
                             };
                             // endregion
+                            if let Some(preload) = preload {
+                                preload_arms.push(quote! {
+                                    Self::#variant_id { .. } => ::std::option::Option::Some(
+                                        ::sycamore_router::__preload::<Self>(
+                                            #preload(::std::clone::Clone::clone(self)),
+                                        ),
+                                    ),
+                                });
+                                first_preload.get_or_insert(preload);
+                            }
                             quote_capture_vars.extend(impl_to(variant, variant_id, &route)?);
                             route_path_ast = Some(route);
                             is_to_route = true;
@@ -70,13 +126,23 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                 }
                 if is_to_route {
                     let route_path_ast = route_path_ast.unwrap();
-                    quoted.extend(quote! {
-                        let __route = #route_path_ast;
-                        if let Some(__captures) = __route.match_path(__segments) {
-                            // Try to capture variables.
-                            #quote_capture_vars
-                        }
+                    route_path_arms.push(quote! {
+                        Self::#variant_id { .. } => ::std::option::Option::Some(#route_path_ast),
                     });
+                    let first_static = match route_path_ast.segments.first() {
+                        Some(SegmentAst::Param(param)) => Some(param.clone()),
+                        _ => None,
+                    };
+                    variants_quoted.push((
+                        first_static,
+                        quote! {{
+                            let __route = #route_path_ast;
+                            if let Some(__captures) = __route.match_path(__segments) {
+                                // Try to capture variables.
+                                #quote_capture_vars
+                            }
+                        }},
+                    ));
                 }
             }
 
@@ -87,13 +153,53 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
                 ));
             }
 
+            if let (Some(preload), None) = (&first_preload, &preload_data) {
+                return Err(syn::Error::new(
+                    preload.span(),
+                    "the type of the data loaded by `preload` must be declared with \
+                     `#[preload_data(T)]` on the enum",
+                ));
+            }
+
+            let quoted = group_by_first_segment(&variants_quoted);
+            let preload_quoted = if preload_arms.is_empty() {
+                TokenStream::new()
+            } else {
+                quote! {
+                    fn preload(&self) -> ::std::option::Option<::sycamore_router::PreloadFuture> {
+                        match self {
+                            #(#preload_arms)*
+                            _ => ::std::option::Option::None,
+                        }
+                    }
+                }
+            };
+
+            let preload_data_quoted = match &preload_data {
+                Some(ty) => quote! {
+                    impl ::sycamore_router::PreloadRoute for #ty_name {
+                        type Data = #ty;
+                    }
+                },
+                None => TokenStream::new(),
+            };
+
             Ok(quote! {
                 impl ::sycamore_router::Route for #ty_name {
                     fn match_route(&self, __segments: &[&str]) -> Self {
                         #quoted
                         #err_quoted
                     }
+                    #preload_quoted
+                    fn route_path(&self) -> ::std::option::Option<::sycamore_router::RoutePath> {
+                        match self {
+                            #(#route_path_arms)*
+                            #[allow(unreachable_patterns)]
+                            _ => ::std::option::Option::None,
+                        }
+                    }
                 }
+                #preload_data_quoted
                 // We implement `Default` as well here for the `Router`/`RouterBase` distinction (`Router` needs to pass a default `impl Route` to `RouterBase`)
                 impl ::std::default::Default for #ty_name {
                     fn default() -> Self {
@@ -109,6 +215,50 @@ pub fn route_impl(input: DeriveInput) -> syn::Result<TokenStream> {
     }
 }
 
+/// Groups the variants by their first static segment so that only the variants that can possibly
+/// match are tried, instead of every variant.
+///
+/// Variants whose first segment is not static (e.g. the index route or a route starting with a
+/// capture) can match any first segment, so they are included in every group. Inside a group,
+/// variants keep their declaration order so that the first variant that matches still wins.
+fn group_by_first_segment(variants: &[(Option<String>, TokenStream)]) -> TokenStream {
+    let mut firsts: Vec<&String> = Vec::new();
+    for first in variants.iter().filter_map(|(first, _)| first.as_ref()) {
+        if !firsts.contains(&first) {
+            firsts.push(first);
+        }
+    }
+
+    let arms = firsts.iter().map(|&first| {
+        let group = variants
+            .iter()
+            .filter(|(f, _)| f.is_none() || f.as_ref() == Some(first))
+            .map(|(_, quoted)| quoted);
+        quote! {
+            ::std::option::Option::Some(#first) => { #(#group)* }
+        }
+    });
+    let rest = variants
+        .iter()
+        .filter(|(f, _)| f.is_none())
+        .map(|(_, quoted)| quoted);
+
+    quote! {
+        // The query string and hash are only stripped from the last segment when matching.
+        let __first = match __segments {
+            [] => ::std::option::Option::None,
+            [__first] => ::std::option::Option::Some(
+                __first.split('?').next().unwrap().split('#').next().unwrap()
+            ),
+            [__first, ..] => ::std::option::Option::Some(*__first),
+        };
+        match __first {
+            #(#arms)*
+            _ => { #(#rest)* }
+        }
+    }
+}
+
 /// Implementation for `#[to(_)]` attribute.
 fn impl_to(
     variant: &Variant,
@@ -175,6 +325,27 @@ fn impl_to(
                             };
                         })
                     }
+                    SegmentAst::RawSegments(param) => {
+                        if param != &field.ident.as_ref().unwrap().to_string() {
+                            return Err(syn::Error::new(
+                                field.ident.span(),
+                                format!(
+                                    "capture field name mismatch (expected `{}`, found `{}`)",
+                                    param,
+                                    field.ident.as_ref().unwrap()
+                                ),
+                            ));
+                        }
+                        let param_id: Ident = syn::parse_str(param)?;
+                        captures.push(quote! {
+                            let #param_id = match ::sycamore_router::TryFromParam::try_from_param(
+                                __captures[#i].as_raw_segments().unwrap()
+                            ) {
+                                ::std::option::Option::Some(__value) => __value,
+                                ::std::option::Option::None => break,
+                            };
+                        })
+                    }
                 }
             }
             let named: Punctuated<&Option<Ident>, Token![,]> =
@@ -213,6 +384,14 @@ fn impl_to(
                             ::std::option::Option::None => break,
                         }
                     }}),
+                    SegmentAst::RawSegments(_) => captures.push(quote! {{
+                        match ::sycamore_router::TryFromParam::try_from_param(
+                            __captures[#i].as_raw_segments().unwrap()
+                        ) {
+                            ::std::option::Option::Some(__value) => __value,
+                            ::std::option::Option::None => break,
+                        }
+                    }}),
                 }
             }
             quote! {
@@ -240,6 +419,9 @@ impl ToTokens for SegmentAst {
             SegmentAst::DynSegments(_) => tokens.extend(quote! {
                 ::sycamore_router::Segment::DynSegments
             }),
+            SegmentAst::RawSegments(_) => tokens.extend(quote! {
+                ::sycamore_router::Segment::RawSegments
+            }),
         }
     }
 }
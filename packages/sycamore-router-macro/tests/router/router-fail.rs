@@ -45,4 +45,24 @@ enum Routes7 {
     NotFound,
 }
 
+#[derive(Route)]
+enum Routes8 {
+    #[to("/", load = load)] // Unknown argument
+    Home,
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Route, Clone)]
+enum Routes9 {
+    #[to("/", preload = load)] // Missing `#[preload_data(_)]`
+    Home,
+    #[not_found]
+    NotFound,
+}
+
+async fn load(_route: Routes9) -> String {
+    String::new()
+}
+
 fn main() {}
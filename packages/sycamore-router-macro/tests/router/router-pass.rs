@@ -36,4 +36,33 @@ enum Routes4 {
     NotFound,
 }
 
+#[derive(Route)]
+enum Routes5 {
+    #[to("/proxy/<rest...>")]
+    Proxy(String),
+    #[to("/redirect/<to...>")]
+    Redirect { to: String },
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Route, Clone)]
+#[preload_data(String)]
+enum Routes6 {
+    #[to("/docs/<id>", preload = load_doc)]
+    Doc(u32),
+    #[to("/user/<name>", preload = load_user,)]
+    User { name: String },
+    #[not_found]
+    NotFound,
+}
+
+async fn load_doc(_route: Routes6) -> String {
+    String::new()
+}
+
+async fn load_user(_route: Routes6) -> String {
+    String::new()
+}
+
 fn main() {}
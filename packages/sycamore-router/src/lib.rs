@@ -8,6 +8,14 @@ extern crate self as sycamore_router;
 
 mod router;
 
+#[cfg(feature = "suspense")]
+use std::any::Any;
+#[cfg(feature = "suspense")]
+use std::future::Future;
+#[cfg(feature = "suspense")]
+use std::pin::Pin;
+#[cfg(feature = "suspense")]
+use std::rc::Rc;
 use std::str::FromStr;
 
 pub use router::*;
@@ -25,13 +33,73 @@ pub trait Route: Sized + Default {
     fn match_route(&self, segments: &[&str]) -> Self;
 
     /// Matches a route with the given path.
+    ///
+    /// The query string and hash, if any, are kept together with the last segment so that `/`
+    /// characters inside them are not treated as separators.
     fn match_path(&self, path: &str) -> Self {
-        let segments = path
+        let (pathname, rest) = path.split_at(path.find(['?', '#']).unwrap_or(path.len()));
+        let mut segments = pathname
             .split('/')
             .filter(|s| !s.is_empty())
             .collect::<Vec<_>>();
+        if !rest.is_empty() {
+            match segments.last_mut() {
+                // The query and hash directly follow the last segment, e.g. `/a?b`.
+                Some(last) if !pathname.ends_with('/') => {
+                    *last = &path[pathname.len() - last.len()..];
+                }
+                // There is no segment to attach to, e.g. `/a/?b`.
+                _ => segments.push(rest),
+            }
+        }
         self.match_route(&segments)
     }
+
+    /// Starts loading the data for this route. Returns `None` if the route does not have a
+    /// preload function.
+    ///
+    /// This is generated by the [`Route`](derive@Route) derive macro from the `preload` argument
+    /// of the `#[to(_)]` attribute. The [`Router`] waits for the data to be loaded before
+    /// switching to the new route. The data can then be accessed with [`use_preload_data`].
+    #[cfg(feature = "suspense")]
+    fn preload(&self) -> Option<PreloadFuture> {
+        None
+    }
+
+    /// Returns the [`RoutePath`] that this route was declared with, or `None` if it is not known
+    /// (e.g. for the `#[not_found]` route).
+    ///
+    /// This is generated by the [`Route`](derive@Route) derive macro from the `#[to(_)]`
+    /// attribute. It is used to find the part of the path that is left over for a
+    /// [`NestedRouter`].
+    fn route_path(&self) -> Option<RoutePath> {
+        None
+    }
+}
+
+/// A [`Route`] with preload functions, which declares the type of the data that they load.
+///
+/// This is implemented by the [`Route`](derive@Route) derive macro for enums with a
+/// `#[preload_data(T)]` attribute. Every preload function of the enum must return `T`, which is
+/// checked at compile time. The data can then be accessed with [`use_preload_data`] without
+/// naming its type again.
+#[cfg(feature = "suspense")]
+pub trait PreloadRoute: Route {
+    /// The type of the data that is loaded by the preload functions.
+    type Data: 'static;
+}
+
+/// The future returned by [`Route::preload`]. The output is the data that was loaded, which is of
+/// type [`PreloadRoute::Data`].
+#[cfg(feature = "suspense")]
+pub type PreloadFuture = Pin<Box<dyn Future<Output = Rc<dyn Any>>>>;
+
+/// Implementation detail of the [`Route`](derive@Route) derive macro. Boxes the future returned by
+/// a preload function, which must load the [`PreloadRoute::Data`] of `R`.
+#[doc(hidden)]
+#[cfg(feature = "suspense")]
+pub fn __preload<R: PreloadRoute>(fut: impl Future<Output = R::Data> + 'static) -> PreloadFuture {
+    Box::pin(async move { Rc::new(fut.await) as Rc<dyn Any> })
 }
 
 /// Represents an URL segment or segments.
@@ -43,6 +111,9 @@ pub enum Segment {
     DynParam,
     /// Match an arbitrary amount of segments that are captured.
     DynSegments,
+    /// Match all the remaining segments, including the query string and hash, that are captured
+    /// as a single string. This must be the last segment.
+    RawSegments,
 }
 
 /// Represents a capture of an URL segment or segments.
@@ -52,6 +123,8 @@ pub enum Capture<'a> {
     DynParam(&'a str),
     /// A dynamic segment in the URL (i.e. matches multiple url segments).
     DynSegments(Vec<&'a str>),
+    /// The raw remainder of the URL, including the query string and hash.
+    RawSegments(String),
 }
 
 impl<'a> Capture<'a> {
@@ -72,6 +145,16 @@ impl<'a> Capture<'a> {
             None
         }
     }
+
+    /// Attempts to cast the [`Capture`] to a [`Capture::RawSegments`] with the matched remainder
+    /// of the url.
+    pub fn as_raw_segments(&self) -> Option<&str> {
+        if let Self::RawSegments(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
 }
 
 /// A list of [`Segment`]s.
@@ -93,7 +176,12 @@ impl RoutePath {
         if let Some(last) = paths.last_mut() {
             // Get rid of everything after '?' and '#' in the last segment.
             *last = last.split('?').next().unwrap().split('#').next().unwrap();
+            if last.is_empty() {
+                // The segment only contained the query string and/or hash.
+                paths.pop();
+            }
         }
+        let paths_len = paths.len();
         let mut paths = paths.iter();
         let mut segments = self.segments.iter();
         let mut captures = Vec::new();
@@ -138,6 +226,20 @@ impl RoutePath {
                         captures.push(Capture::DynSegments(capture));
                     }
                 }
+                Segment::RawSegments => {
+                    // Capture the rest of the original path, which still has the query string and
+                    // hash.
+                    let consumed = paths_len - paths.as_slice().len();
+                    let mut raw = String::new();
+                    for (i, segment) in path[consumed..].iter().enumerate() {
+                        if i > 0 && !segment.starts_with(['?', '#']) {
+                            raw.push('/');
+                        }
+                        raw.push_str(segment);
+                    }
+                    paths.by_ref().for_each(drop);
+                    captures.push(Capture::RawSegments(raw));
+                }
             }
         }
 
@@ -147,6 +249,30 @@ impl RoutePath {
 
         Some(captures)
     }
+
+    /// Returns the part of `path` that is captured by the trailing `<rest..>` or `<rest...>`
+    /// segment of this route, with a leading `/` and followed by the query string and hash of
+    /// `path`. Returns `None` if this route does not end with such a segment or does not match
+    /// `path`.
+    fn leftover(&self, path: &str) -> Option<String> {
+        if !matches!(
+            self.segments.last(),
+            Some(Segment::DynSegments | Segment::RawSegments)
+        ) {
+            return None;
+        }
+        let (pathname, rest) = path.split_at(path.find(['?', '#']).unwrap_or(path.len()));
+        let segments = pathname
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        let captured = match self.match_path(&segments)?.pop()? {
+            Capture::DynSegments(segments) => segments.join("/"),
+            Capture::RawSegments(raw) => raw,
+            Capture::DynParam(_) => unreachable!("last segment is not a DynParam"),
+        };
+        Some(format!("/{captured}{rest}"))
+    }
 }
 
 /// Fallible conversion between a param capture into a value.
@@ -392,6 +518,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn raw_segments_final() {
+        check(
+            "/proxy/a/b?foo=bar#baz",
+            RoutePath::new(vec![Param("proxy".to_string()), RawSegments]),
+            Some(vec![Capture::RawSegments("a/b?foo=bar#baz".to_string())]),
+        );
+    }
+
+    #[test]
+    fn raw_segments_can_capture_zero_segments() {
+        check(
+            "/proxy",
+            RoutePath::new(vec![Param("proxy".to_string()), RawSegments]),
+            Some(vec![Capture::RawSegments(String::new())]),
+        );
+    }
+
+    #[test]
+    fn raw_segments_only_query() {
+        check(
+            "/proxy/?foo=bar",
+            RoutePath::new(vec![Param("proxy".to_string()), RawSegments]),
+            Some(vec![Capture::RawSegments("?foo=bar".to_string())]),
+        );
+    }
+
+    #[test]
+    fn ignore_query_only_segment() {
+        check(
+            "/a/?foo=bar",
+            RoutePath::new(vec![Param("a".to_string())]),
+            Some(Vec::new()),
+        );
+    }
+
+    #[test]
+    fn leftover_path() {
+        let route = RoutePath::new(vec![Param("admin".to_string()), DynSegments]);
+        assert_eq!(
+            route.leftover("/admin/users/42"),
+            Some("/users/42".to_string())
+        );
+        assert_eq!(
+            route.leftover("/admin/users?q=a#top"),
+            Some("/users?q=a#top".to_string())
+        );
+        assert_eq!(route.leftover("/admin"), Some("/".to_string()));
+        assert_eq!(route.leftover("/about"), None);
+
+        let route = RoutePath::new(vec![Param("proxy".to_string()), RawSegments]);
+        assert_eq!(
+            route.leftover("/proxy/a/b?q=a"),
+            Some("/a/b?q=a".to_string())
+        );
+
+        let route = RoutePath::new(vec![Param("admin".to_string()), DynParam]);
+        assert_eq!(route.leftover("/admin/users"), None);
+    }
+
     mod integration {
         use crate::*;
 
@@ -553,6 +739,126 @@ mod tests {
             );
         }
 
+        #[test]
+        fn first_match_wins_across_static_and_dynamic_routes() {
+            #[derive(Debug, PartialEq, Eq, Route)]
+            enum Routes {
+                #[to("/account/<id>")]
+                Account(u32),
+                #[to("/<id>")]
+                Id(u32),
+                #[to("/account/me")]
+                Me,
+                #[to("/<name>")]
+                Name(String),
+                #[to("/about")]
+                About,
+                #[not_found]
+                NotFound,
+            }
+
+            assert_eq!(
+                Routes::default().match_path("/account/1"),
+                Routes::Account(1)
+            );
+            assert_eq!(Routes::default().match_path("/account/me"), Routes::Me);
+            assert_eq!(Routes::default().match_path("/1"), Routes::Id(1));
+            assert_eq!(
+                Routes::default().match_path("/account"),
+                Routes::Name("account".to_string())
+            );
+            // `Name` is declared before `About` so it wins.
+            assert_eq!(
+                Routes::default().match_path("/about?foo=bar"),
+                Routes::Name("about".to_string())
+            );
+            assert_eq!(Routes::default().match_path("/"), Routes::NotFound);
+            assert_eq!(
+                Routes::default().match_path("/account/me/404"),
+                Routes::NotFound
+            );
+        }
+
+        #[cfg(feature = "suspense")]
+        #[test]
+        fn preload() {
+            #[derive(Debug, Clone, PartialEq, Eq, Route)]
+            #[preload_data(String)]
+            enum Routes {
+                #[to("/")]
+                Home,
+                #[to("/docs/<id>", preload = load_doc)]
+                Doc(u32),
+                #[to("/blog/<slug>", preload = load_post)]
+                Post { slug: String },
+                #[not_found]
+                NotFound,
+            }
+
+            async fn load_doc(route: Routes) -> String {
+                match route {
+                    Routes::Doc(id) => format!("doc {id}"),
+                    _ => unreachable!(),
+                }
+            }
+
+            async fn load_post(route: Routes) -> String {
+                match route {
+                    Routes::Post { slug } => slug.to_uppercase(),
+                    _ => unreachable!(),
+                }
+            }
+
+            let data = futures::executor::block_on(
+                Routes::default().match_path("/docs/1").preload().unwrap(),
+            );
+            assert_eq!(data.downcast_ref::<String>().unwrap(), "doc 1");
+
+            let data = futures::executor::block_on(
+                Routes::default()
+                    .match_path("/blog/hello")
+                    .preload()
+                    .unwrap(),
+            );
+            assert_eq!(data.downcast_ref::<String>().unwrap(), "HELLO");
+
+            assert!(Routes::Home.preload().is_none());
+            assert!(Routes::NotFound.preload().is_none());
+        }
+
+        #[test]
+        fn raw_segments_router() {
+            #[derive(Debug, PartialEq, Eq, Route)]
+            enum Routes {
+                #[to("/")]
+                Home,
+                #[to("/about")]
+                About,
+                #[to("/proxy/<rest...>")]
+                Proxy(String),
+                #[not_found]
+                NotFound,
+            }
+
+            assert_eq!(Routes::default().match_path("/?foo=bar"), Routes::Home);
+            assert_eq!(
+                Routes::default().match_path("/about?next=/a/b"),
+                Routes::About
+            );
+            assert_eq!(
+                Routes::default().match_path("/proxy/a/b?next=/c/d#top"),
+                Routes::Proxy("a/b?next=/c/d#top".to_string())
+            );
+            assert_eq!(
+                Routes::default().match_path("/proxy/?foo=bar"),
+                Routes::Proxy("?foo=bar".to_string())
+            );
+            assert_eq!(
+                Routes::default().match_path("/proxy"),
+                Routes::Proxy(String::new())
+            );
+        }
+
         #[test]
         fn nested_router() {
             #[derive(Debug, PartialEq, Eq, Route)]
@@ -587,5 +893,28 @@ mod tests {
                 Routes::NotFound
             );
         }
+
+        #[test]
+        fn route_path() {
+            #[derive(Debug, PartialEq, Eq, Route)]
+            enum Routes {
+                #[to("/")]
+                Home,
+                #[to("/admin/<rest..>")]
+                Admin { rest: Vec<String> },
+                #[not_found]
+                NotFound,
+            }
+
+            let leftover = |path: &str| {
+                let route = Routes::default().match_path(path);
+                route
+                    .route_path()
+                    .and_then(|route_path| route_path.leftover(path))
+            };
+            assert_eq!(leftover("/"), None);
+            assert_eq!(leftover("/admin/users"), Some("/users".to_string()));
+            assert_eq!(leftover("/404"), None);
+        }
     }
 }
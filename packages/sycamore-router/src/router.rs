@@ -1,14 +1,18 @@
-use std::cell::Cell;
+#[cfg(feature = "suspense")]
+use std::any::{Any, TypeId};
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::str::FromStr;
 
 use sycamore::prelude::*;
 use wasm_bindgen::prelude::*;
-use web_sys::js_sys::Array;
-use web_sys::{Element, Event, HtmlAnchorElement, HtmlBaseElement, KeyboardEvent, UrlSearchParams};
+use web_sys::{Element, Event, HtmlAnchorElement, HtmlBaseElement, KeyboardEvent};
 
-use crate::Route;
+#[cfg(feature = "suspense")]
+use crate::PreloadRoute;
+use crate::{Route, Segment};
 
 /// A router integration provides the methods for adapting a router to a certain environment (e.g.
 /// history API).
@@ -16,17 +20,38 @@ pub trait Integration {
     /// Get the current pathname.
     fn current_pathname(&self) -> String;
 
-    /// Add a callback for listening to the `popstate` event.
+    /// Get the query string and hash of the current location (e.g. `?q=sycamore#top`).
+    ///
+    /// This is appended to the pathname to get the location that the router tracks, which is what
+    /// [`use_search_query`] and [`use_location_hash`] read from. By default, this returns an empty
+    /// string, which is correct for integrations where [`current_pathname`](Self::current_pathname)
+    /// already includes the query and hash.
+    fn current_query_and_hash(&self) -> String {
+        String::new()
+    }
+
+    /// Add a callback for listening to the `popstate` event (or the equivalent event for the
+    /// integration, e.g. `hashchange`).
     fn on_popstate(&self, f: Box<dyn FnMut()>);
 
     /// Get the click handler that is run when links are clicked.
-
     fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)>;
+
+    /// Resolve an url passed to [`navigate`] (or one of its variants) into the url that is written
+    /// to the history stack and the location that the router navigates to.
+    ///
+    /// By default, `url` is used for both.
+    fn navigation_target(&self, url: &str) -> (String, String) {
+        (url.to_string(), url.to_string())
+    }
 }
 
 thread_local! {
+    /// The location of the current [`Router`] without the base path, i.e. the pathname followed by
+    /// the query string and hash.
     static PATHNAME: Cell<Option<Signal<String>>> = const { Cell::new(None) };
-    static QUERY: Cell<Option<Signal<()>>> = const { Cell::new(None) };
+    /// The integration of the current [`Router`], which is used to resolve urls when navigating.
+    static INTEGRATION: RefCell<Option<Rc<dyn Integration>>> = const { RefCell::new(None) };
 }
 
 /// A router integration that uses the
@@ -51,11 +76,24 @@ impl Integration for HistoryIntegration {
         window().location().pathname().unwrap_throw()
     }
 
+    fn current_query_and_hash(&self) -> String {
+        let location = window().location();
+        format!(
+            "{}{}",
+            location.search().unwrap_throw(),
+            location.hash().unwrap_throw()
+        )
+    }
+
     fn on_popstate(&self, f: Box<dyn FnMut()>) {
         let closure = Closure::wrap(f);
-        window()
-            .add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
-            .unwrap_throw();
+        // Navigating to a fragment of the same page does not always fire `popstate`, so the
+        // location is also updated on `hashchange`.
+        for event in ["popstate", "hashchange"] {
+            window()
+                .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+                .unwrap_throw();
+        }
         closure.forget();
     }
 
@@ -85,38 +123,16 @@ impl Integration for HistoryIntegration {
 
                 let meta_keys_pressed = meta_keys_pressed(ev.unchecked_ref::<KeyboardEvent>());
                 if !meta_keys_pressed && location.origin() == Ok(origin) {
+                    let a_location = format!("{a_pathname}{query}{hash}");
                     if location.pathname().as_ref() != Ok(&a_pathname) {
                         // Same origin, different path. Navigate to new page.
                         ev.prevent_default();
-                        PATHNAME.with(|pathname| {
-                            // Update History API.
-                            let history = window().history().unwrap_throw();
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&a_pathname))
-                                .unwrap_throw();
-                            window().scroll_to_with_x_and_y(0.0, 0.0);
-
-                            let pathname = pathname.get().unwrap_throw();
-                            let path = a_pathname
-                                .strip_prefix(&base_pathname())
-                                .unwrap_or(&a_pathname);
-                            pathname.set(path.to_string());
-                        });
+                        push_location(&a_location);
+                        window().scroll_to_with_x_and_y(0.0, 0.0);
                     } else if location.search().as_ref() != Ok(&query) {
                         // Same origin, same pathname, different query.
                         ev.prevent_default();
-                        let history = window().history().unwrap_throw();
-                        if query.is_empty() {
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&a.href()))
-                                .unwrap_throw();
-                        } else {
-                            let history = window().history().unwrap_throw();
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&query))
-                                .unwrap_throw();
-                        }
-                        QUERY.with(|query| query.get().unwrap_throw().update(|_| {}));
+                        push_location(&a_location);
                     } else if location.hash().as_ref() != Ok(&hash) {
                         // Same origin, same pathname, same query, different hash. Use default
                         // browser behavior.
@@ -140,6 +156,154 @@ impl Integration for HistoryIntegration {
     }
 }
 
+/// Pushes `location` onto the history stack and updates the location of the current [`Router`].
+fn push_location(location: &str) {
+    let history = window().history().unwrap_throw();
+    history
+        .push_state_with_url(&JsValue::UNDEFINED, "", Some(location))
+        .unwrap_throw();
+
+    PATHNAME.with(|pathname| {
+        let pathname = pathname.get().unwrap_throw();
+        let path = location.strip_prefix(&base_pathname()).unwrap_or(location);
+        pathname.set(path.to_string());
+    });
+}
+
+/// A router integration that stores the current path in the
+/// [URL fragment](https://developer.mozilla.org/en-US/docs/Web/API/Location/hash) (e.g.
+/// `/#/about`) instead of the pathname.
+///
+/// Unlike [`HistoryIntegration`], this does not require any server configuration to serve the app
+/// for every path, which makes it suitable for static hosts such as GitHub Pages.
+///
+/// Links should have an `href` beginning with `#`, e.g. `<a href="#/about">`.
+#[derive(Default, Debug)]
+pub struct HashIntegration {
+    /// This field is to prevent downstream users from creating a new `HashIntegration` without
+    /// the `new` method.
+    _internal: (),
+}
+
+impl HashIntegration {
+    /// Create a new [`HashIntegration`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Integration for HashIntegration {
+    fn current_pathname(&self) -> String {
+        hash_to_pathname(&window().location().hash().unwrap_throw())
+    }
+
+    fn on_popstate(&self, f: Box<dyn FnMut()>) {
+        let closure = Closure::wrap(f);
+        window()
+            .add_event_listener_with_callback("hashchange", closure.as_ref().unchecked_ref())
+            .unwrap_throw();
+        closure.forget();
+    }
+
+    fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        Box::new(|ev| {
+            if let Some(a) = ev
+                .target()
+                .unwrap_throw()
+                .unchecked_into::<Element>()
+                .closest("a[href]")
+                .unwrap_throw()
+            {
+                let a = a.unchecked_into::<HtmlAnchorElement>();
+
+                // Check if a has `rel="external"`.
+                if a.rel() == "external" {
+                    // Use default browser behavior.
+                    return;
+                }
+
+                // Only intercept links that point to a fragment of the current page.
+                let Some(href) = a.get_attribute("href") else {
+                    return;
+                };
+                if !href.starts_with('#') {
+                    return;
+                }
+
+                let meta_keys_pressed = meta_keys_pressed(ev.unchecked_ref::<KeyboardEvent>());
+                if !meta_keys_pressed {
+                    ev.prevent_default();
+                    if window().location().hash().as_ref() != Ok(&href) {
+                        // Update History API. This does not trigger a `hashchange` event.
+                        let history = window().history().unwrap_throw();
+                        history
+                            .push_state_with_url(&JsValue::UNDEFINED, "", Some(&href))
+                            .unwrap_throw();
+                        window().scroll_to_with_x_and_y(0.0, 0.0);
+
+                        PATHNAME.with(|pathname| {
+                            pathname.get().unwrap_throw().set(hash_to_pathname(&href));
+                        });
+                    }
+                }
+            }
+        })
+    }
+
+    /// Urls that start with `#` are stored in the fragment as is. Urls that start with `/` are
+    /// moved into the fragment, so that the pathname of the document is left untouched.
+    fn navigation_target(&self, url: &str) -> (String, String) {
+        if url.starts_with('#') {
+            (url.to_string(), hash_to_pathname(url))
+        } else if url.starts_with('/') && !url.starts_with("//") {
+            (format!("#{url}"), url.to_string())
+        } else {
+            (url.to_string(), url.to_string())
+        }
+    }
+}
+
+/// Gets the path stored in a URL fragment. An empty fragment (`""` or `"#"`) is the root path.
+fn hash_to_pathname(hash: &str) -> String {
+    let path = hash.strip_prefix('#').unwrap_or(hash);
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+/// A router integration that always returns the same pathname and never listens to any browser
+/// events.
+///
+/// This is useful for rendering a [`Router`] on the server, where there is no browser to get the
+/// current location from. Click handling is also disabled.
+#[derive(Default, Debug, Clone)]
+pub struct StaticIntegration {
+    pathname: String,
+}
+
+impl StaticIntegration {
+    /// Create a new [`StaticIntegration`] with the given `pathname`.
+    pub fn new(pathname: impl Into<String>) -> Self {
+        Self {
+            pathname: pathname.into(),
+        }
+    }
+}
+
+impl Integration for StaticIntegration {
+    fn current_pathname(&self) -> String {
+        self.pathname.clone()
+    }
+
+    fn on_popstate(&self, _f: Box<dyn FnMut()>) {}
+
+    fn click_handler(&self) -> Box<dyn Fn(web_sys::MouseEvent)> {
+        Box::new(|_| {})
+    }
+}
+
 /// Gets the base pathname from `document.baseURI`.
 fn base_pathname() -> String {
     match document().query_selector("base[href]") {
@@ -157,6 +321,19 @@ fn base_pathname() -> String {
     }
 }
 
+/// Gets the current location from `integration`, without the base pathname.
+fn current_location(integration: &dyn Integration, base_pathname: &str) -> String {
+    let location = format!(
+        "{}{}",
+        integration.current_pathname(),
+        integration.current_query_and_hash()
+    );
+    match location.strip_prefix(base_pathname) {
+        Some(location) => location.to_string(),
+        None => location,
+    }
+}
+
 /// Props for [`Router`].
 #[derive(Props, Debug)]
 pub struct RouterProps<R, F, I>
@@ -217,7 +394,7 @@ where
 }
 
 /// The sycamore router component. This component expects to be used inside a browser environment.
-/// For server environments, see [`StaticRouter`].
+/// For server environments, see [`StaticRouter`] or [`StaticIntegration`].
 #[component]
 pub fn Router<R, F, I>(props: RouterProps<R, F, I>) -> View
 where
@@ -252,7 +429,11 @@ where
         route,
     } = props;
     let integration = Rc::new(integration);
-    let base_pathname = base_pathname();
+    let base_pathname = if is_ssr!() {
+        String::new()
+    } else {
+        base_pathname()
+    };
 
     PATHNAME.with(|pathname| {
         assert!(
@@ -260,29 +441,106 @@ where
             "cannot have more than one Router component initialized"
         );
         // Get initial url from window.location.
-        let path = integration.current_pathname();
-        let path = path.strip_prefix(&base_pathname).unwrap_or(&path);
-        pathname.set(Some(create_signal(path.to_string())));
-        QUERY.set(Some(create_signal(())));
+        let location = current_location(&*integration, &base_pathname);
+        pathname.set(Some(create_signal(location)));
+    });
+    INTEGRATION.with(|i| *i.borrow_mut() = Some(integration.clone() as Rc<dyn Integration>));
+    let location = PATHNAME.with(|p| p.get().unwrap_throw());
+    let route = Rc::new(route);
+    // The route is only matched again when the part of the location that it depends on changes.
+    // Use `use_search_query` and `use_location_hash` to track the query and hash.
+    let route_key = create_selector({
+        let route = Rc::clone(&route);
+        move || location.with(|location| route_key(&*route, location))
     });
-    let pathname = PATHNAME.with(|p| p.get().unwrap_throw());
 
-    // Set PATHNAME to None when the Router is destroyed.
-    on_cleanup(|| PATHNAME.with(|pathname| pathname.set(None)));
+    // Set PATHNAME and INTEGRATION to None when the Router is destroyed.
+    on_cleanup(|| {
+        PATHNAME.with(|pathname| pathname.set(None));
+        INTEGRATION.with(|i| *i.borrow_mut() = None);
+    });
 
     // Listen to popstate event.
     integration.on_popstate(Box::new({
         let integration = integration.clone();
+        let base_pathname = base_pathname.clone();
         move || {
-            let path = integration.current_pathname();
-            let path = path.strip_prefix(&base_pathname).unwrap_or(&path);
-            if pathname.with(|pathname| pathname != path) {
-                pathname.set(path.to_string());
+            let path = current_location(&*integration, &base_pathname);
+            if location.with(|location| *location != path) {
+                location.set(path);
             }
         }
     }));
-    let route_signal = create_memo(move || pathname.with(|pathname| route.match_path(pathname)));
-    let view = view(route_signal);
+    // When the route has a preload function, the route is only switched once its data has been
+    // loaded. `matched` is the location that `route_signal` was matched with, which lags behind
+    // `location` in the meantime.
+    let route_signal =
+        create_signal(location.with_untracked(|location| route.match_path(location)));
+    let matched = create_signal(location.get_clone_untracked());
+    // Changes to the query and hash that do not change the route are still passed on to nested
+    // routers.
+    create_effect(move || {
+        location.with(|location| {
+            let is_current = matched.with_untracked(|matched| {
+                strip_query_and_hash(matched) == strip_query_and_hash(location)
+            });
+            if is_current {
+                matched.set_if_changed(location.clone());
+            }
+        });
+    });
+    let nested = create_selector(move || {
+        route_signal.with(|route| matched.with(|matched| nested_path(route, matched)))
+    });
+    provide_context(RouterPathname {
+        base: base_pathname,
+        prefix: *create_signal(String::new()),
+        nested,
+        pathname: *location,
+    });
+    #[cfg(feature = "suspense")]
+    let preload_data = create_signal(None);
+    #[cfg(feature = "suspense")]
+    provide_context(PreloadData {
+        route: TypeId::of::<R>(),
+        data: *preload_data,
+    });
+    let mut is_initial = true;
+    create_effect(move || {
+        route_key.track();
+        let new_route = location.with_untracked(|location| route.match_path(location));
+        let is_initial = std::mem::replace(&mut is_initial, false);
+        #[cfg(feature = "suspense")]
+        if let Some(preload) = if is_ssr!() { None } else { new_route.preload() } {
+            // A preload that is still in flight was spawned in the previous run of this effect, so
+            // it is aborted when the effect is re-run. Like a resource, it keeps the enclosing
+            // `Suspense` or `Transition` pending until it is done.
+            sycamore_futures::create_suspense_task(async move {
+                let data = preload.await;
+                batch(move || {
+                    if !is_initial {
+                        route_signal.set(new_route);
+                        matched.set(location.get_clone_untracked());
+                    }
+                    preload_data.set(Some(data));
+                });
+            });
+            return;
+        }
+        if !is_initial {
+            batch(move || {
+                route_signal.set(new_route);
+                matched.set(location.get_clone_untracked());
+                #[cfg(feature = "suspense")]
+                preload_data.set(None);
+            });
+        }
+    });
+    let view = view(*route_signal);
+    if is_ssr!() {
+        // There is nothing to click on the server.
+        return view;
+    }
     let nodes = view.as_web_sys();
     on_mount(move || {
         for node in nodes {
@@ -304,7 +562,14 @@ where
     F: Fn(ReadSignal<R>) -> View + 'static,
 {
     view: F,
+    /// The route to render. If `pathname` is also set, this is the route that `pathname` is
+    /// matched against.
+    #[prop(default)]
     route: R,
+    /// The pathname to render. If this is set, the route is obtained by matching it with
+    /// [`Route::match_path`].
+    #[prop(setter(into))]
+    pathname: Option<String>,
 }
 
 impl<R, F> StaticRouterProps<R, F>
@@ -314,22 +579,83 @@ where
 {
     /// Create a new [`StaticRouterProps`].
     pub fn new(route: R, view: F) -> Self {
-        Self { view, route }
+        Self {
+            view,
+            route,
+            pathname: None,
+        }
+    }
+
+    /// Create a new [`StaticRouterProps`] from a pathname that is matched to get the route.
+    pub fn with_pathname(pathname: impl Into<String>, view: F) -> Self {
+        Self {
+            view,
+            route: R::default(),
+            pathname: Some(pathname.into()),
+        }
     }
 }
 
-/// A router that only renders once with the given `route`.
+/// A router that only renders once with the given `route` or `pathname`.
 ///
 /// This is useful for SSR where we want the HTML to be rendered instantly instead of waiting for
-/// the route preload to finish loading.
+/// the route preload to finish loading. Since it never accesses the browser, it can be used on the
+/// server with the same `view` closure as the one passed to [`Router`] on the client.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// #[derive(Route, Clone, Copy)]
+/// enum Routes {
+///     #[to("/")]
+///     Home,
+///     #[not_found]
+///     NotFound,
+/// }
+///
+/// # fn App() -> View {
+/// view! {
+///     StaticRouter(
+///         pathname="/",
+///         view=|route: ReadSignal<Routes>| match route.get() {
+///             Routes::Home => view! { "Home" },
+///             Routes::NotFound => view! { "Not Found" },
+///         },
+///     )
+/// }
+/// # }
+/// ```
 #[component]
 pub fn StaticRouter<R, F>(props: StaticRouterProps<R, F>) -> View
 where
     R: Route + 'static,
     F: Fn(ReadSignal<R>) -> View + 'static,
 {
+    let StaticRouterProps {
+        view,
+        route,
+        pathname,
+    } = props;
+    let route = match pathname {
+        Some(pathname) => {
+            let route = route.match_path(&pathname);
+            let nested = nested_path(&route, &pathname);
+            let context = RouterPathname {
+                base: String::new(),
+                prefix: *create_signal(String::new()),
+                nested: *create_signal(nested),
+                pathname: *create_signal(pathname),
+            };
+            return provide_context_in_new_scope(context, move || {
+                view! { StaticRouterBase(view=view, route=route) }
+            });
+        }
+        None => route,
+    };
+
     view! {
-        StaticRouterBase(view=props.view, route=props.route)
+        StaticRouterBase(view=view, route=route)
     }
 }
 
@@ -341,24 +667,514 @@ where
     R: Route + 'static,
     F: Fn(ReadSignal<R>) -> View + 'static,
 {
-    let StaticRouterProps { view, route } = props;
+    let StaticRouterProps { view, route, .. } = props;
+    // Routes are not preloaded when rendering statically.
+    #[cfg(feature = "suspense")]
+    provide_context(PreloadData {
+        route: TypeId::of::<R>(),
+        data: *create_signal(None),
+    });
 
     view(*create_signal(route))
 }
 
+/// The data loaded by the preload function of the current route, provided as a context by
+/// [`Router`] and [`StaticRouter`].
+#[cfg(feature = "suspense")]
+#[derive(Clone, Copy, Debug)]
+struct PreloadData {
+    /// The [`TypeId`] of the [`Route`] type of the router, which determines the type of `data`.
+    route: TypeId,
+    /// The data, which is of type [`PreloadRoute::Data`] of the route type of the router.
+    data: ReadSignal<Option<Rc<dyn Any>>>,
+}
+
+/// Creates a ReadSignal that tracks the data loaded by the preload function of the current route.
+///
+/// `R` is the [`Route`] type of the enclosing router. The type of the data is declared with the
+/// `#[preload_data(T)]` attribute of `R`.
+///
+/// The value is `None` if the current route does not have a preload function or if the data is
+/// still loading. When navigating to a route with a preload function, the [`Router`] only switches
+/// to the new route once its data has been loaded, so the value is always in sync with the route.
+/// The exception is the initial route, which is rendered immediately while its data is loading.
+/// Routes are never preloaded in SSR mode.
+///
+/// If the user navigates again before the data is loaded, the preload is aborted. While the data is
+/// loading, the enclosing `Suspense` or `Transition`, if any, is pending, just like for a resource.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// #[derive(Route, Clone)]
+/// #[preload_data(String)]
+/// enum Routes {
+///     #[to("/docs/<id>", preload = load_doc)]
+///     Doc(u32),
+///     #[not_found]
+///     NotFound,
+/// }
+///
+/// async fn load_doc(route: Routes) -> String {
+///     // Fetch the doc here.
+///     # String::new()
+/// }
+///
+/// # fn App() -> View {
+/// view! {
+///     Router(
+///         integration=HistoryIntegration::new(),
+///         view=|route: ReadSignal<Routes>| {
+///             let doc = use_preload_data::<Routes>();
+///             view! {
+///                 (match route.get_clone() {
+///                     Routes::Doc(_) => view! {
+///                         (doc.get_clone().map(|doc| doc.to_string()).unwrap_or_default())
+///                     },
+///                     Routes::NotFound => view! { "Not Found" },
+///                 })
+///             }
+///         },
+///     )
+/// }
+/// # }
+/// ```
+///
+/// # Panics
+/// This function will `panic!()` if it is not used inside a [`Router`] or [`StaticRouter`] whose
+/// route type is `R`.
+#[cfg(feature = "suspense")]
+pub fn use_preload_data<R: PreloadRoute + 'static>() -> ReadSignal<Option<Rc<R::Data>>> {
+    let PreloadData { route, data } =
+        try_use_context().expect("cannot get preload data outside of a Router");
+    assert!(
+        route == TypeId::of::<R>(),
+        "the route type of the Router is not `{}`",
+        std::any::type_name::<R>()
+    );
+    create_memo(move || {
+        data.get_clone().map(|data| {
+            data.downcast::<R::Data>()
+                .expect("preload data has the wrong type")
+        })
+    })
+}
+
+/// Adapts a `view` closure that also takes the data loaded by the preload function of the current
+/// route, so that it can be passed to a [`Router`] or [`StaticRouter`].
+///
+/// The data is the same as the one returned by [`use_preload_data`].
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// # #[derive(Route, Clone)]
+/// # #[preload_data(String)]
+/// # enum Routes {
+/// #     #[to("/docs/<id>", preload = load_doc)]
+/// #     Doc(u32),
+/// #     #[not_found]
+/// #     NotFound,
+/// # }
+/// # async fn load_doc(route: Routes) -> String { String::new() }
+/// # fn App() -> View {
+/// view! {
+///     Router(
+///         integration=HistoryIntegration::new(),
+///         view=with_preload_data(|route: ReadSignal<Routes>, doc| view! {
+///             (match route.get_clone() {
+///                 Routes::Doc(_) => view! {
+///                     (doc.get_clone().map(|doc| doc.to_string()).unwrap_or_default())
+///                 },
+///                 Routes::NotFound => view! { "Not Found" },
+///             })
+///         }),
+///     )
+/// }
+/// # }
+/// ```
+#[cfg(feature = "suspense")]
+pub fn with_preload_data<R, F>(f: F) -> impl Fn(ReadSignal<R>) -> View
+where
+    R: PreloadRoute + 'static,
+    F: Fn(ReadSignal<R>, ReadSignal<Option<Rc<R::Data>>>) -> View + 'static,
+{
+    move |route| f(route, use_preload_data::<R>())
+}
+
+/// The location of the current router, provided as a context by [`Router`], [`StaticRouter`] and
+/// [`NestedRouter`].
+#[derive(Clone, Debug)]
+struct RouterPathname {
+    /// The path of the `<base>` element, if any. This is the same for every nested router.
+    base: String,
+    /// The path that the current router is mounted under, relative to the top-level router. This
+    /// is empty for the top-level router.
+    prefix: ReadSignal<String>,
+    /// The part of the location that is left over for a [`NestedRouter`] by the current route, or
+    /// `None` if the current route does not end with a `<rest..>` or `<rest...>` segment.
+    nested: ReadSignal<Option<NestedPath>>,
+    /// The full location of the top-level router, including the query and hash, without the
+    /// `<base>` path. This is the same for every nested router.
+    pathname: ReadSignal<String>,
+}
+
+/// The part of a path that is captured by the trailing `<rest..>` or `<rest...>` segment of a
+/// route, which is matched by a [`NestedRouter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct NestedPath {
+    /// The part of the path in front of the capture.
+    prefix: String,
+    /// The captured path, with a leading `/` and followed by the query string and hash.
+    path: String,
+}
+
+/// Returns the part of `path` that the route it matches depends on: the whole path if the route
+/// captures the query string and hash with a `<rest...>` segment, or only the pathname otherwise.
+/// The route only needs to be matched again when this changes.
+fn route_key<R: Route>(route: &R, path: &str) -> String {
+    let captures_query = route
+        .match_path(path)
+        .route_path()
+        .is_some_and(|route_path| matches!(route_path.segments.last(), Some(Segment::RawSegments)));
+    if captures_query {
+        path.to_string()
+    } else {
+        strip_query_and_hash(path).to_string()
+    }
+}
+
+/// Returns the part of `path` that is left over for a [`NestedRouter`] after it was matched with
+/// `route`.
+fn nested_path<R: Route>(route: &R, path: &str) -> Option<NestedPath> {
+    let leftover = route.route_path()?.leftover(path)?;
+    let pathname = strip_query_and_hash(path).trim_end_matches('/');
+    let prefix = pathname
+        .strip_suffix(strip_query_and_hash(&leftover).trim_end_matches('/'))
+        .unwrap_or_default();
+    Some(NestedPath {
+        prefix: prefix.to_string(),
+        path: leftover,
+    })
+}
+
+/// Strips `base` from the start of `path`. Only whole segments are stripped, so that `/admin` is
+/// not considered to be a prefix of `/administrator`.
+fn strip_base<'a>(path: &'a str, base: &str) -> Option<&'a str> {
+    let base = base.trim_end_matches('/');
+    let rest = path.strip_prefix(base)?;
+    if rest.is_empty() || rest.starts_with(['/', '?', '#']) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/// Prepends `prefix` to `url` if it is relative to the root of the router, i.e. if it starts with
+/// `/`, or with `#/` for [`HashIntegration`]. Other urls are returned as is.
+fn prepend_prefix(prefix: &str, url: &str) -> String {
+    if let Some(path) = url.strip_prefix('#').filter(|path| path.starts_with('/')) {
+        format!("#{prefix}{path}")
+    } else if url.starts_with('/') && !url.starts_with("//") {
+        format!("{prefix}{url}")
+    } else {
+        url.to_string()
+    }
+}
+
+/// Props for [`NestedRouter`].
+#[derive(Props, Debug)]
+pub struct NestedRouterProps<R, F>
+where
+    R: Route + 'static,
+    F: FnOnce(ReadSignal<R>) -> View + 'static,
+{
+    view: F,
+    #[prop(default, setter(skip))]
+    _phantom: PhantomData<R>,
+}
+
+impl<R, F> NestedRouterProps<R, F>
+where
+    R: Route + 'static,
+    F: FnOnce(ReadSignal<R>) -> View + 'static,
+{
+    /// Create a new [`NestedRouterProps`].
+    pub fn new(view: F) -> Self {
+        Self {
+            view,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A router that matches the part of the path that is left over by the route of a parent
+/// [`Router`] or [`StaticRouter`].
+///
+/// The parent route must end with a `<rest..>` or `<rest...>` segment. The nested router only
+/// matches against what is captured by that segment, which allows the nested routes to be declared
+/// without repeating the prefix. If the parent route does not end with such a segment, the
+/// `#[not_found]` route is used. Nested routers can themselves contain other nested routers.
+///
+/// Links inside a nested router are resolved relative to it when using [`NavLink`] or
+/// [`use_navigate`], so that `/users` links to `/admin/users` in the example below. Plain `<a>`
+/// tags are not rewritten. Use [`use_router_base`] to get the full base path of the current router.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// #[derive(Route, Clone, Copy)]
+/// enum AdminRoutes {
+///     #[to("/")]
+///     Dashboard,
+///     #[to("/users")]
+///     Users,
+///     #[not_found]
+///     NotFound,
+/// }
+///
+/// #[derive(Route, Clone)]
+/// enum Routes {
+///     #[to("/")]
+///     Home,
+///     #[to("/admin/<_..>")]
+///     Admin(Vec<String>),
+///     #[not_found]
+///     NotFound,
+/// }
+///
+/// # fn App() -> View {
+/// view! {
+///     Router(
+///         integration=HistoryIntegration::new(),
+///         view=|route: ReadSignal<Routes>| match route.get_clone() {
+///             Routes::Home => view! { "Home" },
+///             Routes::Admin(_) => view! {
+///                 NestedRouter(
+///                     view=|route: ReadSignal<AdminRoutes>| match route.get() {
+///                         AdminRoutes::Dashboard => view! {
+///                             NavLink(to="/users") { "Users" }
+///                         },
+///                         AdminRoutes::Users => view! { "Users" },
+///                         AdminRoutes::NotFound => view! { "Not Found" },
+///                     },
+///                 )
+///             },
+///             Routes::NotFound => view! { "Not Found" },
+///         },
+///     )
+/// }
+/// # }
+/// ```
+///
+/// # Panics
+/// This component will `panic!()` if it is not used inside a [`Router`], a [`StaticRouter`] with a
+/// `pathname`, or another [`NestedRouter`].
+#[component]
+pub fn NestedRouter<R, F>(props: NestedRouterProps<R, F>) -> View
+where
+    R: Route + 'static,
+    F: FnOnce(ReadSignal<R>) -> View + 'static,
+{
+    let NestedRouterProps { view, .. } = props;
+    let parent = try_use_context::<RouterPathname>()
+        .expect("NestedRouter must be used inside a Router or StaticRouter");
+
+    let route = Rc::new(R::default());
+    let route_key = create_selector({
+        let route = Rc::clone(&route);
+        move || {
+            parent.nested.with(|nested| {
+                nested
+                    .as_ref()
+                    .map(|nested| route_key(&*route, &nested.path))
+            })
+        }
+    });
+    let route_signal = create_memo(move || {
+        route_key.track();
+        parent.nested.with_untracked(|nested| match nested {
+            Some(nested) => route.match_path(&nested.path),
+            None => R::default(),
+        })
+    });
+    let nested = create_selector(move || {
+        parent.nested.with(|nested| {
+            let nested = nested.as_ref()?;
+            route_signal.with(|route| nested_path(route, &nested.path))
+        })
+    });
+    let prefix = create_selector(move || {
+        let prefix = parent.prefix.get_clone();
+        parent.nested.with(|nested| match nested {
+            Some(nested) => format!("{prefix}{}", nested.prefix),
+            None => prefix,
+        })
+    });
+    let context = RouterPathname {
+        base: parent.base,
+        prefix,
+        nested,
+        pathname: parent.pathname,
+    };
+    provide_context_in_new_scope(context, move || view(route_signal))
+}
+
+/// Returns the full base path of the current router, i.e. the path of the `<base>` element (if
+/// any) followed by the path that every enclosing [`NestedRouter`] is mounted under.
+///
+/// This is useful for creating links inside a [`NestedRouter`] without hardcoding the prefix. The
+/// prefix depends on the current location if a parent route has captures in front of its
+/// `<rest..>` segment (e.g. `/users/<id>/<rest..>`), which is why this returns a signal.
+///
+/// # Panics
+/// This function will `panic!()` if it is not used inside a router.
+pub fn use_router_base() -> ReadSignal<String> {
+    let RouterPathname { base, prefix, .. } =
+        try_use_context().expect("cannot get router base outside of a Router");
+    create_memo(move || prefix.with(|prefix| format!("{base}{prefix}")))
+}
+
+/// Returns a function that navigates to an url relative to the current router, like [`navigate`].
+///
+/// Inside a [`NestedRouter`], urls that start with `/` are prefixed with the path that the router
+/// is mounted under, so that navigating to `/users` from a router mounted under `/admin` goes to
+/// `/admin/users`. Outside of a nested router, this is the same as [`navigate`].
+///
+/// # Panics
+/// This function will `panic!()` if it is not used inside a router.
+pub fn use_navigate() -> impl Fn(&str) + Copy {
+    let prefix = try_use_context::<RouterPathname>()
+        .expect("cannot navigate outside of a Router")
+        .prefix;
+    move |url| navigate(&prepend_prefix(&prefix.get_clone_untracked(), url))
+}
+
+/// Props for [`NavLink`].
+#[derive(Props, Debug)]
+pub struct NavLinkProps {
+    /// The `href` of the link. Inside a [`NestedRouter`], this is relative to the nested router.
+    #[prop(setter(into))]
+    to: String,
+    /// The classes that are always set on the link.
+    #[prop(default, setter(into))]
+    class: String,
+    /// The class that is added to the link when it points to the current page. Defaults to
+    /// `"active"`.
+    #[prop(default = "active".to_string(), setter(into))]
+    active_class: String,
+    /// If `true`, the link is only active if the current pathname is exactly `to`. Otherwise, the
+    /// link is also active for every path under `to`, e.g. `/docs` is active for `/docs/intro`.
+    #[prop(default)]
+    exact: bool,
+    #[prop(default)]
+    children: Children,
+}
+
+/// A link (`<a>`) that adds `active_class` to its classes when it points to the current page.
+///
+/// Only the pathname is compared, so the query and hash of both `to` and the current location are
+/// ignored. With [`HashIntegration`], the leading `#` of `to` is also ignored. Links are navigated
+/// to by the router's click handler, just like any other `<a>`.
+///
+/// Inside a [`NestedRouter`], a `to` that starts with `/` (or `#/`) is relative to the nested
+/// router: the path that the router is mounted under is prepended to it.
+///
+/// When used outside of a [`Router`] or inside a [`StaticRouter`] without a `pathname`, the
+/// current pathname is not known and the link is never active.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// # fn Nav() -> View {
+/// view! {
+///     nav {
+///         NavLink(to="/", exact=true) { "Home" }
+///         NavLink(to="/docs", class="nav-link", active_class="current") { "Docs" }
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn NavLink(props: NavLinkProps) -> View {
+    let NavLinkProps {
+        to,
+        class,
+        active_class,
+        exact,
+        children,
+    } = props;
+    let context = try_use_context::<RouterPathname>();
+    let prefix = context.as_ref().map(|context| context.prefix);
+    let pathname = context.map(|context| context.pathname);
+
+    let href = create_memo(move || match prefix {
+        Some(prefix) => prefix.with(|prefix| prepend_prefix(prefix, &to)),
+        None => to.clone(),
+    });
+    let is_active = create_selector(move || {
+        pathname.is_some_and(|pathname| {
+            href.with(|href| {
+                let target = strip_query_and_hash(href.strip_prefix('#').unwrap_or(href));
+                pathname
+                    .with(|pathname| is_link_active(strip_query_and_hash(pathname), target, exact))
+            })
+        })
+    });
+    let class = move || {
+        if !is_active.get() {
+            class.clone()
+        } else if class.is_empty() {
+            active_class.clone()
+        } else {
+            format!("{class} {active_class}")
+        }
+    };
+
+    view! {
+        a(href=move || href.get_clone(), class=class) {
+            (children)
+        }
+    }
+}
+
+/// Returns `path` without its query string and hash.
+fn strip_query_and_hash(path: &str) -> &str {
+    path.split(['?', '#']).next().unwrap_or_default()
+}
+
+/// Returns whether a link to `target` is active for the current `pathname`. Trailing slashes are
+/// ignored.
+fn is_link_active(pathname: &str, target: &str, exact: bool) -> bool {
+    if exact {
+        pathname.trim_end_matches('/') == target.trim_end_matches('/')
+    } else {
+        strip_base(pathname, target).is_some()
+    }
+}
+
 /// Navigates to the specified `url`. The url should have the same origin as the app.
 ///
 /// This is useful for imperatively navigating to an url when using an anchor tag (`<a>`) is not
 /// possible/suitable (e.g. when submitting a form).
 ///
+/// The url is resolved by the integration of the current [`Router`]. With [`HashIntegration`],
+/// both `/about` and `#/about` navigate to the `/about` route by updating the URL fragment.
+///
 /// # Panics
 /// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn navigate(url: &str) {
+    let (url, location) = navigation_target(url);
     let history = window().history().unwrap_throw();
     history
-        .push_state_with_url(&JsValue::UNDEFINED, "", Some(url))
+        .push_state_with_url(&JsValue::UNDEFINED, "", Some(&url))
         .unwrap_throw();
-    navigate_no_history(url);
+    window().scroll_to_with_x_and_y(0.0, 0.0);
+    update_pathname(&location);
 }
 
 /// Navigates to the specified `url` without adding a new history entry. Instead, this replaces the
@@ -370,11 +1186,13 @@ pub fn navigate(url: &str) {
 /// # Panics
 /// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn navigate_replace(url: &str) {
+    let (url, location) = navigation_target(url);
     let history = window().history().unwrap_throw();
     history
-        .replace_state_with_url(&JsValue::UNDEFINED, "", Some(url))
+        .replace_state_with_url(&JsValue::UNDEFINED, "", Some(&url))
         .unwrap_throw();
-    navigate_no_history(url);
+    window().scroll_to_with_x_and_y(0.0, 0.0);
+    update_pathname(&location);
 }
 
 /// Navigates to the specified `url` without touching the history API.
@@ -384,8 +1202,18 @@ pub fn navigate_replace(url: &str) {
 /// # Panics
 /// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn navigate_no_history(url: &str) {
+    let (_, location) = navigation_target(url);
     window().scroll_to_with_x_and_y(0.0, 0.0);
-    update_pathname(url);
+    update_pathname(&location);
+}
+
+/// Resolves `url` with the [`Integration`] of the current [`Router`]. See
+/// [`Integration::navigation_target`].
+fn navigation_target(url: &str) -> (String, String) {
+    INTEGRATION.with(|integration| match &*integration.borrow() {
+        Some(integration) => integration.navigation_target(url),
+        None => (url.to_string(), url.to_string()),
+    })
 }
 
 /// Internal function for getting the global pathname variable and updating it with the given `url`.
@@ -426,83 +1254,130 @@ pub fn refresh() {
 }
 
 /// Creates a ReadSignal that tracks the url query provided.
+///
+/// The query is read from the location of the enclosing router, so this also works with
+/// [`HashIntegration`], where the query is part of the fragment, and on the server, where it is
+/// read from the pathname passed to the [`StaticRouter`] or the [`StaticIntegration`].
 pub fn use_search_query(query: &'static str) -> ReadSignal<Option<String>> {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot get query outside of a Router",
-        );
-
-        let pathname = pathname.get().unwrap_throw();
-
-        create_memo(move || {
-            QUERY.with(|query| query.get().unwrap_throw()).track();
-            pathname.track();
-            UrlSearchParams::new_with_str(&window().location().search().unwrap_throw())
-                .unwrap_throw()
-                .get(query)
+    let location = use_location("cannot get query outside of a Router");
+    create_memo(move || {
+        location.with(|location| {
+            query_pairs(split_location(location).0)
+                .into_iter()
+                .find(|(key, _)| key == query)
+                .map(|(_, value)| value)
         })
     })
 }
 
-/// Creates a ReadSignal that tracks the url query string.
-pub fn use_search_queries() -> ReadSignal<HashMap<String, String>> {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot get query outside of a Router",
-        );
+/// Creates a ReadSignal that tracks the url query provided and parses it into `T`.
+///
+/// The value is `None` if the query is absent or if it cannot be parsed into `T`. If the same key
+/// appears multiple times in the query string (e.g. `?q=a&q=b`), the first value is used, just like
+/// [`use_search_query`].
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// # fn Comp() -> View {
+/// // With the url `/search?page=2`:
+/// let page = use_search_query_typed::<u32>("page");
+/// let page = move || page.get().unwrap_or(1);
+/// # view! {}
+/// # }
+/// ```
+pub fn use_search_query_typed<T: FromStr + 'static>(query: &'static str) -> ReadSignal<Option<T>> {
+    let value = use_search_query(query);
+    create_memo(move || value.with(|value| value.as_deref().and_then(|value| value.parse().ok())))
+}
 
-        let pathname = pathname.get().unwrap_throw();
+/// Creates a ReadSignal that tracks the url query string and deserializes it into `T`.
+///
+/// The value is `None` if the query string cannot be deserialized into `T`. If the same key appears
+/// multiple times in the query string (e.g. `?q=a&q=b`), a [`Vec`] field receives all the values,
+/// while other fields only receive the first one.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore_router::*;
+/// #[derive(serde::Deserialize)]
+/// struct Search {
+///     q: String,
+///     page: Option<u32>,
+/// }
+///
+/// # fn Comp() -> View {
+/// // With the url `/search?q=sycamore&page=2`:
+/// let search = use_search_query_struct::<Search>();
+/// # view! {}
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+pub fn use_search_query_struct<T>() -> ReadSignal<Option<T>>
+where
+    T: serde::de::DeserializeOwned + 'static,
+{
+    let location = use_location("cannot get query outside of a Router");
+    create_memo(move || location.with(|location| deserialize_query(split_location(location).0)))
+}
 
-        create_memo(move || {
-            QUERY.with(|query| query.get().unwrap_throw()).track();
-            pathname.track();
-            UrlSearchParams::new_with_str(&window().location().search().unwrap_throw())
-                .unwrap_throw()
-                .entries()
+/// Deserializes a query string (with or without the leading `?`) into `T`. See
+/// [`deserialize_form_pairs`](sycamore::web::deserialize_form_pairs) for how repeated keys are
+/// handled.
+#[cfg(feature = "serde")]
+fn deserialize_query<T: serde::de::DeserializeOwned>(search: &str) -> Option<T> {
+    let search = search.strip_prefix('?').unwrap_or(search);
+    sycamore::web::deserialize_form_pairs(query_pairs(search)).ok()
+}
+
+/// Creates a ReadSignal that tracks the url query string.
+///
+/// Like [`use_search_query`], the query is read from the location of the enclosing router.
+pub fn use_search_queries() -> ReadSignal<HashMap<String, String>> {
+    let location = use_location("cannot get query outside of a Router");
+    create_memo(move || {
+        location.with(|location| {
+            query_pairs(split_location(location).0)
                 .into_iter()
-                .map(|e| {
-                    let e: Array = e.unwrap_throw().into();
-                    let e = e
-                        .into_iter()
-                        .map(|s| s.as_string().unwrap_throw())
-                        .collect::<Vec<String>>();
-                    (e[0].clone(), e[1].clone())
-                })
                 .collect()
         })
     })
 }
 
 /// Creates a ReadSignal that tracks the url fragment.
+///
+/// The fragment is read from the location of the enclosing router. With [`HashIntegration`], this
+/// is the fragment that follows the path in the url, e.g. `#top` for `/#/about#top`. On the server,
+/// it is read from the pathname passed to the [`StaticRouter`] or the [`StaticIntegration`]. Note
+/// that browsers do not send the fragment to the server.
 pub fn use_location_hash() -> ReadSignal<String> {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.get().is_some(),
-            "cannot get hash outside of a Router"
-        );
+    let location = use_location("cannot get hash outside of a Router");
+    create_memo(move || location.with(|location| split_location(location).1.to_string()))
+}
 
-        let pathname = pathname.get().unwrap_throw();
+/// Returns the location of the enclosing router, including the query and hash.
+///
+/// # Panics
+/// Panics with `msg` if there is no enclosing router.
+fn use_location(msg: &str) -> ReadSignal<String> {
+    try_use_context::<RouterPathname>().expect(msg).pathname
+}
 
-        let on_hashchange = create_signal(());
-        window()
-            .add_event_listener_with_callback(
-                "hashchange",
-                Closure::wrap(Box::new(move || {
-                    on_hashchange.update(|_| {});
-                }) as Box<dyn FnMut()>)
-                .into_js_value()
-                .unchecked_ref(),
-            )
-            .unwrap_throw();
+/// Splits the query string (without the leading `?`) and the hash (including the leading `#`) out
+/// of `path`.
+fn split_location(path: &str) -> (&str, &str) {
+    let (path, hash) = path.split_at(path.find('#').unwrap_or(path.len()));
+    let query = path.split_once('?').map_or("", |(_, query)| query);
+    (query, hash)
+}
 
-        create_memo(move || {
-            on_hashchange.track();
-            pathname.track();
-            window().location().hash().unwrap_throw()
-        })
-    })
+/// Parses a query string (without the leading `?`) into decoded key-value pairs, in the same way
+/// as `URLSearchParams`.
+fn query_pairs(query: &str) -> Vec<(String, String)> {
+    // Decoding into strings cannot fail since invalid percent-encodings are kept as is.
+    serde_urlencoded::from_str(query).unwrap_or_default()
 }
 
 fn meta_keys_pressed(kb_event: &KeyboardEvent) -> bool {
@@ -575,4 +1450,411 @@ mod tests {
             "Not Found"
         );
     }
+
+    #[derive(Route, Clone, Copy, Debug, PartialEq)]
+    enum SsrRoutes {
+        #[to("/")]
+        Home,
+        #[to("/about")]
+        About,
+        #[not_found]
+        NotFound,
+    }
+
+    fn ssr_view(route: ReadSignal<SsrRoutes>) -> View {
+        match route.get() {
+            SsrRoutes::Home => view! { "Home" },
+            SsrRoutes::About => view! { "About" },
+            SsrRoutes::NotFound => view! { "Not Found" },
+        }
+    }
+
+    #[test]
+    fn static_router_with_pathname() {
+        assert_eq!(
+            sycamore::render_to_string(|| view! { StaticRouter(pathname="/", view=ssr_view) }),
+            "Home"
+        );
+        assert_eq!(
+            sycamore::render_to_string(|| view! { StaticRouter(pathname="/about", view=ssr_view) }),
+            "About"
+        );
+        assert_eq!(
+            sycamore::render_to_string(|| view! { StaticRouter(pathname="/404", view=ssr_view) }),
+            "Not Found"
+        );
+    }
+
+    #[test]
+    fn router_with_static_integration_in_ssr() {
+        assert_eq!(
+            sycamore::render_to_string(|| view! {
+                Router(integration=StaticIntegration::new("/about"), view=ssr_view)
+            }),
+            "About"
+        );
+        // The router should be cleaned up after rendering so that it can be rendered again.
+        assert_eq!(
+            sycamore::render_to_string(|| view! {
+                Router(integration=StaticIntegration::new("/"), view=ssr_view)
+            }),
+            "Home"
+        );
+    }
+
+    #[test]
+    fn strip_base_path() {
+        assert_eq!(strip_base("/admin/users", "/admin"), Some("/users"));
+        assert_eq!(strip_base("/admin", "/admin"), Some(""));
+        assert_eq!(strip_base("/admin/", "/admin/"), Some("/"));
+        assert_eq!(strip_base("/admin?tab=1", "/admin"), Some("?tab=1"));
+        assert_eq!(strip_base("/administrator", "/admin"), None);
+        assert_eq!(strip_base("/about", "/admin"), None);
+        assert_eq!(strip_base("/about", ""), Some("/about"));
+    }
+
+    #[test]
+    fn hash_pathname() {
+        assert_eq!(hash_to_pathname("#/about"), "/about");
+        assert_eq!(hash_to_pathname("#/search?q=a"), "/search?q=a");
+        assert_eq!(hash_to_pathname("#"), "/");
+        assert_eq!(hash_to_pathname(""), "/");
+    }
+
+    #[test]
+    fn hash_navigation_target() {
+        let integration = HashIntegration::new();
+        assert_eq!(
+            integration.navigation_target("#/admin/users"),
+            ("#/admin/users".to_string(), "/admin/users".to_string())
+        );
+        assert_eq!(
+            integration.navigation_target("/about?q=a"),
+            ("#/about?q=a".to_string(), "/about?q=a".to_string())
+        );
+        assert_eq!(
+            integration.navigation_target("#"),
+            ("#".to_string(), "/".to_string())
+        );
+        assert_eq!(
+            integration.navigation_target("https://example.com/"),
+            (
+                "https://example.com/".to_string(),
+                "https://example.com/".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_query_pairs() {
+        assert_eq!(split_location("/search?q=a#top"), ("q=a", "#top"));
+        assert_eq!(split_location("/search#top?q=a"), ("", "#top?q=a"));
+        assert_eq!(split_location("/search"), ("", ""));
+
+        let pairs = query_pairs("q=sycamore+rs&tag=%F0%9F%A6%80&empty&bad=%zz&&k=v=w&p=%+1");
+        assert_eq!(
+            pairs,
+            [
+                ("q".to_string(), "sycamore rs".to_string()),
+                ("tag".to_string(), "🦀".to_string()),
+                ("empty".to_string(), String::new()),
+                ("bad".to_string(), "%zz".to_string()),
+                ("k".to_string(), "v=w".to_string()),
+                ("p".to_string(), "% 1".to_string()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "suspense")]
+    #[test]
+    fn preload_data_in_static_router() {
+        #[derive(Route, Clone)]
+        #[preload_data(String)]
+        enum Routes {
+            #[to("/docs/<id>", preload = load_doc)]
+            #[allow(dead_code)]
+            Doc(u32),
+            #[not_found]
+            NotFound,
+        }
+
+        async fn load_doc(_route: Routes) -> String {
+            unreachable!("routes are not preloaded when rendering statically")
+        }
+
+        let html = sycamore::render_to_string(|| {
+            view! {
+                StaticRouter(
+                    pathname="/docs/1",
+                    view=with_preload_data(|route: ReadSignal<Routes>, doc| {
+                        assert!(matches!(route.get_clone(), Routes::Doc(1)));
+                        assert!(doc.get_clone().is_none());
+                        view! { "Doc" }
+                    }),
+                )
+            }
+        });
+        assert_eq!(html, "Doc");
+    }
+
+    #[test]
+    fn search_query_in_ssr() {
+        #[component]
+        fn Search() -> View {
+            let q = use_search_query("q");
+            let page = use_search_query_typed::<u32>("page");
+            let queries = use_search_queries();
+            let hash = use_location_hash();
+            let text = format!(
+                "{}|{}|{}|{}",
+                q.get_clone().unwrap_or_default(),
+                page.get().unwrap_or(1),
+                queries.with(HashMap::len),
+                hash.get_clone(),
+            );
+            view! { (text) }
+        }
+
+        let view = |_: ReadSignal<SsrRoutes>| view! { Search {} };
+        assert_eq!(
+            sycamore::render_to_string(
+                || view! { StaticRouter(pathname="/about?q=sycamore%20rs&page=2#top", view=view) }
+            ),
+            "sycamore rs|2|2|#top"
+        );
+        assert_eq!(
+            sycamore::render_to_string(|| view! {
+                Router(integration=StaticIntegration::new("/about?page=3"), view=view)
+            }),
+            "|3|1|"
+        );
+    }
+
+    #[test]
+    fn nested_router() {
+        #[derive(Route, Clone, Copy)]
+        enum AdminRoutes {
+            #[to("/")]
+            Dashboard,
+            #[to("/users/<id>")]
+            User(u32),
+            #[not_found]
+            NotFound,
+        }
+
+        #[derive(Route, Clone)]
+        enum Routes {
+            #[to("/")]
+            Home,
+            #[to("/admin/<_..>")]
+            #[allow(dead_code)]
+            // The nested router matches the leftover segments itself.
+            Admin(Vec<String>),
+            #[not_found]
+            NotFound,
+        }
+
+        fn admin_view(route: ReadSignal<AdminRoutes>) -> View {
+            assert_eq!(use_router_base().get_clone(), "/admin");
+            match route.get() {
+                AdminRoutes::Dashboard => view! { "Dashboard" },
+                AdminRoutes::User(id) => {
+                    assert_eq!(id, 42);
+                    view! { "User" }
+                }
+                AdminRoutes::NotFound => view! { "Admin Not Found" },
+            }
+        }
+
+        fn routes_view(route: ReadSignal<Routes>) -> View {
+            match route.get_clone() {
+                Routes::Home => view! { "Home" },
+                Routes::Admin(_) => view! { NestedRouter(view=admin_view) },
+                Routes::NotFound => view! { "Not Found" },
+            }
+        }
+
+        let render = |pathname: &'static str| {
+            sycamore::render_to_string(
+                || view! { StaticRouter(pathname=pathname, view=routes_view) },
+            )
+        };
+        assert_eq!(render("/"), "Home");
+        assert_eq!(render("/admin"), "Dashboard");
+        assert_eq!(render("/admin/users/42"), "User");
+        assert_eq!(render("/admin/404"), "Admin Not Found");
+        assert_eq!(render("/404"), "Not Found");
+    }
+
+    #[test]
+    fn nested_router_links() {
+        #[derive(Route, Clone, Copy)]
+        enum UserRoutes {
+            #[to("/")]
+            Profile,
+            #[to("/settings")]
+            Settings,
+            #[not_found]
+            NotFound,
+        }
+
+        #[derive(Route, Clone)]
+        enum Routes {
+            #[to("/users/<id>/<rest..>")]
+            #[allow(dead_code)]
+            User(u32, Vec<String>),
+            #[not_found]
+            NotFound,
+        }
+
+        fn user_view(route: ReadSignal<UserRoutes>) -> View {
+            let page = match route.get() {
+                UserRoutes::Profile => "Profile",
+                UserRoutes::Settings => "Settings",
+                UserRoutes::NotFound => "Not Found",
+            };
+            view! {
+                (page)
+                NavLink(to="/settings", exact=true) { "Settings" }
+            }
+        }
+
+        let render = |pathname: &'static str| {
+            sycamore::render_to_string(|| {
+                view! {
+                    StaticRouter(pathname=pathname, view=|route: ReadSignal<Routes>| {
+                        match route.get_clone() {
+                            Routes::User(..) => view! { NestedRouter(view=user_view) },
+                            Routes::NotFound => view! { "Not Found" },
+                        }
+                    })
+                }
+            })
+        };
+        assert_eq!(
+            render("/users/7"),
+            "Profile<a href=\"/users/7/settings\" class=\"\" data-hk=\"0.0\">Settings</a>"
+        );
+        assert_eq!(
+            render("/users/7/settings?tab=1"),
+            "Settings<a href=\"/users/7/settings\" class=\"active\" data-hk=\"0.0\">Settings</a>"
+        );
+    }
+
+    #[test]
+    fn route_key_includes_raw_captures() {
+        #[derive(Route)]
+        enum Routes {
+            #[to("/docs/<page>")]
+            #[allow(dead_code)]
+            Docs(String),
+            #[to("/proxy/<rest...>")]
+            #[allow(dead_code)]
+            Proxy(String),
+            #[not_found]
+            NotFound,
+        }
+
+        let route = Routes::default();
+        assert_eq!(route_key(&route, "/docs/intro?q=a#top"), "/docs/intro");
+        assert_eq!(route_key(&route, "/proxy/a?q=a#top"), "/proxy/a?q=a#top");
+        assert_eq!(route_key(&route, "/404?q=a"), "/404");
+    }
+
+    #[test]
+    fn nested_path_prefix() {
+        assert_eq!(prepend_prefix("/admin", "/users"), "/admin/users");
+        assert_eq!(prepend_prefix("/admin", "#/users"), "#/admin/users");
+        assert_eq!(prepend_prefix("/admin", "users"), "users");
+        assert_eq!(prepend_prefix("/admin", "//example.com"), "//example.com");
+        assert_eq!(prepend_prefix("", "/users"), "/users");
+    }
+
+    #[test]
+    fn nav_link_active() {
+        #[derive(Route, Clone, Copy)]
+        enum Routes {
+            #[not_found]
+            NotFound,
+        }
+
+        let render = |pathname: &'static str| {
+            sycamore::render_to_string(|| {
+                view! {
+                    StaticRouter(pathname=pathname, view=|_: ReadSignal<Routes>| view! {
+                        NavLink(to="/", exact=true) { "Home" }
+                        NavLink(to="/docs?page=1", class="link") { "Docs" }
+                    })
+                }
+            })
+        };
+        assert_eq!(
+            render("/"),
+            "<a href=\"/\" class=\"active\" data-hk=\"0.0\">Home</a><a href=\"/docs?page=1\" class=\"link\" data-hk=\"0.1\">Docs</a>"
+        );
+        assert_eq!(
+            render("/docs/intro#top"),
+            "<a href=\"/\" class=\"\" data-hk=\"0.0\">Home</a><a href=\"/docs?page=1\" class=\"link active\" data-hk=\"0.1\">Docs</a>"
+        );
+        assert_eq!(
+            render("/documents"),
+            "<a href=\"/\" class=\"\" data-hk=\"0.0\">Home</a><a href=\"/docs?page=1\" class=\"link\" data-hk=\"0.1\">Docs</a>"
+        );
+    }
+
+    #[test]
+    fn link_active() {
+        assert!(is_link_active("/", "/", true));
+        assert!(is_link_active("/docs/", "/docs", true));
+        assert!(!is_link_active("/docs/intro", "/docs", true));
+        assert!(is_link_active("/docs/intro", "/docs", false));
+        assert!(is_link_active("/docs/intro", "/", false));
+        assert!(!is_link_active("/documents", "/docs", false));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_query_struct() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Search {
+            q: String,
+            page: Option<u32>,
+        }
+
+        assert_eq!(
+            deserialize_query("?q=sycamore&page=2"),
+            Some(Search {
+                q: "sycamore".to_string(),
+                page: Some(2),
+            })
+        );
+        assert_eq!(
+            deserialize_query("q=hello%20world"),
+            Some(Search {
+                q: "hello world".to_string(),
+                page: None,
+            })
+        );
+        // Repeated keys use the first value.
+        assert_eq!(
+            deserialize_query("?q=a&q=b"),
+            Some(Search {
+                q: "a".to_string(),
+                page: None,
+            })
+        );
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Tags {
+            tag: Vec<String>,
+        }
+        assert_eq!(
+            deserialize_query("?tag=a&tag=b+c"),
+            Some(Tags {
+                tag: vec!["a".to_string(), "b c".to_string()],
+            })
+        );
+        // Malformed values.
+        assert_eq!(deserialize_query::<Search>("?q=a&page=abc"), None);
+        assert_eq!(deserialize_query::<Search>("?page=1"), None);
+    }
 }
@@ -0,0 +1,108 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use sycamore::prelude::*;
+use sycamore_router::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Route, Clone)]
+enum Routes {
+    #[to("/")]
+    Home,
+    #[to("/admin/<_..>")]
+    #[allow(dead_code)]
+    // The nested router matches the leftover segments itself.
+    Admin(Vec<String>),
+    #[not_found]
+    NotFound,
+}
+
+#[derive(Route, Clone, Copy)]
+enum AdminRoutes {
+    #[to("/")]
+    Dashboard,
+    #[to("/users")]
+    Users,
+    #[not_found]
+    NotFound,
+}
+
+type Navigate = Rc<dyn Fn(&str)>;
+
+#[wasm_bindgen_test]
+fn use_navigate_with_hash_integration() {
+    window()
+        .history()
+        .unwrap()
+        .replace_state_with_url(&JsValue::UNDEFINED, "", Some("#/admin"))
+        .unwrap();
+    let pathname = window().location().pathname().unwrap();
+
+    let container = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&container).unwrap();
+
+    let navigate: Rc<RefCell<Option<Navigate>>> = Rc::default();
+    let root = create_root({
+        let container = container.clone();
+        let navigate = navigate.clone();
+        move || {
+            sycamore::render_in_scope(
+                move || {
+                    view! {
+                        Router(
+                            integration=HashIntegration::new(),
+                            view=move |route: ReadSignal<Routes>| view! {
+                                (match route.get_clone() {
+                                    Routes::Home => view! { "Home" },
+                                    Routes::Admin(_) => {
+                                        let navigate = navigate.clone();
+                                        view! {
+                                            NestedRouter(view=move |route: ReadSignal<AdminRoutes>| {
+                                                *navigate.borrow_mut() = Some(Rc::new(use_navigate()));
+                                                view! {
+                                                    (match route.get() {
+                                                        AdminRoutes::Dashboard => "Dashboard",
+                                                        AdminRoutes::Users => "Users",
+                                                        AdminRoutes::NotFound => "Admin Not Found",
+                                                    })
+                                                }
+                                            })
+                                        }
+                                    }
+                                    Routes::NotFound => view! { "Not Found" },
+                                })
+                            },
+                        )
+                    }
+                },
+                &container,
+            );
+        }
+    });
+    assert_eq!(container.text_content().unwrap(), "Dashboard");
+
+    // The nested router is re-created when the route changes, so get the latest `navigate`.
+    let navigate = |url: &str| {
+        let f = navigate.borrow().clone().unwrap();
+        f(url);
+    };
+
+    // `use_navigate` prefixes the url with the path of the nested router.
+    navigate("#/users");
+    assert_eq!(container.text_content().unwrap(), "Users");
+    assert_eq!(window().location().hash().unwrap(), "#/admin/users");
+
+    // Urls that are not in the fragment are moved into it instead of changing the pathname.
+    navigate("/");
+    assert_eq!(container.text_content().unwrap(), "Dashboard");
+    assert_eq!(window().location().hash().unwrap(), "#/admin/");
+    assert_eq!(window().location().pathname().unwrap(), pathname);
+
+    root.dispose();
+    container.remove();
+}
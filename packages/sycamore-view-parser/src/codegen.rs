@@ -9,10 +9,158 @@ use syn::{Expr, Pat};
 
 use crate::ir::{DynNode, Node, Prop, PropType, Root, TagIdent, TagNode, TextNode};
 
+#[derive(Default)]
 pub struct Codegen {
     // TODO: configure mode: Client, Hydrate, SSR
+    /// The namespace of the element that is currently being generated.
+    namespace: Namespace,
 }
 
+/// The XML namespace of an element. This is used to pick the right element for tags that exist in
+/// more than one namespace, such as `a` and `title`.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+enum Namespace {
+    #[default]
+    Html,
+    Svg,
+}
+
+/// Tags that are both HTML and SVG elements. Inside an `svg` element, these are created with the
+/// `svg_` prefixed constructor instead.
+const SVG_AMBIGUOUS_TAGS: &[&str] = &["a", "script", "style", "title"];
+
+/// The events that are defined in `sycamore::web::events`. Any other event name is treated as a
+/// custom event.
+// NOTE: This must be kept in sync with the events in `sycamore-web/src/events.rs`.
+const EVENTS: &[&str] = &[
+    "DOMContentLoaded",
+    "abort",
+    "afterprint",
+    "animationcancel",
+    "animationend",
+    "animationiteration",
+    "animationstart",
+    "auxclick",
+    "beforeinput",
+    "beforeprint",
+    "beforeunload",
+    "blur",
+    "canplay",
+    "canplaythrough",
+    "change",
+    "click",
+    "close",
+    "compositionend",
+    "compositionstart",
+    "compositionupdate",
+    "contextmenu",
+    "copy",
+    "cuechange",
+    "cut",
+    "dblclick",
+    "devicemotion",
+    "deviceorientation",
+    "drag",
+    "dragend",
+    "dragenter",
+    "dragleave",
+    "dragover",
+    "dragstart",
+    "drop",
+    "durationchange",
+    "emptied",
+    "ended",
+    "error",
+    "focus",
+    "focusin",
+    "focusout",
+    "formdata",
+    "fullscreenchange",
+    "fullscreenerror",
+    "gamepadconnected",
+    "gamepaddisconnected",
+    "gotpointercapture",
+    "hashchange",
+    "input",
+    "invalid",
+    "keydown",
+    "keypress",
+    "keyup",
+    "languagechange",
+    "load",
+    "loadeddata",
+    "loadedmetadata",
+    "loadstart",
+    "lostpointercapture",
+    "message",
+    "messageerror",
+    "mousedown",
+    "mouseenter",
+    "mouseleave",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "offline",
+    "online",
+    "orientationchange",
+    "pagehide",
+    "pageshow",
+    "paste",
+    "pause",
+    "play",
+    "playing",
+    "pointercancel",
+    "pointerdown",
+    "pointerenter",
+    "pointerleave",
+    "pointerlockchange",
+    "pointerlockerror",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "popstate",
+    "progress",
+    "ratechange",
+    "readystatechange",
+    "rejectionhandled",
+    "reset",
+    "resize",
+    "scroll",
+    "securitypolicyviolation",
+    "seeked",
+    "seeking",
+    "select",
+    "selectionchange",
+    "selectstart",
+    "slotchange",
+    "stalled",
+    "storage",
+    "submit",
+    "suspend",
+    "timeupdate",
+    "toggle",
+    "touchcancel",
+    "touchend",
+    "touchmove",
+    "touchstart",
+    "transitioncancel",
+    "transitionend",
+    "transitionrun",
+    "transitionstart",
+    "unhandledrejection",
+    "unload",
+    "visibilitychange",
+    "volumechange",
+    "waiting",
+    "webkitanimationend",
+    "webkitanimationiteration",
+    "webkitanimationstart",
+    "webkittransitionend",
+    "wheel",
+];
+
 impl Codegen {
     pub fn root(&self, root: &Root) -> TokenStream {
         match &root.0[..] {
@@ -66,28 +214,68 @@ impl Codegen {
             children,
         } = element;
 
+        // The inner html replaces the children of the element.
+        if !children.0.is_empty() {
+            let inner_html = props.iter().find(|prop| {
+                matches!(&prop.ty, PropType::Plain { ident } if ident == "dangerously_set_inner_html")
+            });
+            if let Some(inner_html) = inner_html {
+                return syn::Error::new(
+                    inner_html.span,
+                    "an element with `dangerously_set_inner_html` cannot have children",
+                )
+                .to_compile_error();
+            }
+        }
+
         let attributes = props.iter().map(|attr| self.attribute(attr));
 
+        // Children of `svg` are in the SVG namespace, except inside of `foreignObject`, which
+        // contains HTML again.
+        let namespace = match ident {
+            TagIdent::Path(tag) if tag.is_ident("svg") => Namespace::Svg,
+            TagIdent::Path(tag) if tag.is_ident("foreignObject") => Namespace::Html,
+            _ => self.namespace,
+        };
+        let codegen = Codegen { namespace };
         let children = children
             .0
             .iter()
-            .map(|child| self.node(child))
+            .map(|child| codegen.node(child))
             .collect::<Vec<_>>();
 
         match ident {
             TagIdent::Path(tag) => {
-                assert!(tag.get_ident().is_some(), "elements must be an ident");
+                let tag = tag.get_ident().expect("elements must be an ident");
+                let tag = if self.namespace == Namespace::Svg
+                    && SVG_AMBIGUOUS_TAGS.contains(&tag.to_string().as_str())
+                {
+                    quote::format_ident!("svg_{tag}", span = tag.span())
+                } else {
+                    tag.clone()
+                };
                 quote! {
                     ::sycamore::rt::View::from(
                         ::sycamore::rt::tags::#tag().children(::std::vec![#(#children),*])#(#attributes)*
                     )
                 }
             }
+            // `annotation-xml` is a MathML element and is never a valid custom element name.
+            TagIdent::Hyphenated(tag) if tag == "annotation-xml" => quote! {
+                ::sycamore::rt::View::from(
+                    ::sycamore::rt::tags::annotation_xml().children(::std::vec![#(#children),*])#(#attributes)*
+                )
+            },
             TagIdent::Hyphenated(tag) => quote! {
                 ::sycamore::rt::View::from(
                     ::sycamore::rt::custom_element(#tag).children(::std::vec![#(#children),*])#(#attributes)*
                 )
             },
+            TagIdent::Dyn(tag) => quote! {
+                ::sycamore::rt::View::from(
+                    ::sycamore::rt::tag(#tag).children(::std::vec![#(#children),*])#(#attributes)*
+                )
+            },
         }
     }
 
@@ -110,7 +298,16 @@ impl Codegen {
                 quote! { .attr(#ident, #dyn_value) }
             }
             PropType::Directive { dir, ident } => match dir.to_string().as_str() {
-                "on" => quote! { .on(::sycamore::rt::events::#ident, #value) },
+                "on" if EVENTS.contains(&ident.to_string().as_str()) => {
+                    quote! { .on(::sycamore::rt::events::#ident, #value) }
+                }
+                // Event names that are not standard events are treated as custom events.
+                "on" => {
+                    let name = ident.to_string();
+                    quote! {
+                        .on(::sycamore::rt::events::custom::<::sycamore::rt::events::Event>(#name), #value)
+                    }
+                }
                 "prop" => {
                     let ident = ident.to_string();
                     quote! { .prop(#ident, #dyn_value) }
@@ -119,6 +316,19 @@ impl Codegen {
                 _ => syn::Error::new(dir.span(), format!("unknown directive `{dir}`"))
                     .to_compile_error(),
             },
+            // Hyphenated event names are never standard events, so `on:` is treated as a custom
+            // event.
+            PropType::DirectiveHyphenated { dir, ident } => match dir.to_string().as_str() {
+                "on" => quote! {
+                    .on(::sycamore::rt::events::custom::<::sycamore::rt::events::Event>(#ident), #value)
+                },
+                "prop" => quote! { .prop(#ident, #dyn_value) },
+                _ => syn::Error::new(
+                    dir.span(),
+                    format!("directive `{dir}` does not support hyphenated names"),
+                )
+                .to_compile_error(),
+            },
             PropType::Ref => quote! { .r#ref(#value) },
             PropType::Spread => quote! { .spread(#value) },
         }
@@ -134,7 +344,9 @@ impl Codegen {
     ) -> TokenStream {
         let ident = match ident {
             TagIdent::Path(path) => path,
-            TagIdent::Hyphenated(_) => unreachable!("hyphenated tags are not components"),
+            TagIdent::Hyphenated(_) | TagIdent::Dyn(_) => {
+                unreachable!("hyphenated and dynamic tags are not components")
+            }
         };
 
         let plain = props
@@ -156,7 +368,9 @@ impl Codegen {
         let children_quoted = if children.0.is_empty() {
             quote! {}
         } else {
-            let codegen = Codegen {};
+            let codegen = Codegen {
+                namespace: self.namespace,
+            };
             let children = codegen.root(children);
             quote! {
                 .children(
@@ -195,6 +409,8 @@ fn is_component(ident: &TagIdent) -> bool {
         }
         // A hyphenated tag is always a custom-element and therefore never a component.
         TagIdent::Hyphenated(_) => false,
+        // A dynamic tag is always an element.
+        TagIdent::Dyn(_) => false,
     }
 }
 
@@ -285,9 +501,9 @@ fn is_dyn_pattern(pat: &Pat) -> bool {
 fn is_dyn_macro(m: &syn::Macro) -> bool {
     // Bodies of nested inner view! macros will be checked for dynamic
     // parts when their own codegen is run.
-    !m.path
+    m.path
         .get_ident()
-        .is_some_and(|ident| "view" == &ident.to_string())
+        .is_none_or(|ident| "view" != &ident.to_string())
 }
 
 fn is_dyn_block(block: &syn::Block) -> bool {
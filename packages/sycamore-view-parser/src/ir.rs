@@ -31,6 +31,8 @@ pub enum TagIdent {
     /// A hyphenated ident. Can not include any paths.
     /// This is used for custom elements support.
     Hyphenated(String),
+    /// Syntax: `(dyn_tag(<expr>))`. A tag name that is only known at runtime.
+    Dyn(Expr),
 }
 
 impl TagIdent {
@@ -38,6 +40,7 @@ impl TagIdent {
         match self {
             Self::Path(path) => path.span(),
             Self::Hyphenated(_) => Span::call_site(),
+            Self::Dyn(expr) => expr.span(),
         }
     }
 }
@@ -57,6 +60,8 @@ pub enum PropType {
     PlainQuoted { ident: String },
     /// Syntax: `<dir>:<prop>=<expr>`.
     Directive { dir: Ident, ident: Ident },
+    /// Syntax: `<dir>:<hyphenated-prop>=<expr>`.
+    DirectiveHyphenated { dir: Ident, ident: String },
     /// Syntax: `r#ref=<expr>`.
     Ref,
     /// Syntax: `..attributes=<expr>`
@@ -26,7 +26,11 @@ impl Node {
         if input.peek(LitStr) {
             Some(NodeType::Text)
         } else if input.peek(Paren) {
-            Some(NodeType::Dyn)
+            if TagIdent::peek_dyn_tag(&input) {
+                Some(NodeType::Tag)
+            } else {
+                Some(NodeType::Dyn)
+            }
         } else if input.peek(Token![::]) || input.peek(Ident::peek_any) {
             Some(NodeType::Tag)
         } else {
@@ -87,8 +91,32 @@ impl Parse for TagNode {
     }
 }
 
+impl TagIdent {
+    /// Returns `true` if the input starts with a dynamic tag, i.e. `(dyn_tag(<expr>))`.
+    fn peek_dyn_tag(input: ParseStream) -> bool {
+        let parse = |input: ParseStream| -> Result<bool> {
+            let content;
+            parenthesized!(content in input);
+            let ident: Ident = content.call(Ident::parse_any)?;
+            Ok(ident == "dyn_tag" && content.peek(Paren))
+        };
+        parse(&input.fork()).unwrap_or(false)
+    }
+}
+
 impl Parse for TagIdent {
     fn parse(input: ParseStream) -> Result<Self> {
+        if Self::peek_dyn_tag(input) {
+            let content;
+            parenthesized!(content in input);
+            let _: Ident = content.call(Ident::parse_any)?;
+            let name;
+            parenthesized!(name in content);
+            if !content.is_empty() {
+                return Err(content.error("expected `)` after `dyn_tag(...)`"));
+            }
+            return Ok(Self::Dyn(name.parse()?));
+        }
         let is_hyphenated = input.peek2(Token![-]);
         if is_hyphenated {
             let mut segments: Vec<Ident> = vec![input.call(Ident::parse_any)?];
@@ -112,7 +140,7 @@ impl Parse for Prop {
     fn parse(input: ParseStream) -> Result<Self> {
         let span = input.span();
         let ty = input.parse()?;
-        if !matches!(ty, PropType::Spread { .. }) {
+        if !matches!(ty, PropType::Spread) {
             let _eqs: Token![=] = input.parse()?;
         }
         let value = input.parse()?;
@@ -148,7 +176,19 @@ impl Parse for PropType {
                 } else if input.peek(Token![:]) {
                     let _colon: Token![:] = input.parse()?;
                     let ident = input.call(Ident::parse_any)?;
-                    Ok(Self::Directive { dir: name, ident })
+                    if input.peek(Token![-]) {
+                        let mut segments = vec![ident.to_string()];
+                        while input.peek(Token![-]) {
+                            let _: Token![-] = input.parse()?;
+                            segments.push(input.call(Ident::parse_any)?.to_string());
+                        }
+                        Ok(Self::DirectiveHyphenated {
+                            dir: name,
+                            ident: segments.join("-"),
+                        })
+                    } else {
+                        Ok(Self::Directive { dir: name, ident })
+                    }
                 } else {
                     Ok(Self::Plain { ident: name })
                 }
@@ -29,6 +29,55 @@ impl AttributeValue for MaybeDyn<JsValue> {
     }
 }
 
+/// A list of classes that are toggled individually. See [`GlobalAttributes::class_list`].
+pub(crate) struct ClassList {
+    #[allow(clippy::type_complexity)]
+    classes: Box<dyn Fn() -> Vec<(Cow<'static, str>, bool)>>,
+}
+
+impl ClassList {
+    pub(crate) fn new(classes: impl Fn() -> Vec<(Cow<'static, str>, bool)> + 'static) -> Self {
+        Self {
+            classes: Box::new(classes),
+        }
+    }
+}
+
+impl AttributeValue for ClassList {
+    fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>) {
+        if is_ssr!() {
+            let classes = (self.classes)()
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(class, _)| class)
+                .collect::<Vec<_>>();
+            if !classes.is_empty() {
+                el.set_attribute(name, classes.join(" ").into());
+            }
+        } else {
+            let class_list = el
+                .as_web_sys()
+                .unchecked_ref::<web_sys::Element>()
+                .class_list();
+            // The classes returned by the previous run, so that classes that are no longer
+            // returned can be removed.
+            let mut prev = Vec::<Cow<'static, str>>::new();
+            create_effect(move || {
+                let classes = (self.classes)();
+                for class in &prev {
+                    if !classes.iter().any(|(c, _)| c == class) {
+                        class_list.remove_1(class).unwrap_throw();
+                    }
+                }
+                for (class, enabled) in &classes {
+                    class_list.toggle_with_force(class, *enabled).unwrap_throw();
+                }
+                prev = classes.into_iter().map(|(class, _)| class).collect();
+            });
+        }
+    }
+}
+
 /// Trait used to implement `AttributeValue` for `Box<dyn AttributeValue>`.
 #[doc(hidden)]
 pub trait AttributeValueBoxed: 'static {
@@ -53,11 +102,48 @@ impl AttributeValue for Box<dyn AttributeValue> {
 /// Implemented for all types that can accept attributes ([`AttributeValue`]).
 pub trait SetAttribute {
     fn set_attribute(&mut self, name: &'static str, value: impl AttributeValue);
+    /// Set an attribute whose name is only known at runtime.
+    ///
+    /// By default, this calls [`set_attribute`](Self::set_attribute) and leaks `name` if it is
+    /// owned.
+    fn set_attribute_owned(&mut self, name: Cow<'static, str>, value: impl AttributeValue) {
+        let name = match name {
+            Cow::Borrowed(name) => name,
+            Cow::Owned(name) => name.leak(),
+        };
+        self.set_attribute(name, value);
+    }
     fn set_event_handler(
         &mut self,
         name: &'static str,
         value: impl FnMut(web_sys::Event) + 'static,
     );
+    /// Set an event handler for an event whose name is only known at runtime.
+    ///
+    /// By default, this calls [`set_event_handler`](Self::set_event_handler) and leaks `name` if
+    /// it is owned.
+    fn set_event_handler_owned(
+        &mut self,
+        name: Cow<'static, str>,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        let name = match name {
+            Cow::Borrowed(name) => name,
+            Cow::Owned(name) => name.leak(),
+        };
+        self.set_event_handler(name, value);
+    }
+    /// Set an event handler that is removed after the first event.
+    ///
+    /// By default, this calls [`set_event_handler_owned`](Self::set_event_handler_owned), so the
+    /// handler itself has to ignore later events.
+    fn set_event_handler_once(
+        &mut self,
+        name: Cow<'static, str>,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.set_event_handler_owned(name, value);
+    }
 }
 
 impl<T> SetAttribute for T
@@ -68,6 +154,10 @@ where
         value.set_self(self.as_html_node(), name.into());
     }
 
+    fn set_attribute_owned(&mut self, name: Cow<'static, str>, value: impl AttributeValue) {
+        value.set_self(self.as_html_node(), name);
+    }
+
     fn set_event_handler(
         &mut self,
         name: &'static str,
@@ -75,6 +165,22 @@ where
     ) {
         self.as_html_node().set_event_handler(name.into(), value);
     }
+
+    fn set_event_handler_owned(
+        &mut self,
+        name: Cow<'static, str>,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.as_html_node().set_event_handler(name, value);
+    }
+
+    fn set_event_handler_once(
+        &mut self,
+        name: Cow<'static, str>,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.as_html_node().set_event_handler_once(name, value);
+    }
 }
 
 /// A special prop type that can be used to spread attributes onto an element.
@@ -82,7 +188,7 @@ where
 pub struct Attributes {
     values: Vec<(Cow<'static, str>, Box<dyn AttributeValue>)>,
     #[allow(clippy::type_complexity)]
-    event_handlers: Vec<(Cow<'static, str>, Box<dyn FnMut(web_sys::Event)>)>,
+    event_handlers: Vec<(Cow<'static, str>, Box<dyn FnMut(web_sys::Event)>, bool)>,
 }
 
 impl SetAttribute for Attributes {
@@ -90,12 +196,33 @@ impl SetAttribute for Attributes {
         self.values.push((name.into(), Box::new(value)));
     }
 
+    fn set_attribute_owned(&mut self, name: Cow<'static, str>, value: impl AttributeValue) {
+        self.values.push((name, Box::new(value)));
+    }
+
     fn set_event_handler(
         &mut self,
         name: &'static str,
         value: impl FnMut(web_sys::Event) + 'static,
     ) {
-        self.event_handlers.push((name.into(), Box::new(value)));
+        self.event_handlers
+            .push((name.into(), Box::new(value), false));
+    }
+
+    fn set_event_handler_owned(
+        &mut self,
+        name: Cow<'static, str>,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.event_handlers.push((name, Box::new(value), false));
+    }
+
+    fn set_event_handler_once(
+        &mut self,
+        name: Cow<'static, str>,
+        value: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.event_handlers.push((name, Box::new(value), true));
     }
 }
 
@@ -109,14 +236,249 @@ impl Attributes {
         for (name, value) in self.values {
             value.set_self(el, name);
         }
-        for (name, handler) in self.event_handlers {
-            el.set_event_handler(name, handler);
+        for (name, handler, once) in self.event_handlers {
+            if once {
+                el.set_event_handler_once(name, handler);
+            } else {
+                el.set_event_handler(name, handler);
+            }
+        }
+    }
+}
+
+/// A typed inline style that can be passed to the `styles` attribute. See
+/// [`GlobalAttributes::styles`].
+///
+/// Each declaration can be static or reactive. In the browser, each declaration is set with
+/// `style.setProperty` and updated on its own when its value changes, so it does not override
+/// the properties set by a static `style` attribute or in other ways. A declaration whose value is
+/// `None` or empty is removed, which makes it easy to remove a property conditionally. When
+/// rendering on the server, the declarations are added to the `style` attribute.
+///
+/// Note that the `view!` macro wraps attribute values that are not a plain variable or literal
+/// in a closure. The closure is then re-run whenever one of the reactive values changes and only
+/// the properties that have changed are updated.
+///
+/// Common properties have their own methods. Any other property, including custom properties, can
+/// be set with [`set`](Self::set).
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_web::Style;
+/// # fn Comp() -> View {
+/// let width = create_signal(100);
+/// let is_hidden = create_signal(false);
+/// view! {
+///     div(
+///         style="margin: 0",
+///         styles=Style::new()
+///             .color("red")
+///             .width(move || format!("{}px", width.get()))
+///             .display(move || is_hidden.get().then_some("none"))
+///             .set("--accent", "blue")
+///     )
+/// }
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct Style {
+    declarations: Vec<(Cow<'static, str>, StringAttribute)>,
+    /// Lists of declarations that are created with a closure. See [`Style::from`].
+    #[allow(clippy::type_complexity)]
+    lists: Vec<Rc<dyn Fn() -> Vec<(Cow<'static, str>, Cow<'static, str>)>>>,
+}
+
+macro_rules! impl_style_properties {
+    ($($ident:ident ($name:literal),)*) => {
+        $(
+            #[doc = concat!("Sets the `", $name, "` property.")]
+            pub fn $ident(self, value: impl Into<StringAttribute>) -> Self {
+                self.set($name, value)
+            }
+        )*
+    };
+}
+
+impl Style {
+    /// Create a new empty [`Style`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the CSS property `name` to `value`. If the property was already set, it is
+    /// overridden.
+    pub fn set(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl Into<StringAttribute>,
+    ) -> Self {
+        let name = name.into();
+        self.declarations.retain(|(existing, _)| *existing != name);
+        self.declarations.push((name, value.into()));
+        self
+    }
+
+    impl_style_properties! {
+        align_items("align-items"),
+        background("background"),
+        background_color("background-color"),
+        border("border"),
+        border_radius("border-radius"),
+        bottom("bottom"),
+        box_shadow("box-shadow"),
+        color("color"),
+        cursor("cursor"),
+        display("display"),
+        flex("flex"),
+        flex_direction("flex-direction"),
+        font_family("font-family"),
+        font_size("font-size"),
+        font_weight("font-weight"),
+        gap("gap"),
+        height("height"),
+        justify_content("justify-content"),
+        left("left"),
+        line_height("line-height"),
+        margin("margin"),
+        max_height("max-height"),
+        max_width("max-width"),
+        min_height("min-height"),
+        min_width("min-width"),
+        opacity("opacity"),
+        overflow("overflow"),
+        padding("padding"),
+        pointer_events("pointer-events"),
+        position("position"),
+        right("right"),
+        text_align("text-align"),
+        top("top"),
+        transform("transform"),
+        transition("transition"),
+        visibility("visibility"),
+        width("width"),
+        z_index("z-index"),
+    }
+
+    /// Renders the current values of the declarations into a `style` attribute value. Returns
+    /// `None` if there are no declarations with a value.
+    fn render(self) -> Option<Cow<'static, str>> {
+        let style = self
+            .into_declarations()
+            .into_iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect::<Vec<_>>();
+        (!style.is_empty()).then(|| style.join("; ").into())
+    }
+}
+
+/// A list of CSS declarations that is returned by a closure passed to
+/// [`GlobalAttributes::styles`]. This is implemented for [`Style`] and for lists of property
+/// names, each with its value.
+pub trait StyleDeclarations {
+    /// Returns the current value of each property. Properties without a value are left out.
+    fn into_declarations(self) -> Vec<(Cow<'static, str>, Cow<'static, str>)>;
+}
+
+impl<N, V> StyleDeclarations for Vec<(N, V)>
+where
+    N: Into<Cow<'static, str>>,
+    V: Into<Cow<'static, str>>,
+{
+    fn into_declarations(self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        self.into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .filter(|(_, value)| !value.is_empty())
+            .collect()
+    }
+}
+
+impl StyleDeclarations for Style {
+    fn into_declarations(self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        self.declarations
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value.get_clone()?)))
+            .chain(self.lists.iter().flat_map(|list| list()))
+            .filter(|(_, value)| !value.is_empty())
+            .collect()
+    }
+}
+
+/// Creates a [`Style`] from a closure that returns the declarations. The closure is re-run
+/// whenever a signal that is accessed inside changes, and the properties that it no longer
+/// returns are removed.
+impl<F, T> From<F> for Style
+where
+    F: Fn() -> T + 'static,
+    T: StyleDeclarations,
+{
+    fn from(f: F) -> Self {
+        Self {
+            declarations: Vec::new(),
+            lists: vec![Rc::new(move || f().into_declarations())],
+        }
+    }
+}
+
+impl AttributeValue for Style {
+    fn set_self(self, el: &mut HtmlNode, name: Cow<'static, str>) {
+        if is_ssr!() {
+            // This is merged with the `style` attribute if it is already set.
+            el.set_attribute(name, MaybeDyn::Static(self.render()));
+            return;
+        }
+        // Both HTML and SVG elements have a `style` property.
+        let style: web_sys::CssStyleDeclaration =
+            js_sys::Reflect::get(el.as_web_sys(), &"style".into())
+                .unwrap_throw()
+                .unchecked_into();
+        let set = {
+            let style = style.clone();
+            move |name: &str, value: Option<&str>| match value.filter(|value| !value.is_empty()) {
+                Some(value) => style.set_property(name, value).unwrap_throw(),
+                None => {
+                    style.remove_property(name).unwrap_throw();
+                }
+            }
+        };
+        for (name, value) in self.declarations {
+            match value.as_static() {
+                Some(value) => set(&name, value.as_deref()),
+                None => {
+                    let set = set.clone();
+                    create_effect(move || set(&name, value.get_clone().as_deref()));
+                }
+            }
+        }
+        for list in self.lists {
+            // The declarations returned by the previous run, so that only the properties that
+            // have changed are updated and properties that are no longer returned are removed.
+            let mut prev = Vec::<(Cow<'static, str>, Cow<'static, str>)>::new();
+            let style = style.clone();
+            create_effect(move || {
+                let styles = list();
+                for (name, _) in &prev {
+                    if !styles.iter().any(|(n, _)| n == name) {
+                        style.remove_property(name).unwrap_throw();
+                    }
+                }
+                for declaration in &styles {
+                    if !prev.contains(declaration) {
+                        style
+                            .set_property(&declaration.0, &declaration.1)
+                            .unwrap_throw();
+                    }
+                }
+                prev = styles;
+            });
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use expect_test::{expect, Expect};
 
     use super::*;
@@ -149,4 +511,138 @@ mod tests {
             expect![[r#"<div class="test-class" id="test-id" data-hk="0.0"></div>"#]],
         );
     }
+
+    #[test]
+    fn attrs_are_sorted_by_name() {
+        let attrs = HashMap::from([
+            ("id".to_string(), StringAttribute::from("test-id")),
+            (
+                "class".to_string(),
+                StringAttribute::from(move || "test-class"),
+            ),
+            ("aria-label".to_string(), StringAttribute::from("label")),
+        ]);
+        check(
+            move || crate::tags::div().attrs(attrs),
+            expect![[
+                r#"<div aria-label="label" class="test-class" id="test-id" data-hk="0.0"></div>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn set_attribute_owned_defaults_to_set_attribute() {
+        /// Only implements the required methods, like implementors outside of this crate.
+        #[derive(Default)]
+        struct Names(Vec<&'static str>);
+
+        impl SetAttribute for Names {
+            fn set_attribute(&mut self, name: &'static str, _value: impl AttributeValue) {
+                self.0.push(name);
+            }
+
+            fn set_event_handler(
+                &mut self,
+                name: &'static str,
+                _value: impl FnMut(web_sys::Event) + 'static,
+            ) {
+                self.0.push(name);
+            }
+        }
+
+        let mut names = Names::default();
+        names.set_attribute("id", StringAttribute::from("test-id"));
+        names.set_attribute_owned("class".into(), StringAttribute::from("test-class"));
+        names.set_attribute_owned(
+            format!("data-{}", "state").into(),
+            StringAttribute::from("1"),
+        );
+        assert_eq!(names.0, ["id", "class", "data-state"]);
+    }
+
+    #[test]
+    fn data_attr_is_prefixed() {
+        check(
+            move || {
+                let state = create_signal("closed");
+                crate::tags::div()
+                    .data_attr("state", state)
+                    .data_attr("user-id", "1")
+            },
+            expect![[r#"<div data-state="closed" data-user-id="1" data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn aria_bool_attributes_render_false() {
+        check(
+            || {
+                let is_open = create_signal(false);
+                view! {
+                    button(aria_expanded=is_open, aria_hidden=false, aria_controls="menu") {
+                        "Menu"
+                    }
+                }
+            },
+            expect![[
+                r#"<button aria-expanded="false" aria-hidden="false" aria-controls="menu" data-hk="0.0">Menu</button>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn style_static() {
+        let style = Style::new()
+            .color("red")
+            .width("10px")
+            .set("--accent", "blue")
+            .display(None::<&'static str>);
+        check(
+            move || view! { div(styles=style) },
+            expect![[
+                r#"<div style="color: red; width: 10px; --accent: blue" data-hk="0.0"></div>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn style_override() {
+        let style = Style::new().color("red").color("blue");
+        check(
+            move || view! { div(styles=style) },
+            expect![[r#"<div style="color: blue" data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn style_empty() {
+        check(
+            move || view! { div(styles=Style::new().color("")) },
+            expect![[r#"<div data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn style_merged_with_static_style() {
+        check(
+            move || {
+                let width = create_signal(10);
+                view! {
+                    div(
+                        style="margin: 0",
+                        styles=Style::new().width(move || format!("{}px", width.get())),
+                    )
+                }
+            },
+            expect![[r#"<div style="margin: 0; width: 10px" data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn style_from_closure() {
+        check(
+            move || view! { div(styles=move || vec![("color", "red"), ("opacity", "0.5")]) },
+            expect![[r#"<div style="color: red; opacity: 0.5" data-hk="0.0"></div>"#]],
+        );
+    }
 }
@@ -1,8 +1,26 @@
 //! Definition for bind-able attributes/properties.
 
+use std::fmt::Display;
+use std::str::FromStr;
+
 use crate::events::EventDescriptor;
 use crate::*;
 
+/// A binding that can be set up with the `bind:` directive.
+///
+/// This is implemented for all [`BindDescriptor`]s as well as for [`valueAsNumber`], [`group`],
+/// [`groupMultiple`] and [`selectedValues`].
+pub trait Binding<S> {
+    /// Sets up the binding between the element and `signal`.
+    fn bind<T: GlobalAttributes>(el: T, signal: S) -> T;
+}
+
+impl<E: BindDescriptor> Binding<Signal<E::ValueTy>> for E {
+    fn bind<T: GlobalAttributes>(el: T, signal: Signal<E::ValueTy>) -> T {
+        bind_property::<E, T>(el, signal)
+    }
+}
+
 /// Description for a bind-able attribute/property.
 pub trait BindDescriptor {
     /// The event which we listen to to update the value.
@@ -13,10 +31,16 @@ pub trait BindDescriptor {
     const TARGET_PROPERTY: &'static str;
     /// Function for converting from JS to Rust type.
     const CONVERT_FROM_JS: for<'a> fn(&'a JsValue) -> Option<Self::ValueTy>;
+    /// Whether the event is also fired when the property is set programmatically (e.g. `scroll`).
+    ///
+    /// If this is `true`, the event that is fired in response to setting the property from the
+    /// signal is ignored so that the signal is not updated with its own value. The signal is also
+    /// only updated at most once per animation frame.
+    const PROGRAMMATIC_EVENTS: bool = false;
 }
 
 macro_rules! impl_bind {
-    ($name:ident: $event:ty, $value:ty, $target:expr, $fn:expr) => {
+    ($name:ident: $event:ty, $value:ty, $target:expr, $fn:expr $(, $programmatic:expr)?) => {
         #[allow(non_camel_case_types)]
         pub struct $name;
         impl BindDescriptor for $name {
@@ -24,18 +48,398 @@ macro_rules! impl_bind {
             type ValueTy = $value;
             const TARGET_PROPERTY: &'static str = $target;
             const CONVERT_FROM_JS: for<'a> fn(&'a JsValue) -> Option<Self::ValueTy> = $fn;
+            $(const PROGRAMMATIC_EVENTS: bool = $programmatic;)?
         }
     };
 }
 
 macro_rules! impl_binds {
-    ($($name:ident: $event:ty, $value:ty, $target:expr, $fn:expr,)*) => {
-        $(impl_bind!($name: $event, $value, $target, $fn);)*
+    ($($name:ident: $event:ty, $value:ty, $target:expr, $fn:expr $(, $programmatic:expr)?;)*) => {
+        $(impl_bind!($name: $event, $value, $target, $fn $(, $programmatic)?);)*
     };
 }
 
 impl_binds! {
-    value: events::input, String, "value", JsValue::as_string,
-    valueAsNumber: events::input, f64, "valueAsNumber", JsValue::as_f64,
-    checked: events::change, bool, "checked", JsValue::as_bool,
+    value: events::input, String, "value", JsValue::as_string;
+    checked: events::change, bool, "checked", JsValue::as_bool;
+    scrollTop: events::scroll, f64, "scrollTop", JsValue::as_f64, true;
+    scrollLeft: events::scroll, f64, "scrollLeft", JsValue::as_f64, true;
+}
+
+/// Binds the `value` of an `<input>` to a `Signal<T>` of a number type, e.g. `f64` or `u32`.
+///
+/// When the user changes the input, its `value` is parsed with [`FromStr`] and the signal is set
+/// to the result. If the input is empty or its `value` cannot be parsed, the signal is left
+/// unchanged. Setting the signal sets the `value` of the input to the signal formatted with
+/// [`Display`].
+///
+/// The `value` is only set if it does not already parse to the value of the signal, so that input
+/// such as `1.` or `1.50` is not reformatted while the user is still typing.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn Comp() -> View {
+/// let quantity = create_signal(1u32);
+/// view! {
+///     input(r#type="number", min="1", bind:valueAsNumber=quantity)
+///     p { "Quantity: " (quantity) }
+/// }
+/// # }
+/// ```
+#[allow(non_camel_case_types)]
+pub struct valueAsNumber;
+
+impl<U: FromStr + Display + PartialEq + 'static> Binding<Signal<U>> for valueAsNumber {
+    fn bind<T: GlobalAttributes>(mut el: T, signal: Signal<U>) -> T {
+        let scope = use_current_scope(); // Run handler inside the current scope.
+        el.set_event_handler(events::input::NAME, move |ev: web_sys::Event| {
+            let input: web_sys::HtmlInputElement = ev.current_target().unwrap().unchecked_into();
+            if let Ok(parsed) = U::from_str(&input.value()) {
+                scope.run_in(|| signal.set(parsed));
+            }
+        });
+        el.set_attribute("valueAsNumber", ParsedValue { signal });
+        el
+    }
+}
+
+/// Attribute value that keeps the `value` of an input in sync with a [`valueAsNumber`] binding.
+struct ParsedValue<U: 'static> {
+    signal: Signal<U>,
+}
+
+impl<U: FromStr + Display + PartialEq + 'static> AttributeValue for ParsedValue<U> {
+    fn set_self(self, el: &mut HtmlNode, _name: Cow<'static, str>) {
+        if is_not_ssr!() {
+            let signal = self.signal;
+            let input: web_sys::HtmlInputElement = el.as_web_sys().clone().unchecked_into();
+            create_effect(move || {
+                signal.with(|current| {
+                    let is_current = U::from_str(&input.value()).is_ok_and(|v| v == *current);
+                    if !is_current {
+                        input.set_value(&current.to_string());
+                    }
+                })
+            });
+        }
+    }
+}
+
+/// Binds a group of radio buttons to a `Signal<T>`.
+///
+/// Each radio button is checked when its `value` parses to the value of the signal. Selecting a
+/// radio button sets the signal to its parsed `value`. All the radio buttons of the group should
+/// be bound to the same signal.
+///
+/// The checked state is first synced after the element is created, so the order of `value` and
+/// `bind:group` does not matter. Radio buttons whose `value` cannot be parsed are never checked.
+/// When rendering on the server, the `checked` attribute is rendered instead. There, the `value`
+/// has to be set before `bind:group`.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn Comp() -> View {
+/// let size = create_signal(2);
+/// view! {
+///     input(r#type="radio", name="size", value="1", bind:group=size)
+///     input(r#type="radio", name="size", value="2", bind:group=size)
+///     input(r#type="radio", name="size", value="3", bind:group=size)
+/// }
+/// # }
+/// ```
+#[allow(non_camel_case_types)]
+pub struct group;
+
+impl<U: FromStr + PartialEq + 'static> Binding<Signal<U>> for group {
+    fn bind<T: GlobalAttributes>(el: T, signal: Signal<U>) -> T {
+        bind_group(
+            el,
+            move |input_value| {
+                U::from_str(input_value)
+                    .ok()
+                    .is_some_and(|item| signal.with(|current| *current == item))
+            },
+            move |input_value, is_checked| {
+                if is_checked {
+                    if let Ok(item) = U::from_str(input_value) {
+                        signal.set(item);
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// Binds a set of checkboxes to a `Signal<Vec<T>>`.
+///
+/// Each checkbox is checked when its `value` parses to an item of the `Vec`. Checking a checkbox
+/// appends its parsed `value` to the `Vec` and unchecking it removes the value from the `Vec`.
+///
+/// See [`group`] for binding radio buttons instead.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn Comp() -> View {
+/// let toppings = create_signal(vec!["cheese".to_string()]);
+/// view! {
+///     input(r#type="checkbox", value="cheese", bind:groupMultiple=toppings)
+///     input(r#type="checkbox", value="ham", bind:groupMultiple=toppings)
+///     input(r#type="checkbox", value="olives", bind:groupMultiple=toppings)
+/// }
+/// # }
+/// ```
+#[allow(non_camel_case_types)]
+pub struct groupMultiple;
+
+impl<U: FromStr + PartialEq + 'static> Binding<Signal<Vec<U>>> for groupMultiple {
+    fn bind<T: GlobalAttributes>(el: T, signal: Signal<Vec<U>>) -> T {
+        bind_group(
+            el,
+            move |input_value| {
+                U::from_str(input_value)
+                    .ok()
+                    .is_some_and(|item| signal.with(|items| items.contains(&item)))
+            },
+            move |input_value, is_checked| {
+                let Ok(item) = U::from_str(input_value) else {
+                    return;
+                };
+                let is_included = signal.with(|items| items.contains(&item));
+                if is_checked && !is_included {
+                    signal.update(|items| items.push(item));
+                } else if !is_checked && is_included {
+                    signal.update(|items| items.retain(|other| *other != item));
+                }
+            },
+        )
+    }
+}
+
+/// Sets up a binding between an input of a group and a signal that is shared by the group.
+///
+/// `is_checked` is called with the `value` of the input to determine whether it is checked.
+/// `on_change` is called with the `value` and the checked state of the input when the user
+/// changes it.
+fn bind_group<T: GlobalAttributes>(
+    mut el: T,
+    is_checked: impl Fn(&str) -> bool + 'static,
+    on_change: impl Fn(&str, bool) + 'static,
+) -> T {
+    let scope = use_current_scope(); // Run handler inside the current scope.
+    el.set_event_handler(events::change::NAME, move |ev: web_sys::Event| {
+        let input: web_sys::HtmlInputElement = ev.current_target().unwrap().unchecked_into();
+        scope.run_in(|| on_change(&input.value(), input.checked()));
+    });
+    el.set_attribute(
+        "bind:group",
+        WithNode::new(move |el| {
+            is_ssr! {
+                // Inputs without a `value` have a default value of `on`.
+                let is_input_checked = is_checked(el.attribute("value").unwrap_or("on"));
+                el.set_bool_attribute("checked".into(), is_input_checked.into());
+            }
+            is_not_ssr! {
+                let input: web_sys::HtmlInputElement = el.as_web_sys().clone().unchecked_into();
+                // Defer the first sync so that the `value` of the input is set, even if it comes
+                // after the binding.
+                create_effect_deferred(move || input.set_checked(is_checked(&input.value())));
+            }
+        }),
+    );
+    el
+}
+
+/// Attribute value that calls a function with the element it is set on. This lets a [`Binding`]
+/// set up the element directly.
+struct WithNode(Box<dyn FnOnce(&mut HtmlNode)>);
+
+impl WithNode {
+    fn new(f: impl FnOnce(&mut HtmlNode) + 'static) -> Self {
+        Self(Box::new(f))
+    }
+}
+
+impl AttributeValue for WithNode {
+    fn set_self(self, el: &mut HtmlNode, _name: Cow<'static, str>) {
+        (self.0)(el);
+    }
+}
+
+/// Binds the selected options of a `<select multiple>` to a `Signal<Vec<String>>`.
+///
+/// The signal contains the `value` of each selected option, in the order of the options. Changing
+/// the selection sets the signal and setting the signal updates the `selected` state of each
+/// option.
+///
+/// Options can be added or removed dynamically. When this happens, the signal is left unchanged
+/// and the selected state of the options is synced from the signal, so new options are selected
+/// if their value is in the signal. Values that do not have a matching option are kept in the
+/// signal until the user changes the selection.
+///
+/// When rendering on the server, the `selected` attribute is rendered on the options whose value
+/// is in the signal.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn Comp() -> View {
+/// let selected = create_signal(vec!["red".to_string()]);
+/// view! {
+///     select(multiple=true, bind:selectedValues=selected) {
+///         option(value="red") { "Red" }
+///         option(value="green") { "Green" }
+///         option(value="blue") { "Blue" }
+///     }
+/// }
+/// # }
+/// ```
+#[allow(non_camel_case_types)]
+pub struct selectedValues;
+
+impl Binding<Signal<Vec<String>>> for selectedValues {
+    fn bind<T: GlobalAttributes>(mut el: T, signal: Signal<Vec<String>>) -> T {
+        let scope = use_current_scope(); // Run handler inside the current scope.
+        el.set_event_handler(events::change::NAME, move |ev: web_sys::Event| {
+            let select: web_sys::HtmlSelectElement = ev.current_target().unwrap().unchecked_into();
+            let selected = selected_options(&select)
+                .map(|option| option.value())
+                .collect();
+            scope.run_in(|| signal.set(selected));
+        });
+        el.set_attribute(
+            "bind:selectedValues",
+            WithNode::new(move |el| {
+                is_ssr! {
+                    el.set_selected_values(signal.get_clone());
+                }
+                if is_not_ssr!() {
+                    bind_selected_options(el, signal);
+                }
+            }),
+        );
+        el
+    }
+}
+
+/// Returns an iterator over the options of a `<select>` element.
+fn options(
+    select: &web_sys::HtmlSelectElement,
+) -> impl Iterator<Item = web_sys::HtmlOptionElement> {
+    let options = select.options();
+    (0..options.length()).filter_map(move |i| options.item(i).map(JsCast::unchecked_into))
+}
+
+/// Returns an iterator over the selected options of a `<select>` element.
+fn selected_options(
+    select: &web_sys::HtmlSelectElement,
+) -> impl Iterator<Item = web_sys::HtmlOptionElement> {
+    let options = select.selected_options();
+    (0..options.length()).filter_map(move |i| options.item(i).map(JsCast::unchecked_into))
+}
+
+/// Keeps the selected options of a `<select>` in sync with `signal`.
+fn bind_selected_options(el: &mut HtmlNode, signal: Signal<Vec<String>>) {
+    let select: web_sys::HtmlSelectElement = el.as_web_sys().clone().unchecked_into();
+    let sync = move || {
+        signal.with(|values| {
+            for option in options(&select) {
+                option.set_selected(values.contains(&option.value()));
+            }
+        })
+    };
+    create_effect(sync.clone());
+
+    // Options that are added or removed are synced from the signal. The signal itself is never
+    // updated here, since the options might only be missing temporarily.
+    let reconcile = Rc::new(move || {
+        if signal.is_alive() {
+            untrack(&sync);
+        }
+    });
+    // Sync once the options have been added, and then whenever options are added or removed.
+    queue_microtask({
+        let reconcile = Rc::clone(&reconcile);
+        move || reconcile()
+    });
+    let on_mutation = Closure::<dyn FnMut()>::new(move || reconcile());
+    let observer =
+        web_sys::MutationObserver::new(on_mutation.as_ref().unchecked_ref()).unwrap_throw();
+    let init = web_sys::MutationObserverInit::new();
+    init.set_child_list(true);
+    init.set_subtree(true);
+    observer
+        .observe_with_options(el.as_web_sys(), &init)
+        .unwrap_throw();
+    on_cleanup(move || {
+        observer.disconnect();
+        drop(on_mutation);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use super::*;
+    use crate::tags::{HtmlInputAttributes, HtmlOptionAttributes, HtmlSelectAttributes};
+
+    fn check<T: Into<View>>(view: impl FnOnce() -> T, expect: Expect) {
+        let actual = render_to_string(move || view().into());
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn group_renders_checked() {
+        check(
+            || {
+                let size = create_signal(2);
+                view! {
+                    input(r#type="radio", value="1", bind:group=size)
+                    input(r#type="radio", value="2", bind:group=size)
+                }
+            },
+            expect![[
+                r#"<input type="radio" value="1" data-hk="0.0"><input type="radio" value="2" checked data-hk="0.1">"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn group_multiple_renders_checked() {
+        check(
+            || {
+                let toppings = create_signal(vec!["ham".to_string(), "olives".to_string()]);
+                view! {
+                    input(r#type="checkbox", value="cheese", bind:groupMultiple=toppings)
+                    input(r#type="checkbox", value="ham", bind:groupMultiple=toppings)
+                    input(r#type="checkbox", value="olives", bind:groupMultiple=toppings)
+                }
+            },
+            expect![[
+                r#"<input type="checkbox" value="cheese" data-hk="0.0"><input type="checkbox" value="ham" checked data-hk="0.1"><input type="checkbox" value="olives" checked data-hk="0.2">"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn selected_values_renders_selected() {
+        check(
+            || {
+                let selected = create_signal(vec!["b".to_string(), "c".to_string()]);
+                view! {
+                    select(multiple=true, bind:selectedValues=selected) {
+                        option(value="a") { "A" }
+                        option(value="b") { "B" }
+                        option { "c" }
+                    }
+                }
+            },
+            expect![[
+                r#"<select multiple data-hk="0.0"><option value="a" data-hk="0.1">A</option><option value="b" selected data-hk="0.2">B</option><option selected data-hk="0.3">c</option></select>"#
+            ]],
+        );
+    }
 }
@@ -1,4 +1,7 @@
-//! Definition for the [`NoSsr`] and [`NoHydrate`] components.
+//! Definition for utility components such as [`Show`], [`Switch`], [`Dynamic`], [`NoSsr`] and
+//! [`NoHydrate`].
+
+use std::cell::RefCell;
 
 use sycamore_macro::{component, view, Props};
 
@@ -7,23 +10,55 @@ use crate::*;
 /// Props for [`Show`].
 #[derive(Props)]
 pub struct ShowProps {
+    /// The condition for rendering the children.
     #[prop(setter(into))]
     pub when: MaybeDyn<bool>,
+    /// The view to render instead of the children while `when` is `false`. Defaults to nothing.
+    #[prop(default = Box::new(|| view! {}), setter(transform = |f: impl Fn() -> View + 'static| Box::new(f) as Box<dyn Fn() -> View>))]
+    pub fallback: Box<dyn Fn() -> View>,
     pub children: Children,
 }
 
 /// An utility component that only renders its children when a condition is satisfied.
+///
+/// Unlike `(if cond { view! { ... } } else { view! {} })`, the children are only created once.
+/// When `when` becomes `false`, the nodes are detached from the DOM but the children are kept
+/// alive, so their state (e.g. signals and the values of inputs) is preserved and the nodes are
+/// simply attached again when `when` becomes `true`. This makes `Show` well suited for sections
+/// that are toggled frequently.
+///
+/// The `fallback` is rendered while `when` is `false`. It is created again every time it is
+/// shown.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::Show;
+/// # fn App() -> View {
+/// let logged_in = create_signal(false);
+/// view! {
+///     Show(when=logged_in, fallback=|| view! { p { "Please log in." } }) {
+///         p { "Welcome back!" }
+///     }
+/// }
+/// # }
+/// ```
 #[component]
 pub fn Show(props: ShowProps) -> View {
-    let mut children = props.children.call();
-    let when = create_selector(move || props.when.get());
+    let ShowProps {
+        when,
+        fallback,
+        children,
+    } = props;
+    let mut children = children.call();
+    let when = create_selector(move || when.get());
 
     if is_ssr!() {
         View::from_dynamic(move || {
             if when.get() {
                 std::mem::take(&mut children)
             } else {
-                view! {}
+                untrack(&fallback)
             }
         })
     } else {
@@ -40,21 +75,196 @@ pub fn Show(props: ShowProps) -> View {
                 // Wrap children inside a document fragment so that it can still be dynamically
                 // updated even though it is not mounted.
                 children = utils::wrap_in_document_fragment(cloned);
-                view! {}
+                untrack(&fallback)
             }
         })
     }
 }
 
+/// Props for [`Switch`].
+#[derive(Props)]
+pub struct SwitchProps {
+    /// The view to render when no [`Match`] is selected. Defaults to nothing.
+    #[prop(default = Box::new(|| view! {}), setter(transform = |f: impl Fn() -> View + 'static| Box::new(f) as Box<dyn Fn() -> View>))]
+    pub fallback: Box<dyn Fn() -> View>,
+    /// The [`Match`] branches.
+    pub children: Children,
+}
+
+/// A branch of a [`Switch`].
+struct Branch {
+    when: MaybeDyn<bool>,
+    view: Box<dyn Fn() -> View>,
+}
+
+/// Context for registering the [`Match`] branches of a [`Switch`].
+#[derive(Clone)]
+struct SwitchBranches(Rc<RefCell<Vec<Branch>>>);
+
+/// Renders the first [`Match`] whose `when` condition is `true`, or the `fallback` if there is
+/// none.
+///
+/// This is a cleaner alternative to chains of `if`/`else if` in views, e.g. for rendering the
+/// current state of a state machine or the selected tab. The children of `Switch` must be `Match`
+/// components.
+///
+/// The view of a `Match` is created in a new reactive scope when the branch is selected and the
+/// scope is disposed when another branch is selected. The view is not re-created if the selected
+/// branch stays the same, even if the conditions change.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{Match, Switch};
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Status {
+///     Loading,
+///     Ready,
+///     Failed,
+/// }
+///
+/// # fn App() -> View {
+/// let status = create_signal(Status::Loading);
+/// view! {
+///     Switch(fallback=|| view! { "Loading..." }) {
+///         Match(when=move || status.get() == Status::Ready, view=|| view! { "Ready!" })
+///         Match(when=move || status.get() == Status::Failed, view=|| view! { "Failed." })
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Switch(props: SwitchProps) -> View {
+    let SwitchProps { fallback, children } = props;
+
+    let branches = Rc::new(RefCell::new(Vec::new()));
+    create_child_scope(|| {
+        provide_context(SwitchBranches(Rc::clone(&branches)));
+        // The `Match` components register themselves and do not render anything.
+        let _ = children.call();
+    });
+    let branches = Rc::new(branches.take());
+
+    let selected = create_selector({
+        let branches = Rc::clone(&branches);
+        move || branches.iter().position(|branch| branch.when.get())
+    });
+    View::from_dynamic(move || match selected.get() {
+        Some(index) => untrack(&branches[index].view),
+        None => untrack(&fallback),
+    })
+}
+
+/// Props for [`Match`].
+#[derive(Props)]
+pub struct MatchProps {
+    /// The condition for selecting this branch.
+    #[prop(setter(into))]
+    pub when: MaybeDyn<bool>,
+    /// The function that creates the view of this branch. This is called every time the branch
+    /// is selected.
+    #[prop(setter(transform = |f: impl Fn() -> View + 'static| Box::new(f) as Box<dyn Fn() -> View>))]
+    pub view: Box<dyn Fn() -> View>,
+}
+
+/// A branch of a [`Switch`]. See [`Switch`] for more details.
+///
+/// # Panics
+/// Panics if not used as a direct child of a [`Switch`].
+#[component]
+pub fn Match(props: MatchProps) -> View {
+    let MatchProps { when, view } = props;
+    let Some(SwitchBranches(branches)) = try_use_context::<SwitchBranches>() else {
+        panic!("`Match` must be used inside a `Switch`");
+    };
+    branches.borrow_mut().push(Branch { when, view });
+    View::new()
+}
+
+/// Props for [`Dynamic`].
+#[derive(Props)]
+pub struct DynamicProps {
+    /// The component to render.
+    pub component: ReadSignal<Rc<dyn Fn() -> View>>,
+}
+
+/// Renders a component that is chosen at runtime, e.g. from a plugin registry or from the block
+/// types of a CMS.
+///
+/// The component is re-rendered whenever `component` changes. The reactive scope of the previous
+/// component is disposed before the new component is created, so its cleanup callbacks run first.
+///
+/// # Example
+/// ```
+/// # use std::rc::Rc;
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::Dynamic;
+/// #[component]
+/// fn Paragraph() -> View {
+///     view! { p { "Paragraph" } }
+/// }
+///
+/// #[component]
+/// fn Quote() -> View {
+///     view! { blockquote { "Quote" } }
+/// }
+///
+/// # fn App() -> View {
+/// let block: Signal<Rc<dyn Fn() -> View>> = create_signal(Rc::new(Paragraph));
+/// view! {
+///     Dynamic(component=*block)
+///     button(on:click=move |_| block.set(Rc::new(Quote))) { "Use quote" }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Dynamic(props: DynamicProps) -> View {
+    let DynamicProps { component } = props;
+    View::from_dynamic(move || {
+        let component = component.get_clone();
+        untrack(|| component())
+    })
+}
+
 /// Component that is only renders its children on the client side.
 ///
 /// This is useful when wrapping parts of your app that are not intended to be server-side
 /// rendered, e.g. highly interactive components such as graphs, etc...
+///
+/// On the server, the `fallback` is rendered instead, e.g. a skeleton or a placeholder that
+/// reserves space for the content to avoid layout shift. On the client, the fallback is replaced
+/// with the children once the component is mounted, i.e. after hydration, so that the fallback
+/// does not cause a hydration mismatch. The fallback is never rendered on the client and is not
+/// hydrated, so it should not be interactive.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::NoSsr;
+/// # #[component] fn Map() -> View { view! {} }
+/// # fn App() -> View {
+/// view! {
+///     NoSsr(fallback=|| view! { div(style="height: 400px") { "Loading map..." } }) {
+///         Map {}
+///     }
+/// }
+/// # }
+/// ```
 #[component(inline_props)]
-pub fn NoSsr(children: Children) -> View {
+pub fn NoSsr(
+    /// The view to render on the server instead of the children.
+    #[prop(default = Box::new(|| view! {}), setter(transform = |f: impl Fn() -> View + 'static| Box::new(f) as Box<dyn Fn() -> View>))]
+    fallback: Box<dyn Fn() -> View>,
+    children: Children,
+) -> View {
     if is_ssr!() {
-        view! { no-ssr() }
+        // The fallback is not hydrated since it is removed on the client.
+        let is_hydrating = IS_HYDRATING.replace(false);
+        let fallback = fallback();
+        IS_HYDRATING.set(is_hydrating);
+        view! { no-ssr { (fallback) } }
     } else {
+        let _ = fallback;
         let marker = create_node_ref();
         let view = view! { no-ssr(r#ref=marker) };
         on_mount(move || {
@@ -65,6 +275,7 @@ pub fn NoSsr(children: Children) -> View {
             for node in children.as_web_sys() {
                 parent.insert_before(&node, Some(&marker)).unwrap();
             }
+            // This also removes the fallback that was rendered on the server.
             parent.remove_child(&marker).unwrap();
         });
         view
@@ -92,6 +303,13 @@ pub fn NoHydrate(children: Children) -> View {
     }
 }
 
+/// Without the `serde` feature, there is no state to render.
+#[cfg(not(feature = "serde"))]
+#[component]
+fn HydrationStateScript() -> View {
+    view! {}
+}
+
 /// Generate a script element for bootstrapping hydration.
 ///
 /// In general, prefer using [`HydrationScript`] instead.
@@ -106,7 +324,8 @@ pub fn generate_hydration_script(mode: SsrMode) -> &'static str {
 /// Component that creates a script element for bootstrapping hydration. Should be rendered into
 /// the `<head>` of the document.
 ///
-/// This component is required if using SSR in blocking or streaming mode.
+/// This component is required if using SSR in blocking or streaming mode. With the `serde`
+/// feature, it also renders the state that was serialized with `serialize_state`.
 ///
 /// TODO: use this component to also capture and replay events. This requires synthetic event
 /// delegation: <https://github.com/sycamore-rs/sycamore/issues/176>
@@ -118,6 +337,7 @@ pub fn HydrationScript() -> View {
         view! {
             NoHydrate {
                 script(dangerously_set_inner_html=script)
+                HydrationStateScript {}
             }
         }
     }
@@ -125,3 +345,58 @@ pub fn HydrationScript() -> View {
         view! {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn show_renders_fallback_when_false() {
+        let html = render_to_string(|| {
+            view! {
+                Show(when=false, fallback=|| view! { "fallback" }) { "content" }
+            }
+        });
+        assert_eq!(html, "<!--/-->fallback<!--/-->");
+        let html = render_to_string(|| {
+            view! {
+                Show(when=true, fallback=|| view! { "fallback" }) { "content" }
+            }
+        });
+        assert_eq!(html, "<!--/-->content<!--/-->");
+    }
+
+    #[test]
+    fn switch_renders_first_matching_branch() {
+        let render = |state: i32| {
+            render_to_string(move || {
+                view! {
+                    Switch(fallback=|| view! { "fallback" }) {
+                        Match(when=state >= 1, view=|| view! { "one" })
+                        Match(when=state >= 2, view=|| view! { "two" })
+                    }
+                }
+            })
+        };
+        assert_eq!(render(0), "<!--/-->fallback<!--/-->");
+        assert_eq!(render(1), "<!--/-->one<!--/-->");
+        assert_eq!(render(2), "<!--/-->one<!--/-->");
+    }
+
+    #[test]
+    fn dynamic_renders_component() {
+        let html = render_to_string(|| {
+            let component = create_signal(Rc::new(|| view! { "dynamic" }) as Rc<dyn Fn() -> View>);
+            view! { Dynamic(component=*component) }
+        });
+        assert_eq!(html, "<!--/-->dynamic<!--/-->");
+    }
+
+    #[test]
+    #[should_panic = "`Match` must be used inside a `Switch`"]
+    fn match_outside_of_switch_panics() {
+        let _ = create_root(|| {
+            let _: View = view! { Match(when=true, view=|| view! {}) };
+        });
+    }
+}
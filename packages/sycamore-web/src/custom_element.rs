@@ -0,0 +1,330 @@
+//! Exposing Sycamore components as native custom elements (web components).
+
+use crate::*;
+
+/// A trait for props that can be created from the attributes of a custom element.
+///
+/// This should not be implemented manually. Instead, add `#[prop(custom_element)]` to a struct
+/// that derives `Props`. Every field is then read from the attribute with the same name as the
+/// field, in kebab-case (e.g. `max_value` is read from `max-value`). If an attribute is missing
+/// or cannot be parsed, the default value of the field is used instead. The `children` field and
+/// fields with `#[prop(setter(skip))]` are never read from attributes.
+///
+/// Every field type must implement [`FromAttribute`]. Fields without a default value must
+/// additionally implement [`Default`].
+///
+/// Fields are only read once, when the element is connected. To react to later attribute
+/// changes, use a [`ReadSignal`] field instead, which is updated whenever the attribute changes.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// #[derive(Props)]
+/// #[prop(custom_element)]
+/// struct CounterProps {
+///     label: ReadSignal<String>,
+///     #[prop(default = 1)]
+///     step: i32,
+/// }
+/// ```
+pub trait CustomElementProps: Sized {
+    /// The names of the attributes that are read when creating the props.
+    const OBSERVED_ATTRIBUTES: &'static [&'static str];
+
+    /// Create the props from the attributes. `attribute` returns a signal holding the current
+    /// value of the attribute with the given name, or `None` if the attribute is not set.
+    fn from_attributes(attribute: &dyn Fn(&str) -> ReadSignal<Option<String>>) -> Self;
+}
+
+/// A trait for types that can be parsed from the value of an HTML attribute.
+pub trait FromAttribute: Sized {
+    /// Parses the value of an attribute. Returns `None` if the value is invalid.
+    fn from_attribute(value: &str) -> Option<Self>;
+
+    /// Creates the value from a signal holding the value of an attribute, or `None` if the
+    /// attribute is not set. Returns `None` to use the default value of the field instead.
+    ///
+    /// By default, this parses the current value of the attribute without tracking it.
+    fn from_attribute_signal(attribute: ReadSignal<Option<String>>) -> Option<Self> {
+        attribute.with_untracked(|value| value.as_deref().and_then(Self::from_attribute))
+    }
+}
+
+impl FromAttribute for String {
+    fn from_attribute(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+/// Follows the semantics of boolean HTML attributes: the value is `true` if the attribute is
+/// present, regardless of its value.
+impl FromAttribute for bool {
+    fn from_attribute(_value: &str) -> Option<Self> {
+        Some(true)
+    }
+}
+
+impl<T: FromAttribute> FromAttribute for Option<T> {
+    fn from_attribute(value: &str) -> Option<Self> {
+        T::from_attribute(value).map(Some)
+    }
+}
+
+macro_rules! impl_from_attribute_for_from_str {
+    ($($ty:ty),*) => {
+        $(
+            impl FromAttribute for $ty {
+                fn from_attribute(value: &str) -> Option<Self> {
+                    value.trim().parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_attribute_for_from_str!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char
+);
+
+/// Tracks the attribute. Whenever the attribute changes, the signal is updated with the new
+/// value. If the attribute is missing or cannot be parsed, the signal holds `T::default()`.
+impl<T: FromAttribute + Default + 'static> FromAttribute for ReadSignal<T> {
+    fn from_attribute(value: &str) -> Option<Self> {
+        T::from_attribute(value).map(|value| *create_signal(value))
+    }
+
+    fn from_attribute_signal(attribute: ReadSignal<Option<String>>) -> Option<Self> {
+        Some(create_memo(move || {
+            attribute.with(|value| {
+                value
+                    .as_deref()
+                    .and_then(T::from_attribute)
+                    .unwrap_or_default()
+            })
+        }))
+    }
+}
+
+/// Registers a native custom element that renders `component`.
+///
+/// This makes it possible to use a Sycamore component from pages that are not written with
+/// Sycamore, e.g. to embed an interactive island into an existing site. Once registered, every
+/// `<name>` element on the page renders the component into its shadow root:
+///
+/// * When the element is connected to the document, the props are created from the element's
+///   attributes (see [`CustomElementProps`]) and the component is mounted in a new reactive root.
+/// * When one of the observed attributes changes, the signal holding its value is updated. The
+///   component is not re-rendered, so only [`ReadSignal`] fields see the new value.
+/// * When the element is disconnected from the document, the reactive root is disposed and the
+///   rendered nodes are removed.
+///
+/// In SSR mode, this does nothing.
+///
+/// # Panics
+/// Panics if `name` is not a valid custom element name (it must contain a hyphen) or if an
+/// element with the same name has already been defined.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::define_custom_element;
+/// #[derive(Props)]
+/// #[prop(custom_element)]
+/// struct GreetingProps {
+///     name: ReadSignal<String>,
+/// }
+///
+/// #[component]
+/// fn Greeting(props: GreetingProps) -> View {
+///     view! { p { "Hello, " (props.name) "!" } }
+/// }
+///
+/// // Can now be used as `<my-greeting name="World"></my-greeting>`.
+/// define_custom_element("my-greeting", Greeting);
+/// ```
+pub fn define_custom_element<P, F>(name: &str, component: F)
+where
+    P: CustomElementProps + 'static,
+    F: Fn(P) -> View + 'static,
+{
+    if is_ssr!() {
+        let _ = (name, component);
+        return;
+    }
+
+    #[wasm_bindgen(inline_js = r#"
+export function define_custom_element(name, observedAttributes, connect) {
+    customElements.define(name, class extends HTMLElement {
+        static get observedAttributes() {
+            return observedAttributes;
+        }
+        connectedCallback() {
+            if (!this.__sycamore) this.__sycamore = connect(this);
+        }
+        disconnectedCallback() {
+            const instance = this.__sycamore;
+            this.__sycamore = undefined;
+            if (instance) instance.dispose();
+        }
+        attributeChangedCallback(name, oldValue, newValue) {
+            if (this.__sycamore) this.__sycamore.update(name, newValue);
+        }
+    });
+}
+"#)]
+    extern "C" {
+        #[wasm_bindgen(js_name = "define_custom_element")]
+        fn define_custom_element_js(
+            name: &str,
+            observed_attributes: js_sys::Array,
+            connect: &JsValue,
+        );
+    }
+
+    let component = Rc::new(component);
+    let connect = move |element: web_sys::Element| -> JsValue {
+        let shadow_root = element.shadow_root().unwrap_or_else(|| {
+            let init = web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open);
+            element.attach_shadow(&init).unwrap()
+        });
+        let component = Rc::clone(&component);
+        let mut attributes = Vec::new();
+        let root = create_root(|| {
+            attributes = P::OBSERVED_ATTRIBUTES
+                .iter()
+                .map(|&name| (name, create_signal(element.get_attribute(name))))
+                .collect::<Vec<_>>();
+            let attributes = attributes.clone();
+            let props = P::from_attributes(&|name| {
+                let (_, signal) = attributes
+                    .iter()
+                    .find(|(attribute, _)| *attribute == name)
+                    .expect("attribute should be observed");
+                **signal
+            });
+            render_in_scope(move || component(props), &shadow_root);
+        });
+
+        let update = Closure::<dyn Fn(String, Option<String>)>::new(move |name, value| {
+            if let Some((_, signal)) = attributes.iter().find(|(attribute, _)| *attribute == name) {
+                if signal.with_untracked(|current| *current != value) {
+                    signal.set(value);
+                }
+            }
+        });
+        let dispose = Closure::once(move || {
+            let shadow_root = element.shadow_root().unwrap();
+            root.dispose();
+            shadow_root.set_inner_html("");
+        });
+        let instance = js_sys::Object::new();
+        js_sys::Reflect::set(&instance, &"update".into(), &update.into_js_value()).unwrap();
+        js_sys::Reflect::set(&instance, &"dispose".into(), &dispose.into_js_value()).unwrap();
+        instance.into()
+    };
+    let connect = Closure::<dyn Fn(web_sys::Element) -> JsValue>::new(connect);
+
+    let observed_attributes = P::OBSERVED_ATTRIBUTES
+        .iter()
+        .map(|attribute| JsValue::from_str(attribute))
+        .collect();
+    define_custom_element_js(name, observed_attributes, &connect.into_js_value());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Props)]
+    #[prop(custom_element)]
+    #[allow(dead_code)]
+    struct CounterProps {
+        label: String,
+        #[prop(default = 1)]
+        step: i32,
+        max_value: Option<u32>,
+        disabled: bool,
+        #[prop(default = label.len())]
+        label_len: usize,
+        count: ReadSignal<u32>,
+        children: Children,
+    }
+
+    fn from_attributes(attributes: &[(&str, &str)]) -> CounterProps {
+        let attributes: HashMap<_, _> = attributes.iter().copied().collect();
+        CounterProps::from_attributes(&|name| {
+            *create_signal(attributes.get(name).map(|value| value.to_string()))
+        })
+    }
+
+    #[test]
+    fn observed_attributes_are_kebab_case() {
+        assert_eq!(
+            CounterProps::OBSERVED_ATTRIBUTES,
+            [
+                "label",
+                "step",
+                "max-value",
+                "disabled",
+                "label-len",
+                "count"
+            ]
+        );
+    }
+
+    #[test]
+    fn props_from_attributes() {
+        let _ = create_root(|| {
+            let props = from_attributes(&[
+                ("label", "Count"),
+                ("step", " 5 "),
+                ("max-value", "10"),
+                ("disabled", ""),
+                ("count", "3"),
+            ]);
+            assert_eq!(props.label, "Count");
+            assert_eq!(props.step, 5);
+            assert_eq!(props.max_value, Some(10));
+            assert!(props.disabled);
+            assert_eq!(props.label_len, 5);
+            assert_eq!(props.count.get(), 3);
+        });
+    }
+
+    #[test]
+    fn missing_or_invalid_attributes_use_default() {
+        let _ = create_root(|| {
+            let props = from_attributes(&[("step", "abc"), ("max-value", "-1")]);
+            assert_eq!(props.label, "");
+            assert_eq!(props.step, 1);
+            assert_eq!(props.max_value, None);
+            assert!(!props.disabled);
+            assert_eq!(props.label_len, 0);
+            assert_eq!(props.count.get(), 0);
+        });
+    }
+
+    #[test]
+    fn signal_fields_track_attribute() {
+        let _ = create_root(|| {
+            let attribute = create_signal(Some("1".to_string()));
+            let props = CounterProps::from_attributes(&|name| {
+                if name == "count" {
+                    *attribute
+                } else {
+                    *create_signal(None)
+                }
+            });
+            assert_eq!(props.count.get(), 1);
+
+            attribute.set(Some("2".to_string()));
+            assert_eq!(props.count.get(), 2);
+            attribute.set(Some("abc".to_string()));
+            assert_eq!(props.count.get(), 0);
+            attribute.set(None);
+            assert_eq!(props.count.get(), 0);
+        });
+    }
+}
@@ -0,0 +1,113 @@
+//! Reading `data-*` attributes of elements.
+
+use web_sys::{MutationObserver, MutationObserverInit, MutationRecord};
+
+use crate::*;
+
+/// A reader for the `data-*` attributes of an element. See [`use_dataset`].
+#[derive(Clone, Copy)]
+pub struct Dataset {
+    node: NodeRef,
+    changed: Signal<()>,
+}
+
+impl Dataset {
+    /// Returns the value of the `data-*` attribute with the given key, as in `element.dataset`.
+    ///
+    /// The key is in camel case, i.e. `"userId"` reads the `data-user-id` attribute. Returns
+    /// `None` if the attribute is not set, if `node` is not set, or in SSR mode.
+    ///
+    /// This is reactive: the value is tracked when it is read inside an effect or a memo.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.changed.track();
+        let node = self.node.try_get()?;
+        let dataset = js_sys::Reflect::get(&node, &"dataset".into()).ok()?;
+        if dataset.is_undefined() {
+            return None;
+        }
+        js_sys::Reflect::get(&dataset, &key.into())
+            .ok()?
+            .as_string()
+    }
+}
+
+/// Returns a [`Dataset`] for reading the `data-*` attributes of the element referenced by `node`.
+///
+/// The values are updated whenever a `data-*` attribute of the element is changed, e.g. by
+/// [`data_attr`](GlobalAttributes::data_attr) or by a third-party script. The element is observed
+/// once the component is mounted and whenever `node` changes. The observer is disconnected when the
+/// current reactive scope is disposed.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_dataset;
+/// #[component]
+/// fn Widget() -> View {
+///     let node = create_node_ref();
+///     let dataset = use_dataset(node);
+///     let theme = move || dataset.get("theme").unwrap_or_else(|| "light".to_string());
+///     view! {
+///         div(r#ref=node, data-theme="dark") {
+///             "Theme: " (theme)
+///         }
+///     }
+/// }
+/// ```
+pub fn use_dataset(node: NodeRef) -> Dataset {
+    let changed = create_signal(());
+    if is_ssr!() {
+        return Dataset { node, changed };
+    }
+
+    on_mount(move || {
+        // Re-observe whenever the node ref changes.
+        create_effect(move || {
+            let Some(element) = node.try_get() else {
+                return;
+            };
+
+            let on_mutation: Closure<dyn Fn(js_sys::Array)> =
+                Closure::new(move |records: js_sys::Array| {
+                    let is_data_attribute = records.iter().any(|record| {
+                        record
+                            .unchecked_into::<MutationRecord>()
+                            .attribute_name()
+                            .is_some_and(|name| name.starts_with("data-"))
+                    });
+                    if is_data_attribute {
+                        changed.set(());
+                    }
+                });
+            let observer =
+                MutationObserver::new(on_mutation.as_ref().unchecked_ref()).unwrap_throw();
+            let init = MutationObserverInit::new();
+            init.set_attributes(true);
+            observer
+                .observe_with_options(&element, &init)
+                .unwrap_throw();
+
+            on_cleanup(move || {
+                observer.disconnect();
+                drop(on_mutation);
+            });
+        });
+    });
+
+    Dataset { node, changed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dataset_is_empty_in_ssr() {
+        let _ = create_root(|| {
+            let node = create_node_ref();
+            let dataset = use_dataset(node);
+            let _: View = view! { div(r#ref=node, data-theme="dark") };
+            assert_eq!(dataset.get("theme"), None);
+        });
+    }
+}
@@ -15,6 +15,11 @@ pub(crate) fn svg_element(tag: &'static str) -> HtmlNode {
     HtmlNode::create_element_ns("http://www.w3.org/2000/svg", tag.into())
 }
 
+/// Create a MathML element with `tag`.
+pub(crate) fn mathml_element(tag: &'static str) -> HtmlNode {
+    HtmlNode::create_element_ns("http://www.w3.org/1998/Math/MathML", tag.into())
+}
+
 /// A struct representing a custom element. This can be created by calling [`custom_element`].
 pub struct CustomElement(HtmlNode);
 
@@ -23,6 +28,31 @@ pub fn custom_element(tag: &'static str) -> CustomElement {
     CustomElement(element(tag))
 }
 
+/// Create a new HTML element whose tag name is only known at runtime.
+///
+/// This returns the same builder as [`custom_element`], so attributes, event handlers and
+/// children are attached just like for any other element. In the `view!` macro, this can be
+/// written as `(dyn_tag(name)) { ... }`.
+///
+/// The element is always created in the HTML namespace, so this cannot be used to create SVG or
+/// MathML elements. The tag name is not validated. In SSR mode, it is emitted verbatim.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::tag;
+/// #[component(inline_props)]
+/// fn Heading(level: u8, children: Children) -> View {
+///     tag(format!("h{}", level.clamp(1, 6)))
+///         .class("heading")
+///         .children(children)
+///         .into()
+/// }
+/// ```
+pub fn tag(name: impl Into<Cow<'static, str>>) -> CustomElement {
+    CustomElement(HtmlNode::create_element(name.into()))
+}
+
 impl From<CustomElement> for View {
     fn from(el: CustomElement) -> Self {
         View::from_node(el.0)
@@ -62,6 +92,29 @@ macro_rules! impl_attributes {
     };
 }
 
+/// Like [`impl_attributes!`], but for attributes that take `"true"` or `"false"` instead of being
+/// present or absent, such as `aria-expanded`.
+macro_rules! impl_bool_string_attributes {
+    ($(
+        $(#[$attr:meta])*
+        $ident:ident ($name:literal),
+    )*) => {
+        $(
+            $(#[$attr])*
+            fn $ident(mut self, value: impl Into<MaybeDyn<bool>>) -> Self {
+                let value = value.into();
+                let to_str = |value: bool| if value { "true" } else { "false" };
+                let value = match value {
+                    MaybeDyn::Static(value) => StringAttribute::from(to_str(value)),
+                    value => StringAttribute::from(move || to_str(value.get())),
+                };
+                self.set_attribute($name, value);
+                self
+            }
+        )*
+    };
+}
+
 macro_rules! impl_element {
     (
         $(#[$attr:meta])*
@@ -250,7 +303,102 @@ macro_rules! impl_svg_elements {
     };
 }
 
-/// Definition of all the HTML and SVG elements.
+macro_rules! impl_mathml_element {
+    (
+        $(#[$attr:meta])*
+        $name:ident {
+            $(
+                $(#[$prop_attr:meta])*
+                $prop:ident $(($prop_name:literal))?: $ty:ty,
+            )*
+        }
+    ) => {
+        impl_mathml_element!($(#[$attr])* $name (stringify!($name)) {
+            $(
+                $(#[$prop_attr])*
+                $prop $(($prop_name))*: $ty,
+            )*
+        });
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident ($tag:expr) {
+            $(
+                $(#[$prop_attr:meta])*
+                $prop:ident $(($prop_name:literal))?: $ty:ty,
+            )*
+        }
+    ) => {
+        paste::paste! {
+            #[doc = "The `<" $name ">` MathML element. This can be created by calling [`" $name "()`]."]
+            pub struct [<MathMl $name:camel>] (HtmlNode);
+
+            #[doc = "Create a `<" $name ">` element."]
+            #[doc = ""]
+            $(#[$attr])*
+            pub fn $name() -> [<MathMl $name:camel>] {
+                [<MathMl $name:camel>](mathml_element($tag))
+            }
+
+            impl From<[<MathMl $name:camel>]> for View {
+                fn from(el: [<MathMl $name:camel>]) -> Self {
+                    View::from_node(el.0)
+                }
+            }
+
+            impl AsHtmlNode for [<MathMl $name:camel>] {
+                fn as_html_node(&mut self) -> &mut HtmlNode {
+                    &mut self.0
+                }
+            }
+
+            impl GlobalProps for [<MathMl $name:camel>] {}
+            impl MathMlGlobalAttributes for [<MathMl $name:camel>] {}
+
+            #[doc = "Trait that provides attributes for the `<" $name ">` MathML element."]
+            pub trait [<MathMl $name:camel Attributes>]: SetAttribute + Sized {
+                impl_attributes! {
+                    $(
+                        $(#[$prop_attr])*
+                        $prop $(($prop_name))*: $ty,
+                    )*
+                }
+            }
+
+            impl [<MathMl $name:camel Attributes>] for [<MathMl $name:camel>] {}
+        }
+    };
+}
+
+macro_rules! impl_mathml_elements {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident $(($tag:expr))? {
+            $(
+                $(#[$prop_attr:meta])*
+                $prop:ident $(($prop_name:literal))?: $ty:ty,
+            )*
+        },
+    )*) => {
+        $(
+            impl_mathml_element!($(#[$attr])* $name $(($tag))* {
+                $(
+                    $(#[$prop_attr])*
+                    $prop $(($prop_name))*: $ty,
+                )*
+            });
+        )*
+        /// Module that includes all the MathML attribute traits and is intended to be glob
+        /// exported.
+        pub mod mathml_attributes {
+            paste::paste! {
+                pub use super::{$([<MathMl $name:camel Attributes>]),*};
+            }
+        }
+    };
+}
+
+/// Definition of all the HTML, SVG and MathML elements.
 pub mod tags {
     use super::*;
 
@@ -401,7 +549,7 @@ pub mod tags {
             loading: impl Into<StringAttribute>,
             name: impl Into<StringAttribute>,
             referrerpolicy: impl Into<StringAttribute>,
-            sandbox: impl Into<MaybeDyn<bool>>,
+            sandbox: impl Into<StringAttribute>,
             src: impl Into<StringAttribute>,
             srcdoc: impl Into<StringAttribute>,
             width: impl Into<StringAttribute>,
@@ -451,7 +599,7 @@ pub mod tags {
             readonly: impl Into<MaybeDyn<bool>>,
             required: impl Into<MaybeDyn<bool>>,
             size: impl Into<StringAttribute>, // TODO: int value
-            spellcheck: impl Into<MaybeDyn<bool>>,
+            spellcheck: impl Into<StringAttribute>,
             src: impl Into<StringAttribute>,
             step: impl Into<StringAttribute>,
             tabindex: impl Into<StringAttribute>, // TODO: int value
@@ -650,7 +798,7 @@ pub mod tags {
             readonly: impl Into<MaybeDyn<bool>>,
             required: impl Into<MaybeDyn<bool>>,
             rows: impl Into<StringAttribute>, // TODO: int value
-            spellcheck: impl Into<MaybeDyn<bool>>,
+            spellcheck: impl Into<StringAttribute>,
             r#type("type"): impl Into<StringAttribute>,
             value: impl Into<StringAttribute>,
             wrap: impl Into<StringAttribute>,
@@ -766,6 +914,85 @@ pub mod tags {
         r#use("use") {},
         view {},
     }
+
+    impl_mathml_elements! {
+        math {
+            display: impl Into<StringAttribute>,
+        },
+        annotation {
+            encoding: impl Into<StringAttribute>,
+        },
+        annotation_xml("annotation-xml") {
+            encoding: impl Into<StringAttribute>,
+        },
+        maction {
+            actiontype: impl Into<StringAttribute>,
+            selection: impl Into<StringAttribute>,
+        },
+        merror {},
+        mfrac {
+            linethickness: impl Into<StringAttribute>,
+        },
+        mi {
+            mathvariant: impl Into<StringAttribute>,
+        },
+        mmultiscripts {},
+        mn {},
+        mo {
+            accent: impl Into<StringAttribute>,
+            fence: impl Into<StringAttribute>,
+            form: impl Into<StringAttribute>,
+            largeop: impl Into<StringAttribute>,
+            lspace: impl Into<StringAttribute>,
+            maxsize: impl Into<StringAttribute>,
+            minsize: impl Into<StringAttribute>,
+            movablelimits: impl Into<StringAttribute>,
+            rspace: impl Into<StringAttribute>,
+            separator: impl Into<StringAttribute>,
+            stretchy: impl Into<StringAttribute>,
+            symmetric: impl Into<StringAttribute>,
+        },
+        mover {
+            accent: impl Into<StringAttribute>,
+        },
+        mpadded {
+            depth: impl Into<StringAttribute>,
+            height: impl Into<StringAttribute>,
+            lspace: impl Into<StringAttribute>,
+            voffset: impl Into<StringAttribute>,
+            width: impl Into<StringAttribute>,
+        },
+        mphantom {},
+        mprescripts {},
+        mroot {},
+        mrow {},
+        ms {},
+        mspace {
+            depth: impl Into<StringAttribute>,
+            height: impl Into<StringAttribute>,
+            width: impl Into<StringAttribute>,
+        },
+        msqrt {},
+        mstyle {},
+        msub {},
+        msubsup {},
+        msup {},
+        mtable {},
+        mtd {
+            columnspan: impl Into<StringAttribute>,
+            rowspan: impl Into<StringAttribute>,
+        },
+        mtext {},
+        mtr {},
+        munder {
+            accentunder: impl Into<StringAttribute>,
+        },
+        munderover {
+            accent: impl Into<StringAttribute>,
+            accentunder: impl Into<StringAttribute>,
+        },
+        semantics {},
+    }
 }
 
 /// A trait that is implemented for all elements and which provides all the global HTML attributes.
@@ -1110,6 +1337,27 @@ pub trait SvgGlobalAttributes: SetAttribute + Sized {
     }
 }
 
+/// A trait that is implemented for all MathML elements and which provides all the global MathML
+/// attributes.
+///
+/// Reference: <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes>
+pub trait MathMlGlobalAttributes: SetAttribute + Sized {
+    impl_attributes! {
+        autofocus: impl Into<MaybeDyn<bool>>,
+        class: impl Into<StringAttribute>,
+        dir: impl Into<StringAttribute>,
+        displaystyle: impl Into<StringAttribute>,
+        id: impl Into<StringAttribute>,
+        mathbackground: impl Into<StringAttribute>,
+        mathcolor: impl Into<StringAttribute>,
+        mathsize: impl Into<StringAttribute>,
+        nonce: impl Into<StringAttribute>,
+        scriptlevel: impl Into<StringAttribute>,
+        style: impl Into<StringAttribute>,
+        tabindex: impl Into<StringAttribute>,
+    }
+}
+
 /// Attributes that are available on all elements.
 pub trait GlobalAttributes: SetAttribute + Sized {
     /// Set attribute `name` with `value`.
@@ -1118,6 +1366,120 @@ pub trait GlobalAttributes: SetAttribute + Sized {
         self
     }
 
+    /// Set multiple attributes from `(name, value)` pairs, e.g. from a `HashMap` that is only
+    /// known at runtime.
+    ///
+    /// Like with [`attr`](Self::attr), a value can be a closure to update the attribute
+    /// reactively. The attributes are set in the order of their names, so that the rendered HTML
+    /// is the same regardless of the iteration order of `attrs`.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use sycamore::prelude::*;
+    /// # fn App() -> View {
+    /// let attrs = HashMap::from([
+    ///     ("data-theme".to_string(), "dark".to_string()),
+    ///     ("aria-label".to_string(), "Settings".to_string()),
+    /// ]);
+    /// view! {
+    ///     div(attrs=attrs)
+    /// }
+    /// # }
+    /// ```
+    fn attrs<K, V>(mut self, attrs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        V: Into<StringAttribute>,
+    {
+        let mut attrs = attrs
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect::<Vec<(Cow<'static, str>, StringAttribute)>>();
+        attrs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (name, value) in attrs {
+            self.set_attribute_owned(name, value);
+        }
+        self
+    }
+
+    /// Set the `data-{key}` attribute with `value`.
+    ///
+    /// Like with [`attr`](Self::attr), the value can be a closure to update the attribute
+    /// reactively. The attributes can be read back with [`use_dataset`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # use sycamore::web::tags::div;
+    /// # fn App() -> View {
+    /// let is_open = create_signal(false);
+    /// div()
+    ///     .data_attr("state", move || if is_open.get() { "open" } else { "closed" })
+    ///     .into()
+    /// # }
+    /// ```
+    fn data_attr(mut self, key: &str, value: impl Into<StringAttribute>) -> Self {
+        self.set_attribute_owned(format!("data-{key}").into(), value.into());
+        self
+    }
+
+    impl_attributes! {
+        /// Indicates the checked state of a checkbox, radio or similar widget. Can be `true`,
+        /// `false` or `mixed`.
+        aria_checked("aria-checked"): impl Into<StringAttribute>,
+        /// Identifies the element whose contents or presence are controlled by this element.
+        aria_controls("aria-controls"): impl Into<StringAttribute>,
+        /// Indicates the current item within a set of related elements, e.g. `page` for the link
+        /// to the current page in a navigation.
+        aria_current("aria-current"): impl Into<StringAttribute>,
+        /// Identifies the elements that describe this element.
+        aria_describedby("aria-describedby"): impl Into<StringAttribute>,
+        /// Indicates the availability and type of popup that can be triggered by this element,
+        /// e.g. `menu` or `dialog`.
+        aria_haspopup("aria-haspopup"): impl Into<StringAttribute>,
+        /// Indicates whether the entered value does not conform to the expected format. Can be
+        /// `true`, `false`, `grammar` or `spelling`.
+        aria_invalid("aria-invalid"): impl Into<StringAttribute>,
+        /// Defines a string value that labels this element.
+        aria_label("aria-label"): impl Into<StringAttribute>,
+        /// Identifies the elements that label this element.
+        aria_labelledby("aria-labelledby"): impl Into<StringAttribute>,
+        /// Indicates how updates to the element are announced by screen readers. Can be `off`,
+        /// `polite` or `assertive`.
+        aria_live("aria-live"): impl Into<StringAttribute>,
+        /// Indicates the pressed state of a toggle button. Can be `true`, `false` or `mixed`.
+        aria_pressed("aria-pressed"): impl Into<StringAttribute>,
+        /// Defines the maximum value of a range widget.
+        aria_valuemax("aria-valuemax"): impl Into<StringAttribute>,
+        /// Defines the minimum value of a range widget.
+        aria_valuemin("aria-valuemin"): impl Into<StringAttribute>,
+        /// Defines the current value of a range widget.
+        aria_valuenow("aria-valuenow"): impl Into<StringAttribute>,
+        /// Defines a human readable text alternative of `aria-valuenow`.
+        aria_valuetext("aria-valuetext"): impl Into<StringAttribute>,
+    }
+
+    impl_bool_string_attributes! {
+        /// Indicates whether the element is being modified and assistive technologies should wait
+        /// until the modifications are complete.
+        aria_busy("aria-busy"),
+        /// Indicates that the element is perceivable but disabled.
+        aria_disabled("aria-disabled"),
+        /// Indicates whether the element, or the element it controls, is expanded.
+        aria_expanded("aria-expanded"),
+        /// Indicates whether the element is hidden from assistive technologies.
+        aria_hidden("aria-hidden"),
+        /// Indicates whether the element is modal when displayed.
+        aria_modal("aria-modal"),
+        /// Indicates that the element is not editable, but is otherwise operable.
+        aria_readonly("aria-readonly"),
+        /// Indicates that user input is required on the element before a form may be submitted.
+        aria_required("aria-required"),
+        /// Indicates whether the element is selected.
+        aria_selected("aria-selected"),
+    }
+
     /// Set attribute `name` with `value`.
     fn bool_attr(mut self, name: &'static str, value: impl Into<MaybeDyn<bool>>) -> Self {
         self.set_attribute(name, value.into());
@@ -1130,33 +1492,168 @@ pub trait GlobalAttributes: SetAttribute + Sized {
         self
     }
 
+    /// Reactively toggle individual classes.
+    ///
+    /// `classes` returns a list of class names, each with whether the class should be enabled.
+    /// It is re-run whenever a signal that is accessed inside changes. In the browser, classes
+    /// are added and removed one by one with the `classList` API, so classes that were added in
+    /// another way are kept as is. Classes that are no longer returned by `classes` are removed.
+    /// When rendering on the server, the enabled classes are added to the `class` attribute.
+    ///
+    /// The `class` attribute replaces all the classes of the element when it is set. Therefore,
+    /// `class` should be static and come before `class_list`.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # fn Comp() -> View {
+    /// let is_active = create_signal(true);
+    /// let is_disabled = create_signal(false);
+    /// view! {
+    ///     button(class="btn", class_list=move || vec![
+    ///         ("active", is_active.get()),
+    ///         ("disabled", is_disabled.get()),
+    ///     ])
+    /// }
+    /// # }
+    /// ```
+    fn class_list<C: Into<Cow<'static, str>>>(
+        mut self,
+        classes: impl Fn() -> Vec<(C, bool)> + 'static,
+    ) -> Self {
+        self.set_attribute(
+            "class",
+            ClassList::new(move || {
+                classes()
+                    .into_iter()
+                    .map(|(class, enabled)| (class.into(), enabled))
+                    .collect()
+            }),
+        );
+        self
+    }
+
+    /// Reactively set individual CSS properties.
+    ///
+    /// `styles` is either a [`Style`] or a closure that returns a list of CSS property names, each
+    /// with its value. The closure is re-run whenever a signal that is accessed inside changes. In
+    /// the browser, each property is set with `style.setProperty`, so properties that were set in
+    /// another way are kept as is. Properties that are no longer returned by the closure are
+    /// removed with `style.removeProperty`. When rendering on the server, the properties are added
+    /// to the `style` attribute.
+    ///
+    /// The `style` attribute replaces all the properties of the element when it is set.
+    /// Therefore, `style` should be static and come before `styles`.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # fn Comp() -> View {
+    /// let progress = create_signal(0.5);
+    /// view! {
+    ///     div(class="bar", styles=move || vec![
+    ///         ("width", format!("{}%", progress.get() * 100.0)),
+    ///         ("--progress", progress.get().to_string()),
+    ///     ])
+    /// }
+    /// # }
+    /// ```
+    fn styles(mut self, styles: impl Into<Style>) -> Self {
+        self.set_attribute("style", styles.into());
+        self
+    }
+
     /// Set an event handler with `name`.
-    fn on<E: events::EventDescriptor, R>(
+    fn on<E: events::DynEventDescriptor, R>(
         mut self,
-        _: E,
+        event: E,
         mut handler: impl EventHandler<E, R>,
     ) -> Self {
+        let once = handler.once();
         let scope = use_current_scope(); // Run handler inside the current scope.
         let handler = move |ev: web_sys::Event| scope.run_in(|| handler.call(ev.unchecked_into()));
-        self.set_event_handler(E::NAME, handler);
+        if once {
+            self.set_event_handler_once(event.into_name(), handler);
+        } else {
+            self.set_event_handler_owned(event.into_name(), handler);
+        }
         self
     }
 
     /// Set a two way binding with `name`.
-    fn bind<E: bind::BindDescriptor>(mut self, _: E, signal: Signal<E::ValueTy>) -> Self {
-        let scope = use_current_scope(); // Run handler inside the current scope.
-        let handler = move |ev: web_sys::Event| {
-            scope.run_in(|| {
-                let value =
-                    js_sys::Reflect::get(&ev.current_target().unwrap(), &E::TARGET_PROPERTY.into())
-                        .unwrap();
-                signal.set(E::CONVERT_FROM_JS(&value).expect("failed to convert value from js"));
-            })
-        };
-        self.set_event_handler(<E::Event as events::EventDescriptor>::NAME, handler);
+    fn bind<E: bind::Binding<S>, S>(self, _: E, signal: S) -> Self {
+        E::bind(self, signal)
+    }
+}
 
-        self.prop(E::TARGET_PROPERTY, move || signal.get_clone().into())
+/// Implementation of [`GlobalAttributes::bind`] for a [`bind::BindDescriptor`].
+pub(crate) fn bind_property<E: bind::BindDescriptor, T: GlobalAttributes>(
+    mut this: T,
+    signal: Signal<E::ValueTy>,
+) -> T {
+    if E::PROGRAMMATIC_EVENTS {
+        return bind_programmatic::<E, T>(this, signal);
     }
+
+    let scope = use_current_scope(); // Run handler inside the current scope.
+    let handler = move |ev: web_sys::Event| {
+        scope.run_in(|| {
+            let value =
+                js_sys::Reflect::get(&ev.current_target().unwrap(), &E::TARGET_PROPERTY.into())
+                    .unwrap();
+            signal.set(E::CONVERT_FROM_JS(&value).expect("failed to convert value from js"));
+        })
+    };
+    this.set_event_handler(<E::Event as events::EventDescriptor>::NAME, handler);
+
+    this.prop(E::TARGET_PROPERTY, move || signal.get_clone().into())
+}
+
+/// Implementation of [`GlobalAttributes::bind`] for properties that are also updated when they are
+/// set programmatically. See [`bind::BindDescriptor::PROGRAMMATIC_EVENTS`].
+fn bind_programmatic<E: bind::BindDescriptor, T: GlobalAttributes>(
+    mut this: T,
+    signal: Signal<E::ValueTy>,
+) -> T {
+    // The last value that was written to the property from the signal. The next event that reads
+    // back this value is caused by our own write and is ignored.
+    let last_written = Rc::new(std::cell::RefCell::new(None::<JsValue>));
+    // Whether an animation frame has been requested to update the signal.
+    let is_pending = Rc::new(Cell::new(false));
+
+    let handler = {
+        let last_written = Rc::clone(&last_written);
+        move |ev: web_sys::Event| {
+            if is_pending.replace(true) {
+                return;
+            }
+            let target = ev.current_target().unwrap();
+            let last_written = Rc::clone(&last_written);
+            let is_pending = Rc::clone(&is_pending);
+            let update = move || {
+                is_pending.set(false);
+                // The element might have been removed by the time the frame is run.
+                if !signal.is_alive() {
+                    return;
+                }
+                let value = js_sys::Reflect::get(&target, &E::TARGET_PROPERTY.into()).unwrap();
+                if last_written.take().as_ref() == Some(&value) {
+                    return;
+                }
+                signal.set(E::CONVERT_FROM_JS(&value).expect("failed to convert value from js"));
+            };
+            window()
+                .request_animation_frame(&Closure::once_into_js(update).unchecked_into())
+                .unwrap();
+        }
+    };
+    this.set_event_handler(<E::Event as events::EventDescriptor>::NAME, handler);
+
+    this.prop(E::TARGET_PROPERTY, move || {
+        let value: JsValue = signal.get_clone().into();
+        *last_written.borrow_mut() = Some(value.clone());
+        value
+    })
 }
 
 impl<T: GlobalProps> GlobalAttributes for T {}
@@ -1164,7 +1661,28 @@ impl<T: GlobalProps> GlobalAttributes for T {}
 /// Props that are available on all elements.
 pub trait GlobalProps: GlobalAttributes + AsHtmlNode + Sized {
     /// Set the inner html of an element.
-    fn dangerously_set_inner_html(mut self, inner_html: impl Into<Cow<'static, str>>) -> Self {
+    ///
+    /// The value is inserted as raw HTML without any escaping, both in the browser and when
+    /// rendering on the server, so it must come from a trusted source or be sanitized beforehand.
+    /// If the value is dynamic, the inner html is replaced whenever it changes. When hydrating, the
+    /// server-rendered inner html is kept as is and its nodes are not hydrated.
+    ///
+    /// The inner html replaces the children of the element, so this should not be combined with
+    /// [`children`](Self::children). In the `view!` macro, an element with this attribute cannot
+    /// have children.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # fn render_markdown(_: &str) -> String { String::new() }
+    /// # fn Comp() -> View {
+    /// let markdown = create_signal(String::from("# Hello"));
+    /// view! {
+    ///     article(dangerously_set_inner_html=render_markdown(&markdown.get_clone()))
+    /// }
+    /// # }
+    /// ```
+    fn dangerously_set_inner_html(mut self, inner_html: impl Into<StringAttribute>) -> Self {
         self.as_html_node().set_inner_html(inner_html.into());
         self
     }
@@ -0,0 +1,231 @@
+//! Components for handling errors in a part of the view.
+
+use std::error::Error;
+
+use crate::*;
+
+/// A handle to the nearest [`ErrorBoundary`]. Can be obtained with [`use_error_boundary`].
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorBoundaryHandle {
+    error: Signal<Option<Rc<dyn Error>>>,
+}
+
+impl ErrorBoundaryHandle {
+    /// Reports an error to the error boundary. The boundary renders its fallback instead of its
+    /// children until it is reset.
+    ///
+    /// If an error has already been reported, it is replaced by the new one.
+    pub fn throw(self, err: impl Into<Box<dyn Error>>) {
+        self.error.set(Some(Rc::from(err.into())));
+    }
+
+    /// Returns the value of `result` if it is `Ok`. Otherwise, reports the error to the error
+    /// boundary and returns `None`.
+    ///
+    /// This can be used instead of unwrapping a `Result` inside a component.
+    pub fn catch<T, E: Into<Box<dyn Error>>>(self, result: Result<T, E>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.throw(err);
+                None
+            }
+        }
+    }
+
+    /// Clears the error of the error boundary so that its children are rendered again.
+    pub fn reset(self) {
+        self.error.set(None);
+    }
+
+    /// Returns the error that was reported to the error boundary, if any. This is reactive.
+    pub fn error(self) -> Option<Rc<dyn Error>> {
+        self.error.get_clone()
+    }
+}
+
+/// Returns a handle to the nearest [`ErrorBoundary`].
+///
+/// The error boundary is looked up from the current reactive scope. To report errors from code
+/// that runs later on, e.g. an event handler, a timer or an async task, call this while rendering
+/// and use the returned handle in that code.
+///
+/// # Panics
+/// Panics if not inside an [`ErrorBoundary`].
+pub fn use_error_boundary() -> ErrorBoundaryHandle {
+    try_use_context().expect("not inside an `ErrorBoundary`")
+}
+
+/// Reports an error to the nearest [`ErrorBoundary`]. This is a shorthand for
+/// `use_error_boundary().throw(err)`.
+///
+/// Like [`use_error_boundary`], this looks up the error boundary from the current reactive
+/// scope, so it should only be called while rendering. Use the handle returned by
+/// [`use_error_boundary`] to report errors later on.
+///
+/// # Panics
+/// Panics if not inside an [`ErrorBoundary`].
+pub fn throw_error(err: impl Into<Box<dyn Error>>) {
+    use_error_boundary().throw(err);
+}
+
+/// Props for [`ErrorBoundary`].
+#[derive(Props)]
+pub struct ErrorBoundaryProps {
+    /// The view to display when an error is reported. Receives the error.
+    #[prop(setter(transform = |f: impl Fn(Rc<dyn Error>) -> View + 'static| Box::new(f) as Box<dyn Fn(Rc<dyn Error>) -> View>))]
+    fallback: Box<dyn Fn(Rc<dyn Error>) -> View>,
+    /// The error is cleared whenever a signal that is accessed in this closure changes. For
+    /// example, `reset_on=move || route.track()` resets the error boundary when navigating.
+    #[prop(default = Box::new(|| {}), setter(transform = |f: impl Fn() + 'static| Box::new(f) as Box<dyn Fn()>))]
+    reset_on: Box<dyn Fn()>,
+    children: Children,
+}
+
+/// `ErrorBoundary` renders a fallback view instead of its children when one of them reports an
+/// error.
+///
+/// Errors are reported with [`throw_error`] or with the [`ErrorBoundaryHandle`] returned by
+/// [`use_error_boundary`], e.g. instead of unwrapping a `Result` in a component. Errors can be
+/// reported while rendering as well as later on, e.g. in an event handler, by getting the handle
+/// while rendering and using it in the handler. An error is always
+/// handled by the nearest error boundary. Errors reported inside of the fallback are handled by
+/// the next error boundary up the tree.
+///
+/// The children are kept alive while the fallback is displayed. Once the error boundary is reset,
+/// either with [`ErrorBoundaryHandle::reset`] or through the `reset_on` prop, the children are
+/// displayed again.
+///
+/// Note that panics are not caught, since panicking aborts the program on `wasm32`.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_web::{ErrorBoundary, use_error_boundary};
+/// #[component(inline_props)]
+/// fn Age(input: &'static str) -> View {
+///     match use_error_boundary().catch(input.parse::<u32>()) {
+///         Some(age) => view! { "Age: " (age) },
+///         None => view! {},
+///     }
+/// }
+///
+/// # fn App() -> View {
+/// view! {
+///     ErrorBoundary(fallback=|err| view! { "Error: " (err.to_string()) }) {
+///         Age(input="not a number")
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn ErrorBoundary(props: ErrorBoundaryProps) -> View {
+    let ErrorBoundaryProps {
+        fallback,
+        reset_on,
+        children,
+    } = props;
+
+    let error = create_signal(None);
+    let handle = ErrorBoundaryHandle { error };
+
+    let mut is_initial = true;
+    create_effect(move || {
+        reset_on();
+        if !std::mem::replace(&mut is_initial, false) {
+            handle.reset();
+        }
+    });
+
+    let children = provide_context_in_new_scope(handle, move || children.call());
+    let fallback = move || match error.get_clone() {
+        Some(err) => fallback(err),
+        None => view! {},
+    };
+
+    view! {
+        Show(when=move || error.with(Option::is_none)) {
+            (children)
+        }
+        (fallback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[component(inline_props)]
+    fn Parse(input: &'static str) -> View {
+        match use_error_boundary().catch(input.parse::<u32>()) {
+            Some(value) => view! { (value) },
+            None => view! {},
+        }
+    }
+
+    #[test]
+    fn renders_children_without_error() {
+        let res = render_to_string(|| {
+            view! {
+                ErrorBoundary(fallback=|err| view! { (err.to_string()) }) {
+                    Parse(input="123")
+                }
+            }
+        });
+        assert_eq!(res, "<!--/-->123<!--/--><!--/--><!--/-->");
+    }
+
+    #[test]
+    fn renders_fallback_with_error() {
+        let res = render_to_string(|| {
+            view! {
+                ErrorBoundary(fallback=|err| view! { "Error: " (err.to_string()) }) {
+                    Parse(input="abc")
+                }
+            }
+        });
+        assert_eq!(
+            res,
+            "<!--/--><!--/--><!--/-->Error: <!--/-->invalid digit found in string<!--/--><!--/-->"
+        );
+    }
+
+    #[test]
+    fn nearest_boundary_handles_error() {
+        let res = render_to_string(|| {
+            view! {
+                ErrorBoundary(fallback=|_| view! { "outer" }) {
+                    ErrorBoundary(fallback=|_| view! { "inner" }) {
+                        Parse(input="abc")
+                    }
+                }
+            }
+        });
+        assert_eq!(
+            res,
+            "<!--/--><!--/--><!--/--><!--/-->inner<!--/--><!--/--><!--/--><!--/-->"
+        );
+    }
+
+    #[test]
+    fn reset_on_clears_error() {
+        let _ = create_root(|| {
+            let key = create_signal(0);
+            let handle = create_signal(None);
+            let _: View = view! {
+                ErrorBoundary(fallback=|_| view! {}, reset_on=move || key.track()) {
+                    ({
+                        handle.set(Some(use_error_boundary()));
+                        throw_error("error");
+                        view! {}
+                    })
+                }
+            };
+            let handle = handle.get().unwrap();
+            assert_eq!(handle.error().unwrap().to_string(), "error");
+
+            key.set(1);
+            assert!(handle.error().is_none());
+        });
+    }
+}
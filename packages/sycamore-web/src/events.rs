@@ -1,14 +1,18 @@
 //! Definition for all the events that can be listened to.
 
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
 #[cfg(feature = "suspense")]
 use sycamore_futures::spawn_local_scoped;
 use wasm_bindgen::JsCast;
 pub use web_sys::{
-    AnimationEvent, BeforeUnloadEvent, CompositionEvent, DeviceMotionEvent, DeviceOrientationEvent,
-    DragEvent, ErrorEvent, Event, FocusEvent, GamepadEvent, HashChangeEvent, InputEvent,
-    KeyboardEvent, MessageEvent, MouseEvent, PageTransitionEvent, PointerEvent, PopStateEvent,
-    ProgressEvent, PromiseRejectionEvent, SecurityPolicyViolationEvent, StorageEvent, SubmitEvent,
-    TouchEvent, TransitionEvent, UiEvent, WheelEvent,
+    AnimationEvent, BeforeUnloadEvent, CompositionEvent, CustomEvent, DeviceMotionEvent,
+    DeviceOrientationEvent, DragEvent, ErrorEvent, Event, FocusEvent, GamepadEvent,
+    HashChangeEvent, InputEvent, KeyboardEvent, MessageEvent, MouseEvent, PageTransitionEvent,
+    PointerEvent, PopStateEvent, ProgressEvent, PromiseRejectionEvent,
+    SecurityPolicyViolationEvent, StorageEvent, SubmitEvent, TouchEvent, TransitionEvent, UiEvent,
+    WheelEvent,
 };
 
 /// Description of a type of event.
@@ -19,6 +23,28 @@ pub trait EventDescriptor {
     const NAME: &'static str;
 }
 
+/// Description of an event that can be listened to with [`GlobalAttributes::on`].
+///
+/// This is implemented for all [`EventDescriptor`]s, whose name is known at compile time, and for
+/// [`Custom`] events, whose name is only known at runtime.
+///
+/// [`GlobalAttributes::on`]: crate::GlobalAttributes::on
+pub trait DynEventDescriptor {
+    /// The JS type of the event data that is passed to the event handler.
+    type EventTy: Into<Event> + JsCast;
+
+    /// Returns the name of the event.
+    fn into_name(self) -> Cow<'static, str>;
+}
+
+impl<E: EventDescriptor> DynEventDescriptor for E {
+    type EventTy = E::EventTy;
+
+    fn into_name(self) -> Cow<'static, str> {
+        Cow::Borrowed(E::NAME)
+    }
+}
+
 macro_rules! impl_event {
     ($name:ident: $ty:ty) => {
         #[allow(non_camel_case_types)]
@@ -181,13 +207,71 @@ impl_events! {
     visibilitychange: Event,
 }
 
-pub trait EventHandler<E: EventDescriptor, R = ()>: 'static {
+/// Descriptor for an event that is not one of the standard events, e.g. a custom event
+/// dispatched by a web component. Created with [`custom`].
+pub struct Custom<T> {
+    name: Cow<'static, str>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Into<Event> + JsCast> DynEventDescriptor for Custom<T> {
+    type EventTy = T;
+
+    fn into_name(self) -> Cow<'static, str> {
+        self.name
+    }
+}
+
+/// Creates a descriptor for an event with the given name, which can be used with `.on(...)`.
+///
+/// `T` is the type of the event that is passed to the event handler, usually [`CustomEvent`].
+/// The `detail` of a [`CustomEvent`] can be read with [`detail`].
+///
+/// The name can also be only known at runtime, e.g. a `String`.
+///
+/// In the `view!` macro, event names that are not standard events, e.g. `on:my-event` or
+/// `on:myevent`, are automatically treated as custom events with the event type [`Event`].
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::events::{self, detail, CustomEvent};
+/// # fn Comp() -> View {
+/// sycamore::web::tags::div()
+///     .on(events::custom::<CustomEvent>("color-changed"), |ev: CustomEvent| {
+///         let color = detail::<js_sys::JsString>(&ev);
+///         console_log!("color changed to {color:?}");
+///     })
+///     .into()
+/// # }
+/// ```
+pub fn custom<T: Into<Event> + JsCast>(name: impl Into<Cow<'static, str>>) -> Custom<T> {
+    Custom {
+        name: name.into(),
+        _marker: PhantomData,
+    }
+}
+
+/// Returns the `detail` of a [`CustomEvent`] if it is of type `T`.
+///
+/// The type is checked with [`JsCast::dyn_into`], so `T` should be a JS type such as
+/// [`js_sys::JsString`] or [`js_sys::Object`].
+pub fn detail<T: JsCast>(event: &CustomEvent) -> Option<T> {
+    event.detail().dyn_into().ok()
+}
+
+pub trait EventHandler<E: DynEventDescriptor, R = ()>: 'static {
     fn call(&mut self, event: E::EventTy);
+
+    /// Whether the event listener should be removed after the first event.
+    fn once(&self) -> bool {
+        false
+    }
 }
 
 impl<E, F> EventHandler<E, ()> for F
 where
-    E: EventDescriptor,
+    E: DynEventDescriptor,
     F: FnMut(E::EventTy) + 'static,
 {
     fn call(&mut self, event: E::EventTy) {
@@ -204,7 +288,7 @@ pub struct AsyncHandler;
 #[cfg(feature = "suspense")]
 impl<E, F, R> EventHandler<E, AsyncHandler> for F
 where
-    E: EventDescriptor,
+    E: DynEventDescriptor,
     F: FnMut(E::EventTy) -> R + 'static,
     R: std::future::Future<Output = ()> + 'static,
 {
@@ -212,3 +296,100 @@ where
         spawn_local_scoped(self(event));
     }
 }
+
+/// Wraps an event handler so that [`Event::prevent_default`] is called on the event before the
+/// handler is called.
+///
+/// This works for both sync and async event handlers. The type of the event can not be inferred
+/// from the event name, so the closure parameter needs a type annotation if the event is used,
+/// e.g. `prevent_default(|ev: SubmitEvent| ...)`.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::events::prevent_default;
+/// # fn Comp() -> View {
+/// view! {
+///     form(on:submit=prevent_default(|_| console_log!("submitted"))) {
+///         button(r#type="submit") { "Submit" }
+///     }
+/// }
+/// # }
+/// ```
+pub fn prevent_default<T: AsRef<Event>, R>(
+    mut handler: impl FnMut(T) -> R + 'static,
+) -> impl FnMut(T) -> R + 'static {
+    move |event: T| {
+        event.as_ref().prevent_default();
+        handler(event)
+    }
+}
+
+/// Wraps an event handler so that [`Event::stop_propagation`] is called on the event before the
+/// handler is called.
+///
+/// This works for both sync and async event handlers. See [`prevent_default`] for a note about
+/// the type of the event.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::events::stop_propagation;
+/// # fn Comp() -> View {
+/// view! {
+///     div(on:click=|_| console_log!("not called when clicking the button")) {
+///         button(on:click=stop_propagation(|_| console_log!("clicked"))) { "Click" }
+///     }
+/// }
+/// # }
+/// ```
+pub fn stop_propagation<T: AsRef<Event>, R>(
+    mut handler: impl FnMut(T) -> R + 'static,
+) -> impl FnMut(T) -> R + 'static {
+    move |event: T| {
+        event.as_ref().stop_propagation();
+        handler(event)
+    }
+}
+
+/// Wraps an event handler so that it is only called for the first event.
+///
+/// The event listener is added with `{ once: true }`, so the browser removes it after the first
+/// event and the handler is dropped after it has been called.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::events::once;
+/// # fn Comp() -> View {
+/// view! {
+///     button(on:click=once(|_| console_log!("only logged once"))) { "Click" }
+/// }
+/// # }
+/// ```
+pub fn once<T, F: FnOnce(T) + 'static>(handler: F) -> Once<F> {
+    Once(Some(handler))
+}
+
+/// An event handler that is only called for the first event. Created with [`once`].
+pub struct Once<F>(Option<F>);
+
+/// Marker trait to workaround specialization.
+#[doc(hidden)]
+pub struct OnceHandler;
+
+impl<E, F> EventHandler<E, OnceHandler> for Once<F>
+where
+    E: DynEventDescriptor,
+    F: FnOnce(E::EventTy) + 'static,
+{
+    fn call(&mut self, event: E::EventTy) {
+        if let Some(handler) = self.0.take() {
+            handler(event);
+        }
+    }
+
+    fn once(&self) -> bool {
+        true
+    }
+}
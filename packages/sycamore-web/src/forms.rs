@@ -0,0 +1,347 @@
+//! Utilities for reading the values of a `<form>`.
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use web_sys::{FormData, HtmlFormElement, SubmitEvent};
+
+use crate::*;
+
+/// Returns the entries of the form as a list of name and value pairs, in tree order.
+///
+/// Fields with multiple values (e.g. a `<select multiple>` or several checkboxes with the same
+/// name) produce one entry per value. Entries of file inputs are skipped since they are not
+/// strings. To read the selected files, access the `files` of the input element directly.
+pub fn form_entries(form: &HtmlFormElement) -> Vec<(String, String)> {
+    let form_data = FormData::new_with_form(form).unwrap_throw();
+    js_sys::try_iter(&form_data)
+        .unwrap_throw()
+        .expect_throw("`FormData` should be iterable")
+        .filter_map(|entry| {
+            let entry: js_sys::Array = entry.unwrap_throw().unchecked_into();
+            let name = entry.get(0).as_string()?;
+            let value = entry.get(1).as_string()?;
+            Some((name, value))
+        })
+        .collect()
+}
+
+/// Deserializes a list of URL-encoded name and value pairs, e.g. the entries of a form or the
+/// decoded pairs of a query string, into `T`.
+///
+/// Values are parsed the same way as with `serde_urlencoded`, so numbers and other primitives can
+/// be used as field types. A field with multiple values can be deserialized into a sequence such as
+/// a [`Vec`], which receives all the values in order. Other types only receive the first value.
+pub fn deserialize_form_pairs<T: DeserializeOwned>(
+    pairs: Vec<(String, String)>,
+) -> Result<T, serde_urlencoded::de::Error> {
+    // Group the values by name, keeping the names in the order of their first occurrence.
+    let mut fields: Vec<(String, Vec<String>)> = Vec::new();
+    for (name, value) in pairs {
+        match fields.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, values)) => values.push(value),
+            None => fields.push((name, vec![value])),
+        }
+    }
+    let fields = fields
+        .into_iter()
+        .map(|(name, values)| (name, FieldValues(values)));
+    T::deserialize(MapDeserializer::new(fields))
+}
+
+/// The values of a field in [`deserialize_form_pairs`].
+struct FieldValues(Vec<String>);
+
+impl<'de> IntoDeserializer<'de, serde_urlencoded::de::Error> for FieldValues {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! forward_to_first_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                self.first().$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FieldValues {
+    type Error = serde_urlencoded::de::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.first().deserialize_any(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(SeqDeserializer::new(self.0.into_iter().map(FieldValue)))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.first().deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_first_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_f32
+        deserialize_f64 deserialize_char deserialize_str deserialize_string deserialize_bytes
+        deserialize_byte_buf deserialize_unit deserialize_identifier deserialize_ignored_any
+    }
+
+    forward_to_deserialize_any! {
+        unit_struct tuple_struct map struct
+    }
+}
+
+impl FieldValues {
+    /// Returns the first value. There is always at least one.
+    fn first(self) -> FieldValue {
+        FieldValue(self.0.into_iter().next().unwrap_or_default())
+    }
+}
+
+/// A single value of a field in [`deserialize_form_pairs`].
+struct FieldValue(String);
+
+impl<'de> IntoDeserializer<'de, serde_urlencoded::de::Error> for FieldValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! forward_parsed_value {
+    ($($ty:ident => $method:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.parse::<$ty>() {
+                    Ok(value) => value.into_deserializer().$method(visitor),
+                    Err(err) => Err(de::Error::custom(err)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FieldValue {
+    type Error = serde_urlencoded::de::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0
+            .into_deserializer()
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    forward_parsed_value! {
+        bool => deserialize_bool,
+        u8 => deserialize_u8,
+        u16 => deserialize_u16,
+        u32 => deserialize_u32,
+        u64 => deserialize_u64,
+        i8 => deserialize_i8,
+        i16 => deserialize_i16,
+        i32 => deserialize_i32,
+        i64 => deserialize_i64,
+        f32 => deserialize_f32,
+        f64 => deserialize_f64,
+        char => deserialize_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+/// Reads the current values of the form and deserializes them into `T`.
+///
+/// See [`form_entries`] for how the entries are collected and [`deserialize_form_pairs`] for how
+/// they are deserialized. A field with multiple values, e.g. a `<select multiple>`, can be
+/// deserialized into a [`Vec`] to get all of them.
+///
+/// Unchecked checkboxes and radio buttons are not part of the form data, so they should be
+/// deserialized into an [`Option`] or use `#[serde(default)]`.
+pub fn form_values<T: DeserializeOwned>(
+    form: &HtmlFormElement,
+) -> Result<T, serde_urlencoded::de::Error> {
+    deserialize_form_pairs(form_entries(form))
+}
+
+/// Creates a `submit` event handler that calls `handler` with the values of the form
+/// deserialized into `T`.
+///
+/// The default action of the event is always prevented, so the page is not reloaded. The values
+/// are read with [`form_values`]. If they cannot be deserialized into `T`, the error is logged
+/// to the console and `handler` is not called.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_web::on_submit_values;
+/// #[derive(serde::Deserialize)]
+/// struct Login {
+///     username: String,
+///     password: String,
+///     remember: Option<String>,
+/// }
+///
+/// # fn Comp() -> View {
+/// view! {
+///     form(on:submit=on_submit_values(|login: Login| {
+///         console_log!("logging in as {}", login.username);
+///     })) {
+///         input(name="username")
+///         input(r#type="password", name="password")
+///         input(r#type="checkbox", name="remember")
+///         button(r#type="submit") { "Log in" }
+///     }
+/// }
+/// # }
+/// ```
+pub fn on_submit_values<T: DeserializeOwned>(
+    mut handler: impl FnMut(T) + 'static,
+) -> impl FnMut(SubmitEvent) + 'static {
+    move |event: SubmitEvent| {
+        event.prevent_default();
+        let form: HtmlFormElement = event.current_target().unwrap_throw().unchecked_into();
+        match form_values(&form) {
+            Ok(values) => handler(values),
+            Err(err) => console_error!("failed to deserialize form values: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Values {
+        name: String,
+        age: u32,
+        newsletter: Option<String>,
+    }
+
+    fn entries(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn deserialize_form_entries() {
+        let values: Values =
+            deserialize_form_pairs(entries(&[("name", "Jane Doe"), ("age", "42")])).unwrap();
+        assert_eq!(
+            values,
+            Values {
+                name: "Jane Doe".to_string(),
+                age: 42,
+                newsletter: None,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_form_entries_keeps_first_value() {
+        let values: Values = deserialize_form_pairs(entries(&[
+            ("name", "Jane & John"),
+            ("age", "42"),
+            ("newsletter", "weekly"),
+            ("newsletter", "monthly"),
+        ]))
+        .unwrap();
+        assert_eq!(values.name, "Jane & John");
+        assert_eq!(values.newsletter.as_deref(), Some("weekly"));
+    }
+
+    #[test]
+    fn deserialize_form_entries_multiple_values() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Filters {
+            tag: Vec<String>,
+            page: Vec<u32>,
+            #[serde(default)]
+            color: Vec<String>,
+            sort: Option<String>,
+        }
+
+        let filters: Filters = deserialize_form_pairs(entries(&[
+            ("tag", "rust"),
+            ("page", "1"),
+            ("tag", "web"),
+            ("sort", "name"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            filters,
+            Filters {
+                tag: vec!["rust".to_string(), "web".to_string()],
+                page: vec![1],
+                color: Vec::new(),
+                sort: Some("name".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_form_entries_error() {
+        let result: Result<Values, _> =
+            deserialize_form_pairs(entries(&[("name", "Jane Doe"), ("age", "old")]));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! Transferring state from the server to the client for hydration.
+
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use sycamore_macro::{component, view};
+
+use crate::tags::html_attributes::HtmlScriptAttributes;
+use crate::*;
+
+/// The id of the `<script>` element that contains the serialized state.
+const STATE_SCRIPT_ID: &str = "__sycamore_state";
+
+/// Context for the state that is transferred from the server to the client.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HydrationState(Signal<BTreeMap<String, Value>>);
+
+impl HydrationState {
+    /// Creates an empty state, for rendering on the server.
+    #[cfg_ssr]
+    pub(crate) fn new() -> Self {
+        Self(create_signal(BTreeMap::new()))
+    }
+
+    /// Reads the state that was rendered by [`HydrationScript`] on the server.
+    #[cfg_not_ssr]
+    #[cfg(feature = "hydrate")]
+    pub(crate) fn from_document() -> Self {
+        let state = document()
+            .get_element_by_id(STATE_SCRIPT_ID)
+            .and_then(|script| script.text_content())
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .unwrap_or_else(|err| {
+                console_warn!("could not parse hydrated state: {err}");
+                None
+            });
+        Self(create_signal(state.unwrap_or_default()))
+    }
+}
+
+/// Serializes `value` under `key` so that it is available on the client with
+/// [`use_hydrated_state`].
+///
+/// The state is rendered as JSON by [`HydrationScript`], which must be rendered as well. Since
+/// the `<head>` is sent first when using streaming SSR, state that is serialized inside of a
+/// `Suspense` boundary is only transferred when using [`render_to_string_await_suspense`].
+///
+/// This does nothing on the client, or on the server when not rendering with one of the
+/// `render_to_string` functions.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{serialize_state, use_hydrated_state};
+/// # fn fetch_user_count() -> u32 { 42 }
+/// #[component]
+/// fn Stats() -> View {
+///     // Only fetch the data on the server. The client reuses the result.
+///     let count = use_hydrated_state("user-count").unwrap_or_else(|| {
+///         let count = fetch_user_count();
+///         serialize_state("user-count", &count);
+///         count
+///     });
+///     view! { p { (count) " users" } }
+/// }
+/// ```
+pub fn serialize_state<T: Serialize>(key: &str, value: &T) {
+    if is_not_ssr!() {
+        let _ = (key, value);
+        return;
+    }
+    let Some(state) = try_use_context::<HydrationState>() else {
+        return;
+    };
+    match serde_json::to_value(value) {
+        Ok(value) => state.0.update(|state| {
+            state.insert(key.to_string(), value);
+        }),
+        Err(err) => console_warn!("could not serialize state `{key}`: {err}"),
+    }
+}
+
+/// Reads the value that was serialized under `key` on the server with [`serialize_state`].
+///
+/// Returns `None` if there is no such value, if it cannot be deserialized as `T`, or if the app
+/// was not hydrated. On the server, this returns the value that was serialized during the current
+/// render.
+pub fn use_hydrated_state<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let state = try_use_context::<HydrationState>()?;
+    let value = state.0.with_untracked(|state| state.get(key).cloned())?;
+    match serde_json::from_value(value) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            console_warn!("could not deserialize hydrated state `{key}`: {err}");
+            None
+        }
+    }
+}
+
+/// Renders the state that was serialized with [`serialize_state`] into a JSON `<script>`.
+///
+/// This is rendered by [`HydrationScript`] and must not be hydrated.
+#[component]
+pub(crate) fn HydrationStateScript() -> View {
+    is_ssr! {
+        let state = use_context::<HydrationState>();
+        // Escape `<` so that the JSON cannot close the `<script>` element.
+        let json = move || {
+            state
+                .0
+                .with(|state| serde_json::to_string(state).unwrap().replace('<', "\\u003c"))
+        };
+        view! {
+            script(r#type="application/json", id=STATE_SCRIPT_ID) { (json) }
+        }
+    }
+    is_not_ssr! {
+        view! {}
+    }
+}
+
+#[cfg(test)]
+#[cfg_ssr]
+mod tests {
+    use super::*;
+
+    #[component]
+    fn App() -> View {
+        serialize_state("count", &5);
+        serialize_state("html", &"</script>");
+        assert_eq!(use_hydrated_state::<i32>("count"), Some(5));
+        assert_eq!(use_hydrated_state::<String>("count"), None);
+        assert_eq!(use_hydrated_state::<i32>("missing"), None);
+        view! { p { "App" } }
+    }
+
+    #[test]
+    fn hydration_script_renders_state() {
+        let html = render_to_string(|| {
+            view! {
+                HydrationScript {}
+                App {}
+            }
+        });
+        assert_eq!(
+            html,
+            r#"<script></script><script type="application/json" id="__sycamore_state">{"count":5,"html":"\u003c/script>"}</script><p data-hk="0.0">App</p>"#
+        );
+    }
+
+    #[test]
+    fn serialize_state_outside_of_render_does_nothing() {
+        let _ = create_root(|| {
+            serialize_state("count", &5);
+            assert_eq!(use_hydrated_state::<i32>("count"), None);
+        });
+    }
+}
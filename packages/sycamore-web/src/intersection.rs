@@ -0,0 +1,171 @@
+//! Observing the visibility of elements with `IntersectionObserver`.
+
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+use crate::*;
+
+/// Options for [`use_intersection`] and [`on_intersect`].
+///
+/// See the [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/IntersectionObserver#options)
+/// for more details.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntersectionOptions {
+    /// The element that is used as the viewport for checking the visibility of the target. If
+    /// `None` or not set, the browser viewport is used.
+    pub root: Option<NodeRef>,
+    /// A margin around the root, in CSS syntax, e.g. `"200px 0px"` to start loading content before
+    /// it is scrolled into view. Defaults to `"0px"`.
+    pub root_margin: String,
+    /// The ratio of the target that must be visible for it to be considered intersecting, between
+    /// `0.0` and `1.0`. Defaults to `0.0`, i.e. a single visible pixel is enough.
+    pub threshold: f64,
+}
+
+impl Default for IntersectionOptions {
+    fn default() -> Self {
+        Self {
+            root: None,
+            root_margin: "0px".to_string(),
+            threshold: 0.0,
+        }
+    }
+}
+
+/// Returns a signal that is `true` while the element referenced by `node` intersects with the
+/// viewport (or with the root in `options`).
+///
+/// The element is observed once the component is mounted. If `node` is not set at that point, e.g.
+/// because the element is rendered conditionally, observation starts as soon as it is set. The
+/// observer is disconnected when the current reactive scope is disposed.
+///
+/// In SSR mode, the signal is always `false`.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{use_intersection, IntersectionOptions};
+/// #[component]
+/// fn FadeIn(children: Children) -> View {
+///     let node = create_node_ref();
+///     let visible = use_intersection(node, IntersectionOptions::default());
+///     view! {
+///         div(r#ref=node, class=move || if visible.get() { "fade-in visible" } else { "fade-in" }) {
+///             (children)
+///         }
+///     }
+/// }
+/// ```
+pub fn use_intersection(node: NodeRef, options: IntersectionOptions) -> ReadSignal<bool> {
+    let intersecting = create_signal(false);
+    observe_intersection(node, options, move |value| intersecting.set(value));
+    *intersecting
+}
+
+/// Calls `callback` whenever the element referenced by `node` starts intersecting with the
+/// viewport (or with the root in `options`).
+///
+/// This is useful for infinite scrolling, by loading more items whenever a sentinel element at the
+/// end of the list becomes visible. See [`use_intersection`] for details on when the element is
+/// observed.
+///
+/// In SSR mode, `callback` is never called.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{on_intersect, IntersectionOptions};
+/// #[component]
+/// fn Feed() -> View {
+///     let items = create_signal(vec![0, 1, 2]);
+///     let sentinel = create_node_ref();
+///     let options = IntersectionOptions {
+///         root_margin: "200px".to_string(),
+///         ..Default::default()
+///     };
+///     on_intersect(sentinel, options, move || {
+///         items.update(|items| items.push(items.len()));
+///     });
+///     view! {
+///         ul {
+///             Keyed(list=items, view=|item| view! { li { (item) } }, key=|item| *item)
+///         }
+///         div(r#ref=sentinel)
+///     }
+/// }
+/// ```
+pub fn on_intersect(node: NodeRef, options: IntersectionOptions, callback: impl Fn() + 'static) {
+    observe_intersection(node, options, move |intersecting| {
+        if intersecting {
+            untrack(&callback);
+        }
+    });
+}
+
+/// Observes the element referenced by `node` and calls `f` with its intersection state whenever it
+/// changes.
+fn observe_intersection(node: NodeRef, options: IntersectionOptions, f: impl Fn(bool) + 'static) {
+    if is_ssr!() {
+        let _ = (node, options, f);
+        return;
+    }
+
+    let f = Rc::new(f);
+    on_mount(move || {
+        // Re-observe whenever the node ref or the root changes.
+        create_effect(move || {
+            let Some(element) = node.try_get().and_then(|node| node.dyn_into().ok()) else {
+                f(false);
+                return;
+            };
+            let root = options
+                .root
+                .and_then(|root| root.try_get())
+                .and_then(|root| root.dyn_into().ok());
+
+            let on_change: Closure<dyn Fn(js_sys::Array)> = Closure::new({
+                let f = Rc::clone(&f);
+                let threshold = options.threshold;
+                move |entries: js_sys::Array| {
+                    // Only the latest entry is relevant if there were multiple changes.
+                    if let Some(entry) = entries.iter().last() {
+                        let entry = entry.unchecked_into::<IntersectionObserverEntry>();
+                        f(entry.is_intersecting() && entry.intersection_ratio() >= threshold);
+                    }
+                }
+            });
+            let init = IntersectionObserverInit::new();
+            init.set_root(root.as_ref());
+            init.set_root_margin(&options.root_margin);
+            init.set_threshold_f64(options.threshold);
+            let observer =
+                IntersectionObserver::new_with_options(on_change.as_ref().unchecked_ref(), &init)
+                    .unwrap_throw();
+            observer.observe(&element);
+
+            on_cleanup(move || {
+                observer.disconnect();
+                drop(on_change);
+            });
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_is_false_in_ssr() {
+        let _ = create_root(|| {
+            let node = create_node_ref();
+            let visible = use_intersection(node, IntersectionOptions::default());
+            let called = create_signal(false);
+            on_intersect(node, IntersectionOptions::default(), move || {
+                called.set(true)
+            });
+            let _: View = view! { div(r#ref=node) };
+            assert!(!visible.get());
+            assert!(!called.get());
+        });
+    }
+}
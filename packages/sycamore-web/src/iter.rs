@@ -7,7 +7,6 @@
 
 use std::collections::HashMap;
 use std::hash::Hash;
-use std::ops::Deref;
 
 use sycamore_macro::{component, Props};
 use wasm_bindgen::prelude::*;
@@ -36,7 +35,15 @@ where
 /// Using this will minimize re-renders instead of re-rendering every view node on every
 /// state change.
 ///
-/// For non keyed iteration, see [`Indexed`].
+/// For non keyed iteration, see [`Indexed`]. To also get the index of each item, see
+/// [`KeyedWithIndex`].
+///
+/// # Per-item reactivity
+///
+/// If the items themselves need to be updated, consider using a list of signals (e.g.
+/// `Signal<Vec<Signal<T>>>`) instead of replacing the whole list. Updating the signal of an item
+/// then only updates the views that depend on that item. The list is only re-diffed if it actually
+/// changes, even if it is derived from a memo that tracks the item signals.
 ///
 /// # Example
 ///
@@ -93,50 +100,138 @@ where
                 .collect::<Vec<_>>(),
         )
     } else {
-        let start = HtmlNode::create_marker_node();
-        let start_node = start.as_web_sys().clone();
-        let end = HtmlNode::create_marker_node();
-        let end_node = end.as_web_sys().clone();
-
-        // Run the initial function in the outer scope, not the effect scope.
-        // This is because we might want to create signals and other things managed by the reactive
-        // tree that will be used in future triggers of this effect. These things must therefore
-        // live as long as the effect.
-        let scope = use_current_scope();
-        create_effect_initial(move || {
-            scope.run_in(move || {
-                let nodes = map_keyed(list, move |x| view(x).into().as_web_sys(), key);
-                // Flatten nodes.
-                let flattened = nodes.map(|x| x.iter().flatten().cloned().collect::<Vec<_>>());
-                let view = flattened.with(|x| {
-                    View::from_nodes(
-                        x.iter()
-                            .map(|x| HtmlNode::from_web_sys(x.clone()))
-                            .collect(),
-                    )
-                });
-                (
-                    Box::new(move || {
-                        // Get all nodes between start and end and reconcile with new nodes.
-                        let mut new = flattened.get_clone();
-                        let mut old = utils::get_nodes_between(&start_node, &end_node);
-                        // We must include the end node in case `old` is empty (precondition for
-                        // reconcile_fragments).
-                        new.push(end_node.clone());
-                        old.push(end_node.clone());
-
-                        if let Some(parent) = start_node.parent_node() {
-                            reconcile_fragments(&parent, &mut old, &new);
-                        }
-                    }) as Box<dyn FnMut()>,
-                    (start, view, end).into(),
-                )
-            })
+        render_mapped_nodes(move || map_keyed(list, move |x| view(x).into().as_web_sys(), key))
+    }
+}
+
+/// Props for [`KeyedWithIndex`].
+#[derive(Props)]
+pub struct KeyedWithIndexProps<T, K, U, List, F, Key>
+where
+    List: Into<MaybeDyn<Vec<T>>> + 'static,
+    F: Fn(T, ReadSignal<usize>) -> U + 'static,
+    Key: Fn(&T) -> K + 'static,
+    T: 'static,
+{
+    list: List,
+    view: F,
+    key: Key,
+    #[prop(default)]
+    _phantom: std::marker::PhantomData<(T, K, U)>,
+}
+
+/// Keyed iteration that also passes the index of each item to the `view` function.
+///
+/// This is the same as [`Keyed`], except that the `view` function also receives a signal with the
+/// index of the item in the list. When an item is moved, its view is reused and the index signal
+/// is updated to the new position, so only the parts of the view that depend on the index are
+/// updated.
+///
+/// # Example
+///
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn App() -> View {
+/// let players = create_signal(vec!["Alice", "Bob", "Carol"]);
+/// view! {
+///     ol {
+///         KeyedWithIndex(
+///             list=players,
+///             view=|player, index| view! {
+///                 li { "#" (index.get() + 1) ": " (player) }
+///             },
+///             key=|player| *player,
+///         )
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn KeyedWithIndex<T, K, U, List, F, Key>(
+    props: KeyedWithIndexProps<T, K, U, List, F, Key>,
+) -> View
+where
+    T: PartialEq + Clone + 'static,
+    K: Hash + Eq + 'static,
+    U: Into<View>,
+    List: Into<MaybeDyn<Vec<T>>> + 'static,
+    F: Fn(T, ReadSignal<usize>) -> U + 'static,
+    Key: Fn(&T) -> K + 'static,
+{
+    let KeyedWithIndexProps {
+        list, view, key, ..
+    } = props;
+
+    if is_ssr!() {
+        // In SSR mode, just create a static view.
+        View::from(
+            list.into()
+                .evaluate()
+                .into_iter()
+                .enumerate()
+                .map(|(i, x)| view(x, *create_signal(i)).into())
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        render_mapped_nodes(move || {
+            map_keyed_with_index(
+                list,
+                move |x, index| view(x, index).into().as_web_sys(),
+                key,
+            )
         })
     }
 }
 
-/// Props for [`Keyed`].
+/// Renders the nodes of a mapped list between two marker nodes, reconciling the DOM nodes
+/// whenever the list changes. This is the common implementation of the iteration components in
+/// CSR mode.
+fn render_mapped_nodes(
+    map_nodes: impl FnOnce() -> ReadSignal<Vec<Vec<web_sys::Node>>> + 'static,
+) -> View {
+    let start = HtmlNode::create_marker_node();
+    let start_node = start.as_web_sys().clone();
+    let end = HtmlNode::create_marker_node();
+    let end_node = end.as_web_sys().clone();
+
+    // Run the initial function in the outer scope, not the effect scope.
+    // This is because we might want to create signals and other things managed by the reactive
+    // tree that will be used in future triggers of this effect. These things must therefore
+    // live as long as the effect.
+    let scope = use_current_scope();
+    create_effect_initial(move || {
+        scope.run_in(move || {
+            let nodes = map_nodes();
+            // Flatten nodes.
+            let flattened = nodes.map(|x| x.iter().flatten().cloned().collect::<Vec<_>>());
+            let view = flattened.with(|x| {
+                View::from_nodes(
+                    x.iter()
+                        .map(|x| HtmlNode::from_web_sys(x.clone()))
+                        .collect(),
+                )
+            });
+            (
+                Box::new(move || {
+                    // Get all nodes between start and end and reconcile with new nodes.
+                    let mut new = flattened.get_clone();
+                    let mut old = utils::get_nodes_between(&start_node, &end_node);
+                    // We must include the end node in case `old` is empty (precondition for
+                    // reconcile_fragments).
+                    new.push(end_node.clone());
+                    old.push(end_node.clone());
+
+                    if let Some(parent) = start_node.parent_node() {
+                        reconcile_fragments(&parent, &old, &new);
+                    }
+                }) as Box<dyn FnMut()>,
+                (start, view, end).into(),
+            )
+        })
+    })
+}
+
+/// Props for [`Indexed`].
 #[derive(Props)]
 pub struct IndexedProps<T, U, List, F>
 where
@@ -146,6 +241,10 @@ where
 {
     list: List,
     view: F,
+    /// Silences the warning that is shown in debug builds when the list is reordered. See
+    /// [`Indexed`].
+    #[prop(default)]
+    allow_reorder: bool,
     #[prop(default)]
     _phantom: std::marker::PhantomData<(T, U)>,
 }
@@ -156,7 +255,17 @@ where
 /// [`View`]s. Using this will minimize re-renders instead of re-rendering every single
 /// node on every state change.
 ///
-/// For keyed iteration, see [`Keyed`].
+/// For keyed iteration, see [`Keyed`]. To create views that depend on the surrounding items, see
+/// [`IndexedWithNeighbors`].
+///
+/// # Reordering
+///
+/// Views are reused by index, so any state created in the `view` function stays at the same
+/// position when the list is reordered, instead of following its item. For lists that can be
+/// reordered, [`Keyed`] should usually be used instead. In debug builds, a warning is logged the
+/// first time the list is updated to a reordering of its previous value, which is detected by
+/// comparing the hashes of the items. If reusing views by index is intended, the warning can be
+/// silenced with `allow_reorder=true`.
 ///
 /// # Example
 /// ```
@@ -178,66 +287,208 @@ where
 #[component]
 pub fn Indexed<T, U, List, F>(props: IndexedProps<T, U, List, F>) -> View
 where
-    T: PartialEq + Clone + 'static,
+    T: PartialEq + Hash + Clone + 'static,
     U: Into<View>,
     List: Into<MaybeDyn<Vec<T>>> + 'static,
     F: Fn(T) -> U + 'static,
 {
-    let IndexedProps { list, view, .. } = props;
+    let IndexedProps {
+        list,
+        view,
+        allow_reorder,
+        ..
+    } = props;
+    render_indexed(list.into(), view, allow_reorder)
+}
+
+/// Props for [`IndexedWithNeighbors`].
+#[derive(Props)]
+pub struct IndexedWithNeighborsProps<T, U, List, F>
+where
+    List: Into<MaybeDyn<Vec<T>>> + 'static,
+    F: Fn(T, Option<&T>, Option<&T>) -> U + 'static,
+    T: 'static,
+{
+    list: List,
+    view: F,
+    /// Silences the warning that is shown in debug builds when the list is reordered. See
+    /// [`Indexed`].
+    #[prop(default)]
+    allow_reorder: bool,
+    #[prop(default)]
+    _phantom: std::marker::PhantomData<(T, U)>,
+}
+
+/// Non keyed iteration that also passes the previous and next item to the `view` function.
+///
+/// This is the same as [`Indexed`], except that the `view` function also receives the previous
+/// and the next item in the list, if any. This is useful for views that depend on the surrounding
+/// items, e.g. to only show a header before the first item of a group. The view of an item is
+/// re-created whenever the item or one of its neighbors changes.
+///
+/// # Example
+///
+/// Grouping items by their first letter:
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn App() -> View {
+/// let names = create_signal(vec!["Alice", "Anna", "Bob", "Carol", "Chris"]);
+/// view! {
+///     ul {
+///         IndexedWithNeighbors(
+///             list=names,
+///             view=|name, prev, _next| {
+///                 let initial = &name[..1];
+///                 let header = if prev.map_or(true, |prev| !prev.starts_with(initial)) {
+///                     view! { h2 { (initial) } }
+///                 } else {
+///                     view! {}
+///                 };
+///                 view! {
+///                     (header)
+///                     li { (name) }
+///                 }
+///             },
+///         )
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn IndexedWithNeighbors<T, U, List, F>(props: IndexedWithNeighborsProps<T, U, List, F>) -> View
+where
+    T: PartialEq + Hash + Clone + 'static,
+    U: Into<View>,
+    List: Into<MaybeDyn<Vec<T>>> + 'static,
+    F: Fn(T, Option<&T>, Option<&T>) -> U + 'static,
+{
+    let IndexedWithNeighborsProps {
+        list,
+        view,
+        allow_reorder,
+        ..
+    } = props;
+    let list: MaybeDyn<Vec<T>> = list.into();
+
+    #[cfg(debug_assertions)]
+    if !allow_reorder && is_not_ssr!() {
+        warn_on_reorder(list.clone());
+    }
+    let view = move |(item, prev, next): (T, Option<T>, Option<T>)| {
+        view(item, prev.as_ref(), next.as_ref())
+    };
+    let list = create_memo(move || with_neighbors(list.get_clone()));
+    // The warning has already been set up for the original list.
+    render_indexed(list.into(), view, true)
+}
+
+/// Pairs each item of `list` with the previous and next item.
+fn with_neighbors<T: Clone>(list: Vec<T>) -> Vec<(T, Option<T>, Option<T>)> {
+    (0..list.len())
+        .map(|i| {
+            let prev = i.checked_sub(1).map(|i| list[i].clone());
+            let next = list.get(i + 1).cloned();
+            (list[i].clone(), prev, next)
+        })
+        .collect()
+}
 
+/// Renders an [`Indexed`] list with the given view function.
+fn render_indexed<T, U>(
+    list: MaybeDyn<Vec<T>>,
+    view: impl Fn(T) -> U + 'static,
+    allow_reorder: bool,
+) -> View
+where
+    T: PartialEq + Hash + Clone + 'static,
+    U: Into<View>,
+{
     if is_ssr!() {
         // In SSR mode, just create a static view.
         View::from(
-            list.into()
-                .evaluate()
+            list.evaluate()
                 .into_iter()
                 .map(|x| view(x).into())
                 .collect::<Vec<_>>(),
         )
     } else {
-        let start = HtmlNode::create_marker_node();
-        let start_node = start.as_web_sys().clone();
-        let end = HtmlNode::create_marker_node();
-        let end_node = end.as_web_sys().clone();
-
-        // Run the initial function in the outer scope, not the effect scope.
-        // This is because we might want to create signals and other things managed by the reactive
-        // tree that will be used in future triggers of this effect. These things must therefore
-        // live as long as the effect.
-        let scope = use_current_scope();
-        create_effect_initial(move || {
-            scope.run_in(move || {
-                let nodes = map_indexed(list, move |x| view(x).into().as_web_sys());
-                // Flatten nodes.
-                let flattened = nodes.map(|x| x.iter().flatten().cloned().collect::<Vec<_>>());
-                let view = flattened.with(|x| {
-                    View::from_nodes(
-                        x.iter()
-                            .map(|x| HtmlNode::from_web_sys(x.clone()))
-                            .collect(),
-                    )
-                });
-                (
-                    Box::new(move || {
-                        // Get all nodes between start and end and reconcile with new nodes.
-                        let mut new = flattened.get_clone();
-                        let mut old = utils::get_nodes_between(&start_node, &end_node);
-                        // We must include the end node in case `old` is empty (precondition for
-                        // reconcile_fragments).
-                        new.push(end_node.clone());
-                        old.push(end_node.clone());
-
-                        if let Some(parent) = start_node.parent_node() {
-                            reconcile_fragments(&parent, &mut old, &new);
-                        }
-                    }) as Box<dyn FnMut()>,
-                    (start, view, end).into(),
-                )
-            })
-        })
+        #[cfg(debug_assertions)]
+        if !allow_reorder {
+            warn_on_reorder(list.clone());
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = allow_reorder;
+
+        render_mapped_nodes(move || map_indexed(list, move |x| view(x).into().as_web_sys()))
     }
 }
 
+/// Logs a warning the first time `list` is updated to a reordering of its previous value. This
+/// is used by [`Indexed`] to suggest using [`Keyed`] instead.
+///
+/// Only the hashes of the items are kept between updates, so the list does not need to be cloned.
+#[cfg(debug_assertions)]
+fn warn_on_reorder<T: Hash + Clone + 'static>(list: MaybeDyn<Vec<T>>) {
+    if list.as_static().is_some() {
+        return;
+    }
+    let mut prev = None::<Vec<u64>>;
+    let mut warned = false;
+    create_effect(move || {
+        // The list is no longer tracked once the warning has been shown.
+        if warned {
+            return;
+        }
+        let hash_items = |list: &Vec<T>| list.iter().map(hash_item).collect::<Vec<_>>();
+        // Derived lists can only be read by value.
+        let new = match &list {
+            MaybeDyn::Signal(signal) => signal.with(hash_items),
+            _ => hash_items(&list.get_clone()),
+        };
+        if prev.as_ref().is_some_and(|prev| is_reordered(prev, &new)) {
+            console_warn!(
+                "An `Indexed` list was reordered. Views are reused by index, so state in the \
+                 views does not follow the items. Consider using `Keyed` instead, or pass \
+                 `allow_reorder=true` to `Indexed` to silence this warning."
+            );
+            warned = true;
+            prev = None;
+        } else {
+            prev = Some(new);
+        }
+    });
+}
+
+/// Hashes an item of an [`Indexed`] list for [`is_reordered`].
+#[cfg(debug_assertions)]
+fn hash_item<T: Hash>(item: &T) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns `true` if `new` contains the same keys as `prev` but in a different order.
+#[cfg(debug_assertions)]
+fn is_reordered<K: Hash + Eq>(prev: &[K], new: &[K]) -> bool {
+    if prev.len() != new.len() || prev == new {
+        return false;
+    }
+    let mut counts = HashMap::<&K, isize>::with_capacity(prev.len());
+    for key in prev {
+        *counts.entry(key).or_default() += 1;
+    }
+    for key in new {
+        match counts.get_mut(key) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => return false,
+        }
+    }
+    true
+}
+
 #[wasm_bindgen]
 extern "C" {
     /// Extend [`web_sys::Node`] type with an id field. This is used to make `Node` hashable from
@@ -250,49 +501,57 @@ extern "C" {
     pub fn set_node_id(this: &NodeWithId, id: usize);
 }
 
-/// A wrapper around [`web_sys::Node`] that implements `Hash` and `Eq`.
-struct HashableNode<'a>(&'a NodeWithId, usize);
-
-impl<'a> HashableNode<'a> {
+/// Returns a unique id for `node`, assigning a new one if the node does not have one yet.
+fn node_key(node: &web_sys::Node) -> usize {
     thread_local! {
         static NEXT_ID: Cell<usize> = const { Cell::new(0) };
     }
 
-    fn new(node: &'a web_sys::Node) -> Self {
-        let node = node.unchecked_ref::<NodeWithId>();
-        let id = if let Some(id) = node.node_id() {
+    let node = node.unchecked_ref::<NodeWithId>();
+    if let Some(id) = node.node_id() {
+        id
+    } else {
+        NEXT_ID.with(|cell| {
+            let id = cell.get();
+            cell.set(id + 1);
+            node.set_node_id(id);
             id
-        } else {
-            Self::NEXT_ID.with(|cell| {
-                let id = cell.get();
-                cell.set(id + 1);
-                node.set_node_id(id);
-                id
-            })
-        };
-        Self(node, id)
+        })
     }
 }
 
-impl<'a> PartialEq for HashableNode<'a> {
-    fn eq(&self, other: &Self) -> bool {
-        self.1 == other.1
-    }
+/// The operations on a parent node that are needed by [`reconcile`]. This allows testing the
+/// algorithm without a DOM.
+trait ReconcileParent {
+    type Node: Clone + PartialEq;
+
+    /// Inserts or moves `node` before `reference`, or at the end if `reference` is `None`.
+    fn insert(&self, node: &Self::Node, reference: Option<&Self::Node>);
+    /// Removes `node` from the parent.
+    fn remove(&self, node: &Self::Node);
+    /// Returns the node after `node`.
+    fn next(&self, node: &Self::Node) -> Option<Self::Node>;
+    /// Returns a key that uniquely identifies `node`.
+    fn key(&self, node: &Self::Node) -> usize;
 }
 
-impl<'a> Eq for HashableNode<'a> {}
+impl ReconcileParent for web_sys::Node {
+    type Node = web_sys::Node;
 
-impl<'a> Hash for HashableNode<'a> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.1.hash(state);
+    fn insert(&self, node: &web_sys::Node, reference: Option<&web_sys::Node>) {
+        self.insert_before(node, reference).unwrap();
     }
-}
 
-impl Deref for HashableNode<'_> {
-    type Target = NodeWithId;
+    fn remove(&self, node: &web_sys::Node) {
+        self.remove_child(node).unwrap();
+    }
 
-    fn deref(&self) -> &Self::Target {
-        self.0
+    fn next(&self, node: &web_sys::Node) -> Option<web_sys::Node> {
+        node.next_sibling()
+    }
+
+    fn key(&self, node: &web_sys::Node) -> usize {
+        node_key(node)
     }
 }
 
@@ -306,9 +565,7 @@ impl Deref for HashableNode<'_> {
 ///
 /// # Panics
 /// Panics if `a.is_empty()`. Append nodes instead.
-fn reconcile_fragments(parent: &web_sys::Node, a: &mut [web_sys::Node], b: &[web_sys::Node]) {
-    debug_assert!(!a.is_empty(), "a cannot be empty");
-
+fn reconcile_fragments(parent: &web_sys::Node, a: &[web_sys::Node], b: &[web_sys::Node]) {
     // Sanity check: make sure all nodes in a are children of parent.
     #[cfg(debug_assertions)]
     {
@@ -319,118 +576,324 @@ fn reconcile_fragments(parent: &web_sys::Node, a: &mut [web_sys::Node], b: &[web
         }
     }
 
+    reconcile(parent, a, b);
+
+    // Sanity check: make sure all nodes in b are children of parent after reconciliation.
+    #[cfg(debug_assertions)]
+    {
+        for (i, node) in b.iter().enumerate() {
+            if node.parent_node().as_ref() != Some(parent) {
+                panic!(
+                    "node {i} in new nodes Vec is not a child of parent after reconciliation. node = {node:#?}",
+                );
+            }
+        }
+    }
+}
+
+/// The implementation of [`reconcile_fragments`].
+///
+/// The common prefix and suffix of `a` and `b` are skipped first. For the remaining nodes, the
+/// longest subsequence of nodes that are already in the right relative order is computed. Only
+/// the nodes outside of this subsequence are moved, which is the minimal number of moves.
+fn reconcile<P: ReconcileParent>(parent: &P, a: &[P::Node], b: &[P::Node]) {
+    debug_assert!(!a.is_empty(), "a cannot be empty");
+
     let b_len = b.len();
     let mut a_end = a.len();
     let mut b_end = b_len;
     let mut a_start = 0;
     let mut b_start = 0;
-    let mut map = None::<HashMap<HashableNode, usize>>;
 
     // Last node in a.
-    let after = a[a_end - 1].next_sibling();
-
-    while a_start < a_end || b_start < b_end {
-        if a_end == a_start {
-            // Append.
-            let node = if b_end < b_len {
-                if b_start != 0 {
-                    b[b_start - 1].next_sibling()
-                } else {
-                    Some(b[b_end - b_start].clone())
+    let after = parent.next(&a[a_end - 1]);
+
+    // Common prefix.
+    while a_start < a_end && b_start < b_end && a[a_start] == b[b_start] {
+        a_start += 1;
+        b_start += 1;
+    }
+    // Common suffix.
+    while a_start < a_end && b_start < b_end && a[a_end - 1] == b[b_end - 1] {
+        a_end -= 1;
+        b_end -= 1;
+    }
+
+    // The node before which the remaining nodes in `b` should be inserted.
+    let anchor = if b_end < b_len {
+        Some(b[b_end].clone())
+    } else {
+        after
+    };
+
+    if a_start == a_end {
+        // Append.
+        for node in &b[b_start..b_end] {
+            parent.insert(node, anchor.as_ref());
+        }
+        return;
+    }
+    if b_start == b_end {
+        // Remove.
+        for node in &a[a_start..a_end] {
+            parent.remove(node);
+        }
+        return;
+    }
+
+    // For every remaining node in `b`, find its index in `a`, if it already exists. Nodes in `a`
+    // that are not in `b` are removed.
+    let b_indices: HashMap<usize, usize> = (b_start..b_end)
+        .map(|i| (parent.key(&b[i]), i - b_start))
+        .collect();
+    let mut sources = vec![None; b_end - b_start];
+    for (i, node) in a.iter().enumerate().take(a_end).skip(a_start) {
+        match b_indices.get(&parent.key(node)) {
+            Some(&j) => sources[j] = Some(i),
+            None => parent.remove(node),
+        }
+    }
+
+    // Insert the nodes from back to front so that the next node is always in the right place.
+    let stable = longest_increasing_subsequence(&sources);
+    let mut anchor = anchor;
+    for j in (0..sources.len()).rev() {
+        let node = &b[b_start + j];
+        if !stable[j] {
+            parent.insert(node, anchor.as_ref());
+        }
+        anchor = Some(node.clone());
+    }
+}
+
+/// Finds the longest strictly increasing subsequence of the `Some` values in `seq`. Returns a
+/// `Vec` with `true` at the indices that are part of the subsequence.
+fn longest_increasing_subsequence(seq: &[Option<usize>]) -> Vec<bool> {
+    // `tails[k]` is the index in `seq` of the smallest tail of an increasing subsequence of
+    // length `k + 1`.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors = vec![None; seq.len()];
+    for (i, value) in seq.iter().enumerate() {
+        let Some(value) = *value else { continue };
+        let len = tails.partition_point(|&t| seq[t].unwrap() < value);
+        if len > 0 {
+            predecessors[i] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut result = vec![false; seq.len()];
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        result[i] = true;
+        current = predecessors[i];
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn keyed_with_index_ssr() {
+        let res = render_to_string(|| {
+            let list = create_signal(vec!["a", "b"]);
+            view! {
+                ul {
+                    KeyedWithIndex(
+                        list=list,
+                        view=|item, index| view! { li(id=index.get().to_string()) { (item) } },
+                        key=|item| *item,
+                    )
+                }
+            }
+        });
+        assert_eq!(
+            res,
+            r#"<ul data-hk="0.0"><li id="0" data-hk="0.1">a</li><li id="1" data-hk="0.2">b</li></ul>"#
+        );
+    }
+
+    #[test]
+    fn pair_with_neighbors() {
+        assert_eq!(
+            with_neighbors(vec![1, 2, 3]),
+            [
+                (1, None, Some(2)),
+                (2, Some(1), Some(3)),
+                (3, Some(2), None)
+            ]
+        );
+        assert_eq!(with_neighbors(vec![1]), [(1, None, None)]);
+        assert_eq!(with_neighbors(Vec::<i32>::new()), []);
+    }
+
+    #[test]
+    fn indexed_with_neighbors_ssr() {
+        let res = render_to_string(|| {
+            let list = create_signal(vec!["a", "a", "b"]);
+            view! {
+                ul {
+                    IndexedWithNeighbors(
+                        list=list,
+                        view=|item, prev, _next| {
+                            let class = if prev == Some(&item) { "same" } else { "first" };
+                            view! { li(class=class) { (item) } }
+                        },
+                    )
                 }
-            } else {
-                after.clone()
+            }
+        });
+        assert_eq!(
+            res,
+            r#"<ul data-hk="0.0"><li class="first" data-hk="0.1">a</li><li class="same" data-hk="0.2">a</li><li class="first" data-hk="0.3">b</li></ul>"#
+        );
+    }
+
+    /// A parent node that keeps its children in a `Vec` and counts the DOM operations.
+    #[derive(Default)]
+    struct FakeParent {
+        children: RefCell<Vec<u32>>,
+        moves: Cell<usize>,
+    }
+
+    impl ReconcileParent for FakeParent {
+        type Node = u32;
+
+        fn insert(&self, node: &u32, reference: Option<&u32>) {
+            let mut children = self.children.borrow_mut();
+            if let Some(i) = children.iter().position(|x| x == node) {
+                children.remove(i);
+                self.moves.set(self.moves.get() + 1);
+            }
+            let i = match reference {
+                Some(reference) => children.iter().position(|x| x == reference).unwrap(),
+                None => children.len(),
             };
+            children.insert(i, *node);
+        }
+
+        fn remove(&self, node: &u32) {
+            self.children.borrow_mut().retain(|x| x != node);
+        }
 
-            for new_node in &b[b_start..b_end] {
-                parent.insert_before(new_node, node.as_ref()).unwrap();
+        fn next(&self, node: &u32) -> Option<u32> {
+            let children = self.children.borrow();
+            let i = children.iter().position(|x| x == node).unwrap();
+            children.get(i + 1).copied()
+        }
+
+        fn key(&self, node: &u32) -> usize {
+            *node as usize
+        }
+    }
+
+    /// Reconciles `a` to `b` between two marker nodes and returns the number of moved nodes.
+    fn check_reconcile(a: &[u32], b: &[u32]) -> usize {
+        const START: u32 = u32::MAX - 1;
+        const END: u32 = u32::MAX;
+        let parent = FakeParent::default();
+        *parent.children.borrow_mut() = [START].iter().chain(a).chain(&[END]).copied().collect();
+        let a = a.iter().chain(&[END]).copied().collect::<Vec<_>>();
+        let b = b.iter().chain(&[END]).copied().collect::<Vec<_>>();
+
+        reconcile(&parent, &a, &b);
+
+        let expected = [START].iter().chain(&b).copied().collect::<Vec<_>>();
+        assert_eq!(*parent.children.borrow(), expected);
+        parent.moves.get()
+    }
+
+    #[test]
+    fn longest_increasing_subsequence_skips_missing() {
+        let seq = [Some(3), None, Some(1), Some(2), Some(0), Some(4)];
+        assert_eq!(
+            longest_increasing_subsequence(&seq),
+            [false, false, true, true, false, true]
+        );
+        assert_eq!(
+            longest_increasing_subsequence(&[None, None]),
+            [false, false]
+        );
+    }
+
+    #[test]
+    fn reconcile_minimal_moves() {
+        let list = (0..10).collect::<Vec<_>>();
+        let mut swapped = list.clone();
+        swapped.swap(1, 8);
+        assert_eq!(check_reconcile(&list, &swapped), 2);
+
+        let mut rotated = list.clone();
+        rotated.rotate_left(1);
+        assert_eq!(check_reconcile(&list, &rotated), 1);
+
+        let reversed = list.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(check_reconcile(&list, &reversed), 9);
+
+        let mut updated = list.clone();
+        updated[3] = 100;
+        updated[6] = 101;
+        assert_eq!(check_reconcile(&list, &updated), 0);
+    }
+
+    #[test]
+    fn reconcile_random_lists() {
+        // xorshift, so that the test is deterministic.
+        let mut state = 0x2545_f491_u32;
+        let mut rand = move |n: u32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % n
+        };
+
+        for _ in 0..1000 {
+            let mut a = (0..rand(20)).collect::<Vec<_>>();
+            // Shuffle.
+            for i in (1..a.len()).rev() {
+                a.swap(i, rand(i as u32 + 1) as usize);
             }
-            b_start = b_end;
-        } else if b_end == b_start {
-            // Remove.
-            for node in &a[a_start..a_end] {
-                if map.is_none() || !map.as_ref().unwrap().contains_key(&HashableNode::new(node)) {
-                    parent.remove_child(node).unwrap();
+            // Remove, insert and move some nodes.
+            let mut b = a.clone();
+            for _ in 0..rand(5) {
+                if !b.is_empty() {
+                    b.remove(rand(b.len() as u32) as usize);
                 }
             }
-            a_start = a_end;
-        } else if a[a_start] == b[b_start] {
-            // Common prefix.
-            a_start += 1;
-            b_start += 1;
-        } else if a[a_end - 1] == b[b_end - 1] {
-            // Common suffix.
-            a_end -= 1;
-            b_end -= 1;
-        } else if a[a_start] == b[b_end - 1] && b[b_start] == a[a_end - 1] {
-            // Swap backwards.
-            let node = a[a_end - 1].next_sibling();
-            parent
-                .insert_before(&b[b_start], a[a_start].next_sibling().as_ref())
-                .unwrap();
-            parent.insert_before(&b[b_end - 1], node.as_ref()).unwrap();
-            a_start += 1;
-            b_start += 1;
-            a_end -= 1;
-            b_end -= 1;
-            a[a_end] = b[b_end].clone();
-        } else {
-            // Fallback to map.
-            if map.is_none() {
-                let tmp = b[b_start..b_end]
-                    .iter()
-                    .enumerate()
-                    .map(|(i, g)| (HashableNode::new(g), i))
-                    .collect();
-                map = Some(tmp);
+            for next in 100..100 + rand(5) {
+                b.insert(rand(b.len() as u32 + 1) as usize, next);
             }
-            let map = map.as_ref().unwrap();
-
-            if let Some(&index) = map.get(&HashableNode::new(&a[a_start])) {
-                if b_start < index && index < b_end {
-                    let mut i = a_start;
-                    let mut sequence = 1;
-                    let mut t;
-
-                    while i + 1 < a_end && i + 1 < b_end {
-                        i += 1;
-                        t = map.get(&HashableNode::new(&a[i])).copied();
-                        if t != Some(index + sequence) {
-                            break;
-                        }
-                        sequence += 1;
-                    }
-
-                    if sequence > index - b_start {
-                        let node = &a[a_start];
-                        while b_start < index {
-                            parent.insert_before(&b[b_start], Some(node)).unwrap();
-                            b_start += 1;
-                        }
-                    } else {
-                        parent.replace_child(&b[b_start], &a[a_start]).unwrap();
-                        a_start += 1;
-                        b_start += 1;
-                    }
-                } else {
-                    a_start += 1;
+            for _ in 0..rand(5) {
+                if !b.is_empty() {
+                    let node = b.remove(rand(b.len() as u32) as usize);
+                    b.insert(rand(b.len() as u32 + 1) as usize, node);
                 }
-            } else {
-                parent.remove_child(&a[a_start]).unwrap();
-                a_start += 1;
             }
+
+            check_reconcile(&a, &b);
         }
     }
 
-    // Sanity check: make sure all nodes in b are children of parent after reconciliation.
     #[cfg(debug_assertions)]
-    {
-        for (i, node) in b.iter().enumerate() {
-            if node.parent_node().as_ref() != Some(parent) {
-                panic!(
-                    "node {i} in new nodes Vec is not a child of parent after reconciliation. node = {node:#?}",
-                );
-            }
-        }
+    #[test]
+    fn detect_reordered_list() {
+        assert!(is_reordered(&[1, 2, 3], &[3, 1, 2]));
+        assert!(is_reordered(&[1, 1, 2], &[1, 2, 1]));
+        assert!(!is_reordered(&[1, 2, 3], &[1, 2, 3]));
+        assert!(!is_reordered(&[1, 2, 3], &[1, 2, 4]));
+        assert!(!is_reordered(&[1, 1, 2], &[1, 2, 2]));
+        assert!(!is_reordered(&[1, 2], &[2, 1, 3]));
+
+        let long = (0..100_000).collect::<Vec<_>>();
+        let reversed = long.iter().rev().copied().collect::<Vec<_>>();
+        assert!(is_reordered(&long, &reversed));
     }
 }
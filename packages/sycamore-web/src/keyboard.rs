@@ -0,0 +1,217 @@
+//! Global keyboard shortcuts.
+
+use web_sys::KeyboardEvent;
+
+use crate::*;
+
+/// Options for [`use_keydown_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeydownOptions {
+    /// Whether to call the handler for repeated events when the key is held down. Disabled by
+    /// default.
+    pub repeat: bool,
+    /// Whether to call [`Event::prevent_default`](web_sys::Event::prevent_default) when the
+    /// shortcut matches, e.g. to prevent `ctrl+s` from opening the browser's save dialog.
+    /// Disabled by default.
+    pub prevent_default: bool,
+}
+
+/// A parsed key combination such as `ctrl+shift+k`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyCombo {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    /// The lowercase name of the key.
+    key: String,
+}
+
+impl KeyCombo {
+    fn parse(combo: &str) -> Result<Self, String> {
+        let mut parsed = Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: String::new(),
+        };
+        let parts = combo.split('+').map(|part| part.trim().to_lowercase());
+        for part in parts {
+            if !parsed.key.is_empty() {
+                return Err(format!("expected the key to be last in `{combo}`"));
+            }
+            match part.as_str() {
+                "ctrl" | "control" => parsed.ctrl = true,
+                "alt" | "option" => parsed.alt = true,
+                "shift" => parsed.shift = true,
+                "meta" | "cmd" | "command" | "super" => parsed.meta = true,
+                "" => return Err(format!("missing key in `{combo}`")),
+                "esc" => parsed.key = "escape".to_string(),
+                "space" => parsed.key = " ".to_string(),
+                "plus" => parsed.key = "+".to_string(),
+                "up" | "down" | "left" | "right" => parsed.key = format!("arrow{part}"),
+                _ => parsed.key = part,
+            }
+        }
+        if parsed.key.is_empty() {
+            return Err(format!("missing key in `{combo}`"));
+        }
+        Ok(parsed)
+    }
+
+    /// Checks if the combination matches a key event with the given `key` and `code` (see
+    /// [`KeyboardEvent::key`] and [`KeyboardEvent::code`]) and modifiers.
+    ///
+    /// If a modifier turns a letter or digit into a different character (e.g. `alt+k` produces
+    /// `˚` on macOS), the physical key code is checked instead. This is not done if `key` is a
+    /// letter or digit itself, so that the keyboard layout is respected.
+    fn matches(&self, key: &str, code: &str, [ctrl, alt, shift, meta]: [bool; 4]) -> bool {
+        if [ctrl, alt, shift, meta] != [self.ctrl, self.alt, self.shift, self.meta] {
+            return false;
+        }
+        if key.to_lowercase() == self.key {
+            return true;
+        }
+        if key.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return false;
+        }
+        let mut chars = self.key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => {
+                code == format!("Key{}", c.to_ascii_uppercase())
+            }
+            (Some(c), None) if c.is_ascii_digit() => code == format!("Digit{c}"),
+            _ => false,
+        }
+    }
+}
+
+/// Calls `handler` whenever the key combination `combo` is pressed anywhere in the document.
+///
+/// The combination is a list of modifiers followed by a key, separated by `+`, e.g. `"escape"`,
+/// `"ctrl+k"` or `"meta+shift+p"`. The modifiers are `ctrl`, `alt`, `shift` and `meta` (also
+/// `cmd`). The key is matched case-insensitively against
+/// [`KeyboardEvent.key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key),
+/// with `esc`, `space`, `plus`, `up`, `down`, `left` and `right` as aliases. The modifiers must
+/// match exactly, so `"ctrl+k"` does not match `ctrl+shift+k`.
+///
+/// Repeated events from holding down the keys are ignored. To change this, or to prevent the
+/// default action of the shortcut, use [`use_keydown_with_options`].
+///
+/// The listener is removed when the current reactive scope is disposed. In SSR mode, this does
+/// nothing.
+///
+/// # Panics
+/// Panics if `combo` is not a valid key combination.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_keydown;
+/// #[component]
+/// fn CommandPalette() -> View {
+///     let open = create_signal(false);
+///     use_keydown("meta+k", move |_| open.set(true));
+///     use_keydown("esc", move |_| open.set(false));
+///     view! {
+///         (if open.get() { view! { div(class="palette") { "..." } } } else { view! {} })
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn use_keydown(combo: &str, handler: impl Fn(KeyboardEvent) + 'static) {
+    use_keydown_with_options(combo, KeydownOptions::default(), handler);
+}
+
+/// Like [`use_keydown`], but with additional [`KeydownOptions`].
+///
+/// # Panics
+/// Panics if `combo` is not a valid key combination.
+#[track_caller]
+pub fn use_keydown_with_options(
+    combo: &str,
+    options: KeydownOptions,
+    handler: impl Fn(KeyboardEvent) + 'static,
+) {
+    let combo = match KeyCombo::parse(combo) {
+        Ok(combo) => combo,
+        Err(err) => panic!("invalid key combination: {err}"),
+    };
+    if is_ssr!() {
+        let _ = (options, handler);
+        return;
+    }
+
+    let listener: Closure<dyn Fn(KeyboardEvent)> = Closure::new(move |event: KeyboardEvent| {
+        if event.repeat() && !options.repeat {
+            return;
+        }
+        let modifiers = [
+            event.ctrl_key(),
+            event.alt_key(),
+            event.shift_key(),
+            event.meta_key(),
+        ];
+        if combo.matches(&event.key(), &event.code(), modifiers) {
+            if options.prevent_default {
+                event.prevent_default();
+            }
+            untrack(|| handler(event));
+        }
+    });
+    document()
+        .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        document()
+            .remove_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NONE: [bool; 4] = [false; 4];
+    const CTRL: [bool; 4] = [true, false, false, false];
+
+    #[test]
+    fn parse_key_combo() {
+        let combo = KeyCombo::parse("Meta+Shift+P").unwrap();
+        assert!(combo.meta && combo.shift && !combo.ctrl && !combo.alt);
+        assert_eq!(combo.key, "p");
+        assert_eq!(KeyCombo::parse("esc").unwrap().key, "escape");
+        assert_eq!(KeyCombo::parse("ctrl + up").unwrap().key, "arrowup");
+
+        assert!(KeyCombo::parse("").is_err());
+        assert!(KeyCombo::parse("ctrl+").is_err());
+        assert!(KeyCombo::parse("ctrl+shift").is_err());
+        assert!(KeyCombo::parse("k+ctrl").is_err());
+    }
+
+    #[test]
+    fn key_combo_matches() {
+        let combo = KeyCombo::parse("ctrl+k").unwrap();
+        assert!(combo.matches("k", "KeyK", CTRL));
+        assert!(combo.matches("K", "KeyK", CTRL));
+        assert!(!combo.matches("k", "KeyK", NONE));
+        assert!(!combo.matches("k", "KeyK", [true, false, true, false]));
+        assert!(!combo.matches("j", "KeyJ", CTRL));
+
+        // The physical key is used if the key value is changed by a modifier.
+        let combo = KeyCombo::parse("alt+k").unwrap();
+        assert!(combo.matches("˚", "KeyK", [false, true, false, false]));
+        let combo = KeyCombo::parse("shift+1").unwrap();
+        assert!(combo.matches("!", "Digit1", [false, false, true, false]));
+        // On an AZERTY keyboard, the `a` key is where `q` is on a QWERTY keyboard.
+        let combo = KeyCombo::parse("ctrl+q").unwrap();
+        assert!(combo.matches("q", "KeyA", CTRL));
+        assert!(!combo.matches("a", "KeyQ", CTRL));
+
+        let combo = KeyCombo::parse("escape").unwrap();
+        assert!(combo.matches("Escape", "Escape", NONE));
+    }
+}
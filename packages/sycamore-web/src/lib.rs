@@ -8,6 +8,9 @@
 //! - `hydrate` - Enables hydration support in DOM node. By default, hydration is disabled to reduce
 //!   binary size.
 //!
+//! - `serde` - Enables deserializing the values of a `<form>` into a struct and signals that are
+//!   persisted to `localStorage`.
+//!
 //! - `suspense` - Enables suspense and resources support.
 //!
 //! - `wasm-bindgen-interning` (_default_) - Enables interning for `wasm-bindgen` strings. This
@@ -40,33 +43,69 @@ pub mod utils;
 
 mod attributes;
 mod components;
+mod custom_element;
+mod dataset;
 mod elements;
+mod error_boundary;
+#[cfg(feature = "serde")]
+mod forms;
+#[cfg(feature = "serde")]
+mod hydration_state;
+mod intersection;
 mod iter;
+mod keyboard;
 mod macros;
+mod media_query;
 mod node;
 mod noderef;
 mod portal;
+mod raf;
+mod resize;
 #[cfg(feature = "suspense")]
 mod resource;
+mod route_transition;
 mod stable_counter;
+#[cfg(feature = "serde")]
+mod storage;
 #[cfg(feature = "suspense")]
 mod suspense;
+mod timers;
+mod websocket;
 
 pub(crate) mod view;
+mod virtual_list;
 
 pub use self::attributes::*;
 pub use self::components::*;
+pub use self::custom_element::*;
+pub use self::dataset::*;
 pub use self::elements::*;
+pub use self::error_boundary::*;
+#[cfg(feature = "serde")]
+pub use self::forms::*;
+#[cfg(feature = "serde")]
+pub use self::hydration_state::*;
+pub use self::intersection::*;
 pub use self::iter::*;
+pub use self::keyboard::*;
+pub use self::media_query::*;
 pub use self::node::*;
 pub use self::noderef::*;
 pub use self::portal::*;
+pub use self::raf::*;
+pub use self::resize::*;
 #[cfg(feature = "suspense")]
 pub use self::resource::*;
+pub use self::route_transition::*;
 pub use self::stable_counter::*;
+#[cfg(feature = "serde")]
+pub use self::storage::*;
 #[cfg(feature = "suspense")]
 pub use self::suspense::*;
+pub use self::timers::*;
 pub use self::view::*;
+pub use self::virtual_list::*;
+pub use self::websocket::*;
 
 /// We add this to make the macros from `sycamore-macro` work properly.
 extern crate self as sycamore;
@@ -83,7 +122,9 @@ pub mod rt {
 
     #[cfg(feature = "suspense")]
     pub use crate::WrapAsync;
-    pub use crate::{bind, custom_element, tags, View};
+    pub use crate::{
+        bind, custom_element, events, tag, tags, CustomElementProps, FromAttribute, View,
+    };
 }
 
 /// Re-export of `js-sys` and `wasm-bindgen` for convenience.
@@ -188,6 +229,49 @@ pub fn create_client_effect(f: impl FnMut() + 'static) {
     }
 }
 
+/// Create a new effect whose first run is deferred to the next microtask.
+///
+/// This is useful for effects that touch the DOM, since nodes created during setup are not yet
+/// mounted when [`create_effect`] runs the effect for the first time. Dependencies are tracked
+/// starting from the first (deferred) run. After that, the effect is re-run synchronously whenever
+/// its dependencies change, just like a normal effect.
+///
+/// If the current reactive scope is disposed before the microtask runs, the effect is never
+/// created nor run. If not on `wasm32` target, does nothing.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # fn Comp() -> View {
+/// let node_ref = create_node_ref();
+/// let text = create_signal(String::new());
+/// create_effect_deferred(move || {
+///     // The node is mounted by the time this runs.
+///     node_ref.get().set_text_content(Some(&text.get_clone()));
+/// });
+/// view! {
+///     p(r#ref=node_ref)
+/// }
+/// # }
+/// ```
+pub fn create_effect_deferred(f: impl FnMut() + 'static) {
+    if cfg!(target_arch = "wasm32") {
+        let is_alive = Rc::new(Cell::new(true));
+        on_cleanup({
+            let is_alive = Rc::clone(&is_alive);
+            move || is_alive.set(false)
+        });
+
+        let scope = use_current_scope();
+        let cb = move || {
+            if is_alive.get() {
+                scope.run_in(|| create_effect(f));
+            }
+        };
+        queue_microtask(cb);
+    }
+}
+
 /// Queue up a callback to be executed when the component is mounted.
 ///
 /// If not on `wasm32` target, does nothing.
@@ -214,6 +298,35 @@ pub fn on_mount(f: impl FnOnce() + 'static) {
     }
 }
 
+/// Queue up a callback to be executed when the enclosing reactive scope is disposed.
+///
+/// This is an alias for [`on_cleanup`], meant to be used as the counterpart of [`on_mount`].
+/// Components do not have their own reactive scope, so the callback runs when the enclosing scope
+/// is disposed, either when the whole view is disposed or when the component is part of a dynamic
+/// view (e.g. an `if` block) that is re-rendered. This is not tied to the nodes being removed from
+/// the DOM, so the callback may run before or after that happens. Signals created in the component
+/// can still be accessed in the callback.
+///
+/// Unlike [`on_mount`], this also runs outside of `wasm32` targets.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// #[component]
+/// fn Editor() -> View {
+///     let draft = create_signal(String::new());
+///     on_unmount(move || {
+///         // Save `draft.get_clone()` somewhere.
+///     });
+///     view! {
+///         textarea(bind:value=draft)
+///     }
+/// }
+/// ```
+pub fn on_unmount(f: impl FnOnce() + 'static) {
+    on_cleanup(f);
+}
+
 /// Alias for `queueMicrotask`.
 pub fn queue_microtask(f: impl FnOnce() + 'static) {
     #[wasm_bindgen]
@@ -237,3 +350,21 @@ pub fn document() -> web_sys::Document {
     }
     DOCUMENT.with(Clone::clone)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_unmount_runs_when_scope_is_disposed() {
+        let unmounted = Rc::new(Cell::new(false));
+        let root = create_root({
+            let unmounted = Rc::clone(&unmounted);
+            move || on_unmount(move || unmounted.set(true))
+        });
+        assert!(!unmounted.get());
+
+        root.dispose();
+        assert!(unmounted.get());
+    }
+}
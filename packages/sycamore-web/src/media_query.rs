@@ -0,0 +1,84 @@
+//! Reactive media queries.
+
+use crate::*;
+
+/// Returns a signal that is `true` while the CSS media query `query` matches, e.g.
+/// `"(prefers-color-scheme: dark)"` or `"(min-width: 768px)"`.
+///
+/// The signal is updated whenever the result of the query changes. The listener is removed when
+/// the current reactive scope is disposed.
+///
+/// In SSR mode, the signal is always `false`. To use a different value, e.g. for rendering the
+/// mobile layout on the server, use [`use_media_query_with_default`].
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_media_query;
+/// #[component]
+/// fn Logo() -> View {
+///     let dark = use_media_query("(prefers-color-scheme: dark)");
+///     view! {
+///         img(src=move || if dark.get() { "logo-dark.svg" } else { "logo.svg" })
+///     }
+/// }
+/// ```
+pub fn use_media_query(query: &str) -> ReadSignal<bool> {
+    use_media_query_with_default(query, false)
+}
+
+/// Like [`use_media_query`], but with a value for the signal in SSR mode.
+///
+/// While hydrating, the signal also starts out as `ssr_default` so that the hydrated content
+/// matches the server-rendered HTML. It is updated with the result of the query once the
+/// component is mounted, so the content that depends on it may change right after hydration.
+pub fn use_media_query_with_default(query: &str, ssr_default: bool) -> ReadSignal<bool> {
+    if is_ssr!() {
+        let _ = query;
+        return *create_signal(ssr_default);
+    }
+
+    let media_query_list = match window().match_media(query) {
+        Ok(Some(media_query_list)) => media_query_list,
+        _ => panic!("invalid media query `{query}`"),
+    };
+    let matches = if IS_HYDRATING.get() {
+        let matches = create_signal(ssr_default);
+        on_mount({
+            let media_query_list = media_query_list.clone();
+            move || matches.set(media_query_list.matches())
+        });
+        matches
+    } else {
+        create_signal(media_query_list.matches())
+    };
+
+    let on_change: Closure<dyn Fn()> = Closure::new({
+        let media_query_list = media_query_list.clone();
+        move || matches.set(media_query_list.matches())
+    });
+    media_query_list
+        .add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        media_query_list
+            .remove_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+            .unwrap_throw();
+    });
+
+    *matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn media_query_uses_default_in_ssr() {
+        let _ = create_root(|| {
+            assert!(!use_media_query("(prefers-color-scheme: dark)").get());
+            assert!(use_media_query_with_default("(max-width: 600px)", true).get());
+        });
+    }
+}
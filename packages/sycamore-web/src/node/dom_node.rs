@@ -180,10 +180,37 @@ impl ViewHtmlNode for DomNode {
         on_cleanup(|| drop(cb));
     }
 
-    fn set_inner_html(&mut self, inner_html: Cow<'static, str>) {
+    fn set_event_handler_once(
+        &mut self,
+        name: Cow<'static, str>,
+        handler: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        let cb = Closure::wrap(Box::new(handler) as Box<dyn FnMut(_)>);
+        let options = web_sys::AddEventListenerOptions::new();
+        options.set_once(true);
         self.raw
-            .unchecked_ref::<web_sys::Element>()
-            .set_inner_html(&inner_html);
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                &name,
+                cb.as_ref().unchecked_ref(),
+                &options,
+            )
+            .unwrap();
+        on_cleanup(|| drop(cb));
+    }
+
+    fn set_inner_html(&mut self, inner_html: StringAttribute) {
+        if let Some(inner_html) = inner_html.as_static() {
+            if let Some(inner_html) = inner_html {
+                self.raw
+                    .unchecked_ref::<web_sys::Element>()
+                    .set_inner_html(inner_html);
+            }
+        } else {
+            let node = self.raw.clone().unchecked_into::<web_sys::Element>();
+            create_effect(move || {
+                node.set_inner_html(inner_html.get_clone().as_deref().unwrap_or_default())
+            });
+        }
     }
 
     fn as_web_sys(&self) -> &web_sys::Node {
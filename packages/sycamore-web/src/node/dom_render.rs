@@ -2,6 +2,27 @@ use super::*;
 
 /// Render a [`View`] into the DOM.
 /// Alias for [`render_to`] with `parent` being the `<body>` tag.
+///
+/// `view` is called in the root scope, so contexts that are provided in it before the view is
+/// created are available to the whole app.
+///
+/// # Example
+/// ```no_run
+/// # use sycamore::prelude::*;
+/// #[derive(Clone, Copy)]
+/// struct ApiUrl(&'static str);
+///
+/// #[component]
+/// fn App() -> View {
+///     let api_url = use_context::<ApiUrl>();
+///     view! { p { (api_url.0) } }
+/// }
+///
+/// sycamore::render(|| {
+///     provide_context(ApiUrl("https://example.com/api"));
+///     view! { App {} }
+/// });
+/// ```
 pub fn render(view: impl FnOnce() -> View) {
     render_to(view, &document().body().unwrap());
 }
@@ -107,6 +128,8 @@ pub fn hydrate_in_scope(view: impl FnOnce() -> View, parent: &web_sys::Node) {
         IS_HYDRATING.set(true);
         provide_context(mode);
         provide_context(HydrationRegistry::new());
+        #[cfg(feature = "serde")]
+        provide_context(HydrationState::from_document());
         let nodes = view().nodes;
         // We need to append `nodes` to the `parent` so that the top level nodes also get properly
         // hydrated.
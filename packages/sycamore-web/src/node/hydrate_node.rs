@@ -63,7 +63,6 @@ impl ViewNode for HydrateNode {
                     // Noop for hydration since node is already in right place.
                 }
                 NodeState::TextDynamic(node) => {
-                    console_warn!("text dynamic");
                     // Search self for an empty comment node. Once found, the next node should be
                     // the text node. Hydrate the text node and remove the comment node.
                     let mut next = self.as_web_sys().first_child();
@@ -72,6 +71,7 @@ impl ViewNode for HydrateNode {
                             let comment = current.unchecked_ref::<web_sys::Comment>();
                             if comment.text_content().unwrap() == "t" {
                                 let text_node = comment.next_sibling().unwrap();
+                                check_text(self.as_web_sys(), &text_node, node.as_web_sys());
                                 self.as_web_sys()
                                     .replace_child(&node.as_web_sys(), &text_node)
                                     .unwrap();
@@ -81,7 +81,10 @@ impl ViewNode for HydrateNode {
                         }
                         next = current.next_sibling();
                     }
-                    panic!("text node not found during hydration");
+                    panic!(
+                        "hydration mismatch: dynamic text node not found in `{}`",
+                        describe_node(self.as_web_sys())
+                    );
                 }
                 NodeState::TextStatic => {
                     // Noop for hydration.
@@ -119,30 +122,151 @@ impl ViewNode for HydrateNode {
     }
 }
 
+/// Checks that the node that was rendered on the server with the hydration key `key` is an
+/// element with the tag name and the `data-hk` attribute that the client expects. Logs an error
+/// otherwise, since the rest of the subtree is most likely out of sync as well.
 fn check_node(node: &web_sys::Node, tag: &str, key: HydrationKey) {
     if cfg!(debug_assertions) {
+        if node.node_type() != web_sys::Node::ELEMENT_NODE {
+            console_error!(
+                "hydration mismatch at `{key}`: expected element `<{tag}>`, got {}",
+                describe_node(node)
+            );
+            return;
+        }
         let node = node.unchecked_ref::<web_sys::Element>();
         node.set_attribute("data-hydrated", "").unwrap();
 
-        assert_eq!(node.node_type(), web_sys::Node::ELEMENT_NODE);
-        assert_eq!(node.get_attribute("data-hk"), Some(key.to_string()));
-        let actual_tag = node.tag_name().to_lowercase();
-        if actual_tag != tag {
+        // Compare the local name since the tag name of HTML elements is uppercase.
+        let actual_tag = node.local_name();
+        if !actual_tag.eq_ignore_ascii_case(tag) {
+            console_error!(
+                "hydration mismatch at `{key}`: expected element `<{tag}>`, got `<{actual_tag}>`"
+            );
+        }
+        if node.get_attribute("data-hk") != Some(key.to_string()) {
+            console_error!(
+                "hydration mismatch at `{key}`: expected element `<{tag} data-hk=\"{key}\">`, got \
+                 `{}`",
+                describe_node(node)
+            );
+        }
+    }
+}
+
+/// Checks that the text of a dynamic text node that was rendered on the server matches the text
+/// that was computed on the client.
+fn check_text(parent: &web_sys::Node, server: &web_sys::Node, client: &web_sys::Node) {
+    if cfg!(debug_assertions) {
+        let expected = client.text_content().unwrap_or_default();
+        if server.node_type() != web_sys::Node::TEXT_NODE {
+            console_error!(
+                "hydration mismatch in `{}`: expected text '{expected}', got {}",
+                describe_node(parent),
+                describe_node(server)
+            );
+        } else {
+            let actual = server.text_content().unwrap_or_default();
+            if actual != expected {
+                console_error!(
+                    "hydration mismatch in `{}`: expected text '{expected}', got '{actual}'",
+                    describe_node(parent)
+                );
+            }
+        }
+    }
+}
+
+/// Describes a node for hydration diagnostics, e.g. `<div data-hk="0.1">` or `text 'Hello'`.
+fn describe_node(node: &web_sys::Node) -> String {
+    match node.node_type() {
+        web_sys::Node::ELEMENT_NODE => {
+            let element = node.unchecked_ref::<web_sys::Element>();
+            match element.get_attribute("data-hk") {
+                Some(key) => format!("<{} data-hk=\"{key}\">", element.local_name()),
+                None => format!("<{}>", element.local_name()),
+            }
+        }
+        web_sys::Node::TEXT_NODE => {
+            format!("text '{}'", node.text_content().unwrap_or_default())
+        }
+        web_sys::Node::COMMENT_NODE => {
+            format!("comment '{}'", node.text_content().unwrap_or_default())
+        }
+        _ => node.node_name(),
+    }
+}
+
+/// Checks that the value of a `class` or `style` attribute computed on the client matches the
+/// value that was rendered on the server. These attributes are often computed from client-only
+/// state, which makes them the most common source of hydration mismatches.
+///
+/// Since the attribute is not updated until the value changes, a mismatch means that the DOM is
+/// out of sync with the client state.
+fn check_attribute(node: &web_sys::Element, name: &str, value: Option<&str>) {
+    if cfg!(debug_assertions) && (name == "class" || name == "style") {
+        let actual = node.get_attribute(name);
+        let (actual_str, value_str) = (
+            actual.as_deref().unwrap_or_default(),
+            value.unwrap_or_default(),
+        );
+        let matches = if name == "class" {
+            normalize_class(actual_str) == normalize_class(value_str)
+        } else {
+            normalize_style(actual_str) == normalize_style(value_str)
+        };
+        if !matches {
+            let key = node.get_attribute("data-hk").unwrap_or_default();
             console_warn!(
-                "hydration tag name mismatch at `{key}`: expected '{tag}', got '{actual_tag}'"
+                "hydration `{name}` attribute mismatch at `{key}`: expected '{value_str}', got \
+                 '{actual_str}'"
             );
         }
     }
 }
 
+/// Splits a `class` attribute into a sorted list of classes. The order of classes does not matter.
+fn normalize_class(class: &str) -> Vec<&str> {
+    let mut classes = class.split_whitespace().collect::<Vec<_>>();
+    classes.sort_unstable();
+    classes
+}
+
+/// Splits a `style` attribute into a list of `property:value` declarations without any extra
+/// whitespace.
+fn normalize_style(style: &str) -> Vec<String> {
+    style
+        .split(';')
+        .map(str::trim)
+        .filter(|decl| !decl.is_empty())
+        .map(|decl| match decl.split_once(':') {
+            Some((property, value)) => format!("{}:{}", property.trim(), value.trim()),
+            None => decl.to_string(),
+        })
+        .collect()
+}
+
+/// Removes the node with the hydration key `key` from [`HYDRATE_NODES`].
+///
+/// # Panics
+/// Panics if there is no such node, i.e. if the client renders more elements than the server.
+fn take_hydrate_node(key: HydrationKey, tag: &str) -> HydrateNode {
+    HYDRATE_NODES
+        .with(|nodes| nodes.borrow_mut().remove(&key))
+        .unwrap_or_else(|| {
+            panic!(
+                "hydration mismatch at `{key}`: expected element `<{tag}>` but the server did not \
+                 render an element with this key"
+            )
+        })
+}
+
 impl ViewHtmlNode for HydrateNode {
     fn create_element(tag: Cow<'static, str>) -> Self {
         if IS_HYDRATING.get() {
             let reg: HydrationRegistry = use_context();
             let key = reg.next_key();
-            let node = HYDRATE_NODES
-                .with(|nodes| nodes.borrow_mut().remove(&key))
-                .unwrap_or_else(|| panic!("node with hk `{key}` not found"));
+            let node = take_hydrate_node(key, &tag);
             check_node(node.as_web_sys(), &tag, key);
             node
         } else {
@@ -154,15 +278,8 @@ impl ViewHtmlNode for HydrateNode {
         if IS_HYDRATING.get() {
             let reg: HydrationRegistry = use_context();
             let key = reg.next_key();
-            let node = HYDRATE_NODES
-                .with(|nodes| nodes.borrow_mut().remove(&key))
-                .unwrap_or_else(|| panic!("node with hk `{key}` not found"));
-            if cfg!(debug_assertions) {
-                node.as_web_sys()
-                    .unchecked_ref::<web_sys::Element>()
-                    .set_attribute("data-hydrated", "")
-                    .unwrap();
-            }
+            let node = take_hydrate_node(key, &tag);
+            check_node(node.as_web_sys(), &tag, key);
             node
         } else {
             Self(NodeState::Hydrated(DomNode::create_element_ns(
@@ -195,7 +312,13 @@ impl ViewHtmlNode for HydrateNode {
     fn set_attribute(&mut self, name: Cow<'static, str>, value: StringAttribute) {
         // FIXME: use setAttributeNS if SVG
         if IS_HYDRATING.get() {
-            // Noop if value is static since attributes are already set.
+            if cfg!(debug_assertions) {
+                let value = untrack(|| value.get_clone());
+                check_attribute(self.as_web_sys().unchecked_ref(), &name, value.as_deref());
+            }
+            // Noop if value is static since attributes are already set. If the value is dynamic,
+            // the attribute is only updated once the value changes so that the SSR-ed value is
+            // kept as is.
             if value.as_static().is_none() {
                 let node = self
                     .as_web_sys()
@@ -256,9 +379,36 @@ impl ViewHtmlNode for HydrateNode {
         self.0.unwrap_mut().set_event_handler(name, handler);
     }
 
-    fn set_inner_html(&mut self, inner_html: Cow<'static, str>) {
-        // If we are hydrating, inner HTML should already be set.
-        if !IS_HYDRATING.get() {
+    fn set_event_handler_once(
+        &mut self,
+        name: Cow<'static, str>,
+        handler: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.0.unwrap_mut().set_event_handler_once(name, handler);
+    }
+
+    fn set_inner_html(&mut self, inner_html: StringAttribute) {
+        // If we are hydrating, inner HTML should already be set. The raw subtree is not hydrated.
+        // If the value is dynamic, the inner HTML is only replaced once the value changes.
+        if IS_HYDRATING.get() {
+            if inner_html.as_static().is_none() {
+                let node = self
+                    .as_web_sys()
+                    .clone()
+                    .unchecked_into::<web_sys::Element>();
+                create_effect_initial(move || {
+                    let _ = inner_html.track(); // Track dependencies of value.
+                    (
+                        Box::new(move || {
+                            node.set_inner_html(
+                                inner_html.get_clone().as_deref().unwrap_or_default(),
+                            )
+                        }),
+                        (),
+                    )
+                });
+            }
+        } else {
             self.0.unwrap_mut().set_inner_html(inner_html);
         }
     }
@@ -61,8 +61,16 @@ pub trait ViewHtmlNode: ViewNode {
         name: Cow<'static, str>,
         handler: impl FnMut(web_sys::Event) + 'static,
     );
+    /// Set an event handler on an element that is removed after the first event.
+    fn set_event_handler_once(
+        &mut self,
+        name: Cow<'static, str>,
+        handler: impl FnMut(web_sys::Event) + 'static,
+    ) {
+        self.set_event_handler(name, handler);
+    }
     /// Set the inner HTML value of an element.
-    fn set_inner_html(&mut self, inner_html: Cow<'static, str>);
+    fn set_inner_html(&mut self, inner_html: StringAttribute);
 
     /// Return the raw web-sys node.
     fn as_web_sys(&self) -> &web_sys::Node;
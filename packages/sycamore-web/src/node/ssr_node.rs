@@ -15,6 +15,10 @@ pub enum SsrNode {
         // NOTE: This field is boxed to avoid allocating memory for a field that is rarely used.
         inner_html: Option<Box<Cow<'static, str>>>,
         hk_key: Option<HydrationKey>,
+        /// The values of the options that are rendered as selected if this is a `<select>`
+        /// element.
+        // NOTE: This field is boxed for the same reason as `inner_html`.
+        selected_values: Option<Box<Vec<String>>>,
     },
     TextDynamic {
         text: Arc<Mutex<String>>,
@@ -41,7 +45,15 @@ impl From<SsrNode> for View<SsrNode> {
 impl ViewNode for SsrNode {
     fn append_child(&mut self, child: Self) {
         match self {
-            Self::Element { children, .. } => {
+            Self::Element {
+                children,
+                selected_values,
+                ..
+            } => {
+                let mut child = child;
+                if let Some(values) = selected_values {
+                    child.select_options(values);
+                }
                 children.push(child);
             }
             _ => panic!("can only append child to an element"),
@@ -84,6 +96,73 @@ impl ViewNode for SsrNode {
     }
 }
 
+impl SsrNode {
+    /// Returns the value of the attribute `name` if it has been set on this element.
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        match self {
+            Self::Element { attributes, .. } => attributes
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Renders the `<option>` children of this `<select>` element whose value is one of `values`
+    /// with the `selected` attribute. This also applies to options that are appended later.
+    pub(crate) fn set_selected_values(&mut self, values: Vec<String>) {
+        if let Self::Element {
+            children,
+            selected_values,
+            ..
+        } = self
+        {
+            for child in children.iter_mut() {
+                child.select_options(&values);
+            }
+            *selected_values = Some(Box::new(values));
+        }
+    }
+
+    /// Sets the `selected` attribute on this node if it is an `<option>` whose value is one of
+    /// `values`, or on the options inside it otherwise.
+    fn select_options(&mut self, values: &[String]) {
+        match self {
+            Self::Element {
+                tag,
+                attributes,
+                bool_attributes,
+                children,
+                ..
+            } if tag == "option" => {
+                // Options without a `value` use their text as the value.
+                let value = match attributes.iter().find(|(name, _)| name == "value") {
+                    Some((_, value)) => value.to_string(),
+                    None => {
+                        let mut text = String::new();
+                        for child in children.iter() {
+                            render_raw_text_recursive(child, &mut text);
+                        }
+                        text
+                    }
+                };
+                bool_attributes.push(("selected".into(), values.contains(&value)));
+            }
+            Self::Element { children, .. } => {
+                for child in children {
+                    child.select_options(values);
+                }
+            }
+            Self::Dynamic { view } => {
+                for node in &mut view.lock().unwrap().nodes {
+                    node.select_options(values);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 impl ViewHtmlNode for SsrNode {
     fn create_element(tag: Cow<'static, str>) -> Self {
         let hk_key = if IS_HYDRATING.get() {
@@ -99,6 +178,7 @@ impl ViewHtmlNode for SsrNode {
             children: Vec::new(),
             inner_html: None,
             hk_key,
+            selected_values: None,
         }
     }
 
@@ -125,7 +205,23 @@ impl ViewHtmlNode for SsrNode {
         match self {
             Self::Element { attributes, .. } => {
                 if let Some(value) = value.evaluate() {
-                    attributes.push((name, value))
+                    // Merge classes and styles instead of rendering the attribute twice, e.g. when
+                    // using both `class` and `class_list`.
+                    let separator = match name.as_ref() {
+                        "class" => Some(" "),
+                        "style" => Some("; "),
+                        _ => None,
+                    };
+                    let existing = separator.and_then(|separator| {
+                        let (_, existing) = attributes.iter_mut().find(|(n, _)| *n == name)?;
+                        Some((existing, separator))
+                    });
+                    match existing {
+                        Some((existing, separator)) => {
+                            *existing = format!("{existing}{separator}{value}").into()
+                        }
+                        None => attributes.push((name, value)),
+                    }
                 }
             }
             _ => panic!("can only set attribute on an element"),
@@ -153,11 +249,11 @@ impl ViewHtmlNode for SsrNode {
         // Noop in SSR mode.
     }
 
-    fn set_inner_html(&mut self, inner_html: Cow<'static, str>) {
+    fn set_inner_html(&mut self, inner_html: StringAttribute) {
         match self {
             Self::Element {
                 inner_html: slot, ..
-            } => *slot = Some(Box::new(inner_html)),
+            } => *slot = inner_html.evaluate().map(Box::new),
             _ => panic!("can only set inner_html on an element"),
         }
     }
@@ -181,6 +277,11 @@ static VOID_ELEMENTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     .collect()
 });
 
+/// A list of all the raw text HTML elements. The text content of these elements is not parsed as
+/// HTML by the browser and therefore must not be escaped.
+static RAW_TEXT_ELEMENTS: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| ["script", "style"].into_iter().collect());
+
 /// Recursively render `node` by appending to `buf`.
 pub(crate) fn render_recursive(node: &SsrNode, buf: &mut String) {
     match node {
@@ -191,6 +292,7 @@ pub(crate) fn render_recursive(node: &SsrNode, buf: &mut String) {
             children,
             inner_html,
             hk_key,
+            ..
         } => {
             buf.push('<');
             buf.push_str(tag);
@@ -230,6 +332,10 @@ pub(crate) fn render_recursive(node: &SsrNode, buf: &mut String) {
                     "inner_html and children are mutually exclusive"
                 );
                 buf.push_str(inner_html);
+            } else if RAW_TEXT_ELEMENTS.contains(tag.as_ref()) {
+                for child in children {
+                    render_raw_text_recursive(child, buf);
+                }
             } else {
                 for child in children {
                     render_recursive(child, buf);
@@ -259,6 +365,24 @@ pub(crate) fn render_recursive(node: &SsrNode, buf: &mut String) {
     }
 }
 
+/// Recursively render the children of a raw text element (such as `<script>` or `<style>`).
+///
+/// Text is written out without escaping. Hydration markers are also omitted since they would
+/// otherwise be interpreted as part of the script or stylesheet.
+fn render_raw_text_recursive(node: &SsrNode, buf: &mut String) {
+    match node {
+        SsrNode::TextDynamic { text } => buf.push_str(text.lock().unwrap().as_str()),
+        SsrNode::TextStatic { text } => buf.push_str(text),
+        SsrNode::Marker => {}
+        SsrNode::Dynamic { view } => {
+            for node in &view.lock().unwrap().nodes {
+                render_raw_text_recursive(node, buf);
+            }
+        }
+        SsrNode::Element { .. } => render_recursive(node, buf),
+    }
+}
+
 /// Recursively render a [`View`] to a string by calling `render_recursive` on each node.
 pub(crate) fn render_recursive_view(view: &View, buf: &mut String) {
     for node in &view.nodes {
@@ -291,12 +415,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_raw_text_elements_unescaped() {
+        check(
+            move || style().children("p > a { content: \"&\"; }"),
+            expect![[r#"<style data-hk="0.0">p > a { content: "&"; }</style>"#]],
+        );
+        check(
+            move || script().children("if (a < b && b > c) {}"),
+            expect![[r#"<script data-hk="0.0">if (a < b && b > c) {}</script>"#]],
+        );
+        check(
+            move || {
+                let value = create_signal("a > b");
+                sycamore_macro::view! {
+                    script { (value) }
+                }
+            },
+            expect![[r#"<script data-hk="0.0">a > b</script>"#]],
+        );
+        // Text outside of raw text elements should still be escaped.
+        check(
+            move || (style().children("a > b"), p().children("a > b")),
+            expect![[r#"<style data-hk="0.0">a > b</style><p data-hk="0.1">a &gt; b</p>"#]],
+        );
+    }
+
     #[test]
     fn render_inner_html() {
         check(
             move || div().dangerously_set_inner_html("<p>hello</p>"),
             expect![[r#"<div data-hk="0.0"><p>hello</p></div>"#]],
         );
+        check(
+            move || {
+                let html = create_signal("<p>a > b</p>");
+                div().dangerously_set_inner_html(move || html.get())
+            },
+            expect![[r#"<div data-hk="0.0"><p>a > b</p></div>"#]],
+        );
+        check(
+            move || div().dangerously_set_inner_html(None::<String>),
+            expect![[r#"<div data-hk="0.0"></div>"#]],
+        );
     }
 
     #[test]
@@ -308,6 +469,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_class_list() {
+        check(
+            move || {
+                let is_active = create_signal(true);
+                div()
+                    .class("btn")
+                    .class_list(move || vec![("active", is_active.get()), ("disabled", false)])
+            },
+            expect![[r#"<div class="btn active" data-hk="0.0"></div>"#]],
+        );
+        check(
+            move || div().class_list(|| vec![("a", true), ("b", true)]),
+            expect![[r#"<div class="a b" data-hk="0.0"></div>"#]],
+        );
+        check(
+            move || div().class_list(|| vec![("a", false)]),
+            expect![[r#"<div data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn render_styles() {
+        check(
+            move || {
+                let width = create_signal(50);
+                div().style("color: red").styles(move || {
+                    vec![("width", format!("{}%", width.get())), ("top", "".into())]
+                })
+            },
+            expect![[r#"<div style="color: red; width: 50%" data-hk="0.0"></div>"#]],
+        );
+        check(
+            move || div().styles(|| vec![("--size", "2px")]),
+            expect![[r#"<div style="--size: 2px" data-hk="0.0"></div>"#]],
+        );
+    }
+
+    #[test]
+    fn render_bool_attributes() {
+        // Boolean attributes are rendered without a value when `true` and omitted when `false`.
+        check(
+            move || {
+                input()
+                    .disabled(true)
+                    .checked(true)
+                    .readonly(true)
+                    .required(true)
+                    .hidden(true)
+            },
+            expect![[r#"<input disabled checked readonly required hidden data-hk="0.0">"#]],
+        );
+        check(
+            move || {
+                input()
+                    .disabled(false)
+                    .checked(false)
+                    .readonly(false)
+                    .required(false)
+                    .hidden(false)
+            },
+            expect![[r#"<input data-hk="0.0">"#]],
+        );
+        check(
+            move || {
+                let on = create_signal(true);
+                let off = create_signal(false);
+                input()
+                    .disabled(on)
+                    .checked(off)
+                    .readonly(move || on.get())
+                    .required(move || off.get())
+            },
+            expect![[r#"<input disabled readonly data-hk="0.0">"#]],
+        );
+    }
+
     #[test]
     fn fragments() {
         check(
@@ -371,6 +609,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mathml_element() {
+        check(
+            move || {
+                sycamore_macro::view! {
+                    math {
+                        mfrac { mi { "x" } mn { "2" } }
+                    }
+                }
+            },
+            expect![[
+                r#"<math data-hk="0.0"><mfrac data-hk="0.1"><mi data-hk="0.2">x</mi><mn data-hk="0.3">2</mn></mfrac></math>"#
+            ]],
+        );
+    }
+
+    #[test]
+    fn render_dynamic_tag() {
+        check(
+            move || tag(format!("h{}", 2)).class("title").children("Heading"),
+            expect![[r#"<h2 class="title" data-hk="0.0">Heading</h2>"#]],
+        );
+        check(
+            move || {
+                let level = 3;
+                sycamore_macro::view! {
+                    (dyn_tag(format!("h{level}")))(id="heading") { "Heading" }
+                }
+            },
+            expect![[r#"<h3 id="heading" data-hk="0.0">Heading</h3>"#]],
+        );
+    }
+
     #[test]
     fn dynamic_text() {
         check(
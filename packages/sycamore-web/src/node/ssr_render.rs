@@ -19,6 +19,10 @@ pub enum SsrMode {
 }
 
 /// Render a [`View`] into a static [`String`]. Useful for rendering to a string on the server side.
+///
+/// `view` is called in a child scope of a new reactive root. Since this scope is an ancestor of
+/// the whole view, contexts can be provided in it before the view is created, like with
+/// [`render`](crate::render).
 #[must_use]
 pub fn render_to_string(view: impl FnOnce() -> View) -> String {
     is_not_ssr! {
@@ -56,6 +60,8 @@ pub fn render_to_string_in_scope(view: impl FnOnce() -> View) -> String {
 
         let handle = create_child_scope(|| {
             provide_context(HydrationRegistry::new());
+            #[cfg(feature = "serde")]
+            provide_context(HydrationState::new());
             provide_context(SsrMode::Sync);
 
             let prev = IS_HYDRATING.replace(true);
@@ -68,12 +74,60 @@ pub fn render_to_string_in_scope(view: impl FnOnce() -> View) -> String {
     }
 }
 
+/// Render a [`View`] of a whole HTML document into a static [`String`], prepended with a
+/// `<!doctype html>` declaration.
+///
+/// The root of the view should be an `html` element. Otherwise, a warning is printed and the
+/// result is the same as with [`render_to_string`].
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::render_to_string_document;
+/// #[component]
+/// fn App() -> View {
+///     view! {
+///         html {
+///             head { title { "My App" } }
+///             body { "Hello, world!" }
+///         }
+///     }
+/// }
+///
+/// let html = render_to_string_document(App);
+/// assert!(html.starts_with("<!doctype html><html"));
+/// ```
+#[must_use]
+pub fn render_to_string_document(view: impl FnOnce() -> View) -> String {
+    prepend_doctype(render_to_string(view))
+}
+
+/// Prepends the doctype to the rendered HTML if its root is an `html` element. Otherwise, prints
+/// a warning and returns the HTML unchanged.
+fn prepend_doctype(html: String) -> String {
+    let is_document = html
+        .strip_prefix("<html")
+        .is_some_and(|rest| rest.starts_with(['>', ' ']));
+    if is_document {
+        format!("<!doctype html>{html}")
+    } else {
+        console_warn!("the root of the view is not an `html` element, not adding a doctype");
+        html
+    }
+}
+
 /// Renders a [`View`] into a static [`String`] while awaiting for all suspense boundaries to
 /// resolve. Useful for rendering to a string on the server side.
 ///
 /// This sets the SSR mode to "blocking" mode. This means that rendering will wait until suspense
 /// is resolved before returning.
 ///
+/// # Executor
+///
+/// This function automatically creates the executor needed for running the suspense tasks (using
+/// [`provide_executor_scope`](sycamore_futures::provide_executor_scope)). There is therefore no need
+/// to wrap the call yourself. It is also fine if the call is already inside an executor scope.
+///
 /// # Example
 /// ```
 /// # use sycamore::prelude::*;
@@ -118,6 +172,8 @@ pub async fn render_to_string_await_suspense(f: impl FnOnce() -> View) -> String
                 root.run_in(|| {
                     handle = Some(create_child_scope(|| {
                         provide_context(HydrationRegistry::new());
+                        #[cfg(feature = "serde")]
+                        provide_context(HydrationState::new());
                         provide_context(SsrMode::Blocking);
 
                         view = f();
@@ -143,6 +199,17 @@ pub async fn render_to_string_await_suspense(f: impl FnOnce() -> View) -> String
     }
 }
 
+/// Render a [`View`] of a whole HTML document into a static [`String`] while awaiting for all
+/// suspense boundaries to resolve. The HTML is prepended with a `<!doctype html>` declaration.
+///
+/// This is the same as [`render_to_string_await_suspense`], except that the doctype is added like
+/// in [`render_to_string_document`].
+#[must_use]
+#[cfg(feature = "suspense")]
+pub async fn render_to_string_await_suspense_document(f: impl FnOnce() -> View) -> String {
+    prepend_doctype(render_to_string_await_suspense(f).await)
+}
+
 /// Renders a [`View`] to a stream.
 ///
 /// This sets the SSR mode to "streaming" mode. This means that the initial HTML with fallbacks is
@@ -154,7 +221,15 @@ pub async fn render_to_string_await_suspense(f: impl FnOnce() -> View) -> String
 /// # Executor
 ///
 /// This function (unlike [`render_to_string_await_suspense`]) does not automatically create an
-/// executor. You must provide the executor yourself by using `tokio::task::LocalSet`.
+/// executor. You must provide the executor yourself by using `tokio::task::LocalSet`. Since the
+/// suspense tasks keep running after this function returns, the `LocalSet` must be kept alive
+/// (e.g. by awaiting it) until the stream is finished. Note that wrapping the call in
+/// [`provide_executor_scope`](sycamore_futures::provide_executor_scope) is not enough since the
+/// executor scope ends as soon as the function returns.
+///
+/// # Panics
+///
+/// Panics if there is no `LocalSet` when this function is called.
 ///
 /// # Example
 /// ```
@@ -231,6 +306,8 @@ pub fn render_to_string_stream(
             root.run_in(|| {
                 // We run this in a new scope so that we can dispose everything after we render it.
                 provide_context(HydrationRegistry::new());
+                #[cfg(feature = "serde")]
+                provide_context(HydrationState::new());
                 provide_context(SsrMode::Streaming);
                 let suspense_state = SuspenseStream { futures: futures.clone() };
 
@@ -323,6 +400,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn render_to_string_provides_context_at_root() {
+        #[derive(Clone, Copy)]
+        struct Theme(&'static str);
+
+        #[component]
+        fn Themed() -> View {
+            let theme = use_context::<Theme>();
+            view! { p { (theme.0) } }
+        }
+
+        let res = render_to_string(|| {
+            provide_context(Theme("dark"));
+            view! { Themed {} }
+        });
+        assert_eq!(res, r#"<p data-hk="0.0">dark</p>"#);
+    }
+
+    #[test]
+    fn render_to_string_document_adds_doctype() {
+        let res = render_to_string_document(|| view! { html { body { "Hello" } } });
+        assert_eq!(
+            res,
+            r#"<!doctype html><html data-hk="0.0"><body data-hk="0.1">Hello</body></html>"#
+        );
+
+        // Not a document, so nothing is added.
+        let res = render_to_string_document(|| view! { p { "Hello" } });
+        assert_eq!(res, r#"<p data-hk="0.0">Hello</p>"#);
+        assert_eq!(
+            prepend_doctype("<htmlx></htmlx>".to_string()),
+            "<htmlx></htmlx>"
+        );
+    }
+
     #[test]
     fn render_to_string_renders_fallback() {
         let (sender, receiver) = oneshot::channel();
@@ -334,6 +446,58 @@ mod tests {
         assert!(sender.send(()).is_err(), "receiver should be dropped");
     }
 
+    #[test]
+    fn suspense_timeout_is_ignored_in_ssr() {
+        let (_sender, receiver) = oneshot::channel();
+        let res = render_to_string(move || {
+            view! {
+                Suspense(
+                    fallback=|| "fallback".into(),
+                    timeout=std::time::Duration::ZERO,
+                    on_timeout=|| "timed out".into(),
+                ) {
+                    AsyncComponent(receiver=receiver)
+                }
+            }
+        });
+        let expect = expect!["<!--/--><!--/-->fallback<!--/--><!--/--><!--/--><!--/-->"];
+        expect.assert_eq(&res);
+    }
+
+    #[test]
+    fn skeleton_suspense_renders_skeleton_in_container() {
+        let (_sender, receiver) = oneshot::channel();
+        let res = render_to_string(move || {
+            view! {
+                SkeletonSuspense(class="card", skeleton=|| view! { div(class="skeleton") }) {
+                    AsyncComponent(receiver=receiver)
+                }
+            }
+        });
+        let expect = expect![[
+            r#"<div class="card" aria-busy="true" data-hk="0.0"><!--/--><!--/--><div class="skeleton" data-hk="0.1"></div><!--/--><!--/--><!--/--><!--/--></div>"#
+        ]];
+        expect.assert_eq(&res);
+    }
+
+    #[tokio::test]
+    async fn skeleton_suspense_await_suspense_renders_content() {
+        let (sender, receiver) = oneshot::channel();
+        sender.send(()).unwrap();
+        let res = render_to_string_await_suspense(move || {
+            view! {
+                SkeletonSuspense(class="card", skeleton=|| view! { div(class="skeleton") }) {
+                    AsyncComponent(receiver=receiver)
+                }
+            }
+        })
+        .await;
+        let expect = expect![[
+            r#"<div class="card" aria-busy="false" data-hk="0.0"><suspense-start data-key="1" data-hk="0.1"></suspense-start><no-ssr data-hk="0.2"></no-ssr><!--/--><!--/-->Hello, async!<!--/--><!--/--></div>"#
+        ]];
+        expect.assert_eq(&res);
+    }
+
     #[tokio::test]
     async fn render_to_string_await_suspense_works() {
         let (sender, receiver) = oneshot::channel();
@@ -349,4 +513,89 @@ mod tests {
         ]];
         expect.assert_eq(&res);
     }
+
+    #[tokio::test]
+    async fn lazy_component_is_loaded_once() {
+        #[component]
+        fn Editor() -> View {
+            view! { textarea {} }
+        }
+
+        let loads = Rc::new(Cell::new(0));
+        let editor = lazy({
+            let loads = Rc::clone(&loads);
+            move || {
+                loads.set(loads.get() + 1);
+                async { Editor }
+            }
+        });
+        assert_eq!(loads.get(), 0, "loader should not be called until rendered");
+
+        let res = render_to_string_await_suspense({
+            let editor = editor.clone();
+            move || view! { Suspense { (editor()) } }
+        })
+        .await;
+        assert!(res.contains("<textarea"), "{res}");
+        assert_eq!(loads.get(), 1);
+
+        // The loaded component is cached, so it is rendered without suspending.
+        let res = render_to_string(editor);
+        assert_eq!(res, r#"<textarea data-hk="0.0"></textarea>"#);
+        assert_eq!(loads.get(), 1);
+    }
+
+    /// A slow suspense boundary should not delay the initial shell. Its content is streamed once it
+    /// has resolved.
+    #[tokio::test]
+    async fn render_to_string_stream_flushes_shell_first() {
+        use futures::StreamExt;
+
+        let (sender, receiver) = oneshot::channel();
+        let local = tokio::task::LocalSet::new();
+        let stream = local
+            .run_until(async { render_to_string_stream(move || view! { App(receiver=receiver) }) })
+            .await;
+        futures::pin_mut!(stream);
+
+        let shell = local.run_until(stream.next()).await.unwrap();
+        assert!(shell.starts_with("<!doctype html>"));
+        assert!(shell.contains("fallback"));
+        assert!(!shell.contains("Hello, async!"));
+
+        sender.send(()).unwrap();
+        let fragment = local.run_until(stream.next()).await.unwrap();
+        assert!(fragment.starts_with(r#"<template id="sycamore-suspense-1">"#));
+        assert!(fragment.contains("Hello, async!"));
+        assert!(fragment.ends_with("<script>__sycamore_suspense(1)</script>"));
+    }
+
+    /// `render_to_string_await_suspense` should not require setting up an executor scope or even a
+    /// tokio runtime.
+    #[test]
+    fn render_to_string_await_suspense_without_executor_scope() {
+        let (sender, receiver) = oneshot::channel();
+        sender.send(()).unwrap();
+        let res = futures::executor::block_on(render_to_string_await_suspense(
+            move || view! { App(receiver=receiver) },
+        ));
+
+        let expect = expect![[
+            r#"<suspense-start data-key="1" data-hk="0.0"></suspense-start><no-ssr data-hk="0.1"></no-ssr><!--/--><!--/-->Hello, async!<!--/--><!--/-->"#
+        ]];
+        expect.assert_eq(&res);
+    }
+
+    /// Calling `render_to_string_await_suspense` inside an existing executor scope should also
+    /// work.
+    #[tokio::test]
+    async fn render_to_string_await_suspense_nested_executor_scope() {
+        let (sender, receiver) = oneshot::channel();
+        sender.send(()).unwrap();
+        let res = sycamore_futures::provide_executor_scope(render_to_string_await_suspense(
+            move || view! { App(receiver=receiver) },
+        ))
+        .await;
+        assert!(res.contains("Hello, async!"));
+    }
 }
@@ -68,6 +68,45 @@ impl NodeRef {
         self.0.get_clone()
     }
 
+    /// Gets the node stored inside the node ref and casts it to `T`. Returns `None` if the node
+    /// ref has not yet been set or if the node is not of type `T`, e.g. because the node ref was
+    /// attached to the wrong element.
+    ///
+    /// The type is checked with [`JsCast::dyn_into`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # use web_sys::HtmlInputElement;
+    /// # fn Component() -> View {
+    /// let input_ref = create_node_ref();
+    /// let focus = move |_| {
+    ///     if let Some(input) = input_ref.get_as::<HtmlInputElement>() {
+    ///         input.focus().unwrap();
+    ///     }
+    /// };
+    /// view! {
+    ///     input(r#ref=input_ref)
+    ///     button(on:click=focus) { "Focus" }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_as<T: JsCast>(&self) -> Option<T> {
+        self.try_get()?.dyn_into().ok()
+    }
+
+    /// Gets the node stored inside the node ref and casts it to `T` without checking its type.
+    ///
+    /// This is the same as `node_ref.get().unchecked_into::<T>()`. Prefer [`NodeRef::get_as`]
+    /// unless the type check is too costly.
+    ///
+    /// # Panics
+    /// Panics if the node ref is not set yet.
+    #[track_caller]
+    pub fn get_unchecked_as<T: JsCast>(&self) -> T {
+        self.get().unchecked_into()
+    }
+
     /// Sets the node ref with the specified node.
     ///
     /// This method should be rarely used. Instead, use the `r#ref=` syntax in the `view!` macro to
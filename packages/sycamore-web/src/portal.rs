@@ -2,14 +2,83 @@ use sycamore_macro::component;
 
 use crate::*;
 
+/// The element that a [`Portal`] renders its children into.
+#[derive(Debug, Clone, Default)]
+pub enum PortalMount {
+    /// The `<body>` of the document.
+    #[default]
+    Body,
+    /// The first element matching a CSS selector, e.g. `#modal-root`.
+    Selector(String),
+    /// A specific element.
+    Element(web_sys::Element),
+}
+
+impl PortalMount {
+    /// Finds the element that this refers to.
+    ///
+    /// # Panics
+    /// Panics if no element matches the selector.
+    fn resolve(&self) -> web_sys::Element {
+        match self {
+            Self::Body => document()
+                .body()
+                .expect("document should have a body")
+                .into(),
+            Self::Selector(selector) => match document().query_selector(selector).unwrap() {
+                Some(element) => element,
+                None => panic!("element matching selector `{selector}` not found"),
+            },
+            Self::Element(element) => element.clone(),
+        }
+    }
+}
+
+impl From<&str> for PortalMount {
+    fn from(selector: &str) -> Self {
+        Self::Selector(selector.to_string())
+    }
+}
+
+impl From<String> for PortalMount {
+    fn from(selector: String) -> Self {
+        Self::Selector(selector)
+    }
+}
+
+impl From<web_sys::Element> for PortalMount {
+    fn from(element: web_sys::Element) -> Self {
+        Self::Element(element)
+    }
+}
+
 /// A portal into a different part of the DOM. Only renders in client side rendering (CSR) mode.
-/// Does nothing in SSR mode.
+/// Does nothing in SSR mode, since there is no DOM to render into. The children are not rendered
+/// inline either, so content inside a portal is only displayed once the app runs on the client.
+///
+/// The children are appended to the element given by `mount`, after any existing children. This
+/// can be the `<body>` (the default), the first element matching a CSS selector, or a specific
+/// element. A string is converted to a selector, so `mount="#modal-root"` works as well. For
+/// backwards compatibility, the selector can also be passed with `selector`, which takes precedence
+/// over `mount`.
+///
+/// Multiple portals can target the same element (e.g. a stack of toast notifications). When a
+/// portal is disposed, only its own nodes are removed.
+///
+/// # Panics
+/// Panics if no element matches the selector.
 #[component(inline_props)]
-pub fn Portal<'a, T: Into<View> + Default>(selector: &'a str, children: T) -> View {
+pub fn Portal<'a, T: Into<View> + Default>(
+    #[prop(default, setter(into))] mount: PortalMount,
+    selector: Option<&'a str>,
+    children: T,
+) -> View {
     if is_not_ssr!() {
-        let Some(parent) = document().query_selector(selector).unwrap() else {
-            panic!("element matching selector `{selector}` not found");
+        let mount = match selector {
+            Some(selector) => PortalMount::from(selector),
+            None => mount,
         };
+        let parent = mount.resolve();
 
         let start = HtmlNode::create_marker_node();
         let start_node = start.as_web_sys().clone();
@@ -0,0 +1,169 @@
+//! Effects that are synced to the browser's animation frames.
+
+use crate::*;
+
+/// A handle to an effect created with [`create_raf_effect`].
+///
+/// The handle can be used to pause and resume the effect. Pausing the effect does not dispose it.
+/// The effect is only disposed once the enclosing reactive scope is disposed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RafEffect {
+    running: Signal<bool>,
+}
+
+impl RafEffect {
+    /// Pause the effect. The callback will not be called until [`resume`](Self::resume) is
+    /// called.
+    pub fn pause(&self) {
+        self.running.set(false);
+    }
+
+    /// Resume the effect if it was previously paused.
+    pub fn resume(&self) {
+        self.running.set(true);
+    }
+
+    /// Returns `true` if the effect has not been paused. This value is reactive and can be
+    /// tracked.
+    ///
+    /// Note that this still returns `true` while the effect is suspended because the tab is
+    /// hidden.
+    pub fn is_running(&self) -> bool {
+        self.running.get()
+    }
+}
+
+/// Create an effect that is run on every animation frame until the current reactive scope is
+/// disposed.
+///
+/// The callback is passed the time elapsed since the previous frame, in milliseconds. The delta
+/// is `0.0` on the first frame and on the first frame after the effect is resumed, so that time
+/// spent paused is not counted.
+///
+/// The effect is automatically suspended while the page is hidden (e.g. when the user switches to
+/// another tab) and is resumed once the page becomes visible again. Signals accessed inside the
+/// callback are not tracked.
+///
+/// The effect starts running immediately. Use the returned [`RafEffect`] handle to pause or
+/// resume it. In SSR mode, the callback is never called.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_web::create_raf_effect;
+/// # fn Comp() -> View {
+/// let position = create_signal(0.0);
+/// let velocity = 0.1;
+/// let raf = create_raf_effect(move |dt| position.set(position.get() + velocity * dt));
+///
+/// view! {
+///     p { "Position: " (position.get()) }
+///     button(on:click=move |_| raf.pause()) { "Pause" }
+///     button(on:click=move |_| raf.resume()) { "Resume" }
+/// }
+/// # }
+/// ```
+pub fn create_raf_effect(cb: impl FnMut(f64) + 'static) -> RafEffect {
+    let running = create_signal(true);
+
+    if is_not_ssr!() {
+        start_raf_effect(cb, running);
+    }
+
+    RafEffect { running }
+}
+
+/// Sets up the animation frame loop and the `visibilitychange` listener for [`create_raf_effect`].
+fn start_raf_effect(mut cb: impl FnMut(f64) + 'static, running: Signal<bool>) {
+    use std::cell::RefCell;
+
+    let hidden = create_signal(document().hidden());
+
+    let frame_id = Rc::new(Cell::new(None::<i32>));
+    let last_time = Rc::new(Cell::new(None::<f64>));
+    let on_frame = Rc::new(RefCell::new(None::<Closure<dyn FnMut(f64)>>));
+
+    let request_frame = {
+        let frame_id = Rc::clone(&frame_id);
+        let on_frame = Rc::clone(&on_frame);
+        move || {
+            if frame_id.get().is_none() {
+                if let Some(on_frame) = on_frame.borrow().as_ref() {
+                    let id = window()
+                        .request_animation_frame(on_frame.as_ref().unchecked_ref())
+                        .unwrap_throw();
+                    frame_id.set(Some(id));
+                }
+            }
+        }
+    };
+
+    *on_frame.borrow_mut() = Some(Closure::new({
+        let frame_id = Rc::clone(&frame_id);
+        let last_time = Rc::clone(&last_time);
+        let request_frame = request_frame.clone();
+        move |timestamp: f64| {
+            frame_id.set(None);
+            let dt = last_time.get().map_or(0.0, |last| timestamp - last);
+            last_time.set(Some(timestamp));
+            untrack(|| cb(dt));
+            if running.get_untracked() && !hidden.get_untracked() {
+                request_frame();
+            }
+        }
+    }));
+
+    let on_visibility_change: Closure<dyn FnMut()> =
+        Closure::new(move || hidden.set(document().hidden()));
+    document()
+        .add_event_listener_with_callback(
+            "visibilitychange",
+            on_visibility_change.as_ref().unchecked_ref(),
+        )
+        .unwrap_throw();
+
+    create_effect({
+        let frame_id = Rc::clone(&frame_id);
+        move || {
+            if running.get() && !hidden.get() {
+                last_time.set(None);
+                request_frame();
+            } else if let Some(id) = frame_id.take() {
+                window().cancel_animation_frame(id).unwrap_throw();
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(id) = frame_id.take() {
+            window().cancel_animation_frame(id).unwrap_throw();
+        }
+        document()
+            .remove_event_listener_with_callback(
+                "visibilitychange",
+                on_visibility_change.as_ref().unchecked_ref(),
+            )
+            .unwrap_throw();
+        // Drop the frame callback to break the reference cycle with `request_frame`.
+        on_frame.borrow_mut().take();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raf_effect_pause_resume() {
+        let _ = create_root(|| {
+            let raf = create_raf_effect(|_| {});
+            assert!(raf.is_running());
+
+            raf.pause();
+            assert!(!raf.is_running());
+
+            raf.resume();
+            assert!(raf.is_running());
+        });
+    }
+}
@@ -0,0 +1,92 @@
+//! Observing the size of elements with `ResizeObserver`.
+
+use web_sys::{ResizeObserver, ResizeObserverEntry};
+
+use crate::*;
+
+/// Returns a signal with the `(width, height)` of the element referenced by `node`, in CSS pixels.
+///
+/// The size is that of the element's content box, i.e. without padding and borders. It is measured
+/// once the component is mounted and updated whenever the element is resized. If `node` is not set
+/// at that point, the element is measured as soon as it is set. The observer is disconnected when
+/// the current reactive scope is disposed.
+///
+/// Until the element is measured and in SSR mode, the size is `(0.0, 0.0)`. To use a different
+/// value, use [`use_element_size_with_default`].
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_element_size;
+/// #[component]
+/// fn Canvas() -> View {
+///     let node = create_node_ref();
+///     let size = use_element_size(node);
+///     view! {
+///         div(r#ref=node, style="width: 100%; height: 100%;") {
+///             canvas(width=move || size.get().0.to_string(), height=move || size.get().1.to_string())
+///         }
+///     }
+/// }
+/// ```
+pub fn use_element_size(node: NodeRef) -> ReadSignal<(f64, f64)> {
+    use_element_size_with_default(node, (0.0, 0.0))
+}
+
+/// Like [`use_element_size`], but with a value for the signal until the element is measured and in
+/// SSR mode.
+pub fn use_element_size_with_default(node: NodeRef, default: (f64, f64)) -> ReadSignal<(f64, f64)> {
+    let size = create_signal(default);
+    if is_ssr!() {
+        let _ = node;
+        return *size;
+    }
+
+    on_mount(move || {
+        // Re-observe whenever the node ref changes.
+        create_effect(move || {
+            let Some(element) = node
+                .try_get()
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            else {
+                return;
+            };
+
+            let on_resize: Closure<dyn Fn(js_sys::Array)> =
+                Closure::new(move |entries: js_sys::Array| {
+                    // Only the latest entry is relevant if there were multiple changes.
+                    if let Some(entry) = entries.iter().last() {
+                        let rect = entry.unchecked_into::<ResizeObserverEntry>().content_rect();
+                        size.set((rect.width(), rect.height()));
+                    }
+                });
+            let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref()).unwrap_throw();
+            // The callback is called once with the initial size after observing.
+            observer.observe(&element);
+
+            on_cleanup(move || {
+                observer.disconnect();
+                drop(on_resize);
+            });
+        });
+    });
+
+    *size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_size_uses_default_in_ssr() {
+        let _ = create_root(|| {
+            let node = create_node_ref();
+            let size = use_element_size(node);
+            let size_with_default = use_element_size_with_default(node, (800.0, 600.0));
+            let _: View = view! { div(r#ref=node) };
+            assert_eq!(size.get(), (0.0, 0.0));
+            assert_eq!(size_with_default.get(), (800.0, 600.0));
+        });
+    }
+}
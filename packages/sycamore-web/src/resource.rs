@@ -9,7 +9,6 @@ use sycamore_futures::{SuspenseScope, SuspenseTaskGuard};
 use crate::*;
 
 /// Represents a asynchronous resource.
-#[derive(Clone, Copy)]
 pub struct Resource<T: 'static> {
     /// The current value of the resource.
     ///
@@ -25,6 +24,8 @@ pub struct Resource<T: 'static> {
     scopes: Signal<Vec<SuspenseScope>>,
     /// A list of suspense guards that are currently active.
     guards: Signal<Vec<SuspenseTaskGuard>>,
+    /// Tracked by the fetching effect so that the resource can be refetched manually.
+    trigger: Signal<()>,
 }
 
 impl<T: 'static> Resource<T> {
@@ -40,12 +41,16 @@ impl<T: 'static> Resource<T> {
             refetch: create_signal(Box::new(move || refetch().boxed_local())),
             scopes: create_signal(Vec::new()),
             guards: create_signal(Vec::new()),
+            trigger: create_signal(()),
         }
     }
 
     /// Attach handlers to always call the refetch function to get the latest value.
     fn always_refetch(self) -> Self {
+        // The future is spawned in the scope of the effect, so a pending fetch is aborted when the
+        // effect is re-run. This makes sure that a stale value never overwrites a newer one.
         create_effect(move || {
+            self.trigger.track();
             self.is_loading.set(true);
             // Take all the scopes and create a new guard.
             for scope in self.scopes.take() {
@@ -73,6 +78,107 @@ impl<T: 'static> Resource<T> {
     pub fn is_loading(&self) -> bool {
         self.is_loading.get()
     }
+
+    /// Fetches the resource again, e.g. because the data might have become stale.
+    ///
+    /// The previous value is kept while the new value is loading, and [`is_loading`] is `true`
+    /// until the new value is available. If a fetch is still in progress, it is aborted so that
+    /// only the value of the latest fetch is used. The same happens when one of the dependencies
+    /// of the resource changes.
+    ///
+    /// Does nothing for a resource created with [`create_client_resource`] in SSR mode.
+    ///
+    /// [`is_loading`]: Self::is_loading
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # use sycamore::web::create_client_resource;
+    /// # async fn fetch_notifications() -> Vec<String> { Vec::new() }
+    /// # fn Comp() -> View {
+    /// let notifications = create_client_resource(fetch_notifications);
+    /// view! {
+    ///     button(on:click=move |_| notifications.refetch()) { "Refresh" }
+    /// }
+    /// # }
+    /// ```
+    pub fn refetch(&self) {
+        self.trigger.set(());
+    }
+}
+
+// Implemented manually so that the resource is `Copy` even if `T` is not.
+impl<T: 'static> Clone for Resource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: 'static> Copy for Resource<T> {}
+
+/// Methods for resources whose fetcher can fail.
+///
+/// Like the value of the resource, these are tracked by [`Suspense`], which displays its fallback
+/// until the fetcher has resolved, regardless of whether it succeeded or failed.
+impl<T: 'static, E: 'static> Resource<Result<T, E>> {
+    /// Returns the data of the resource, or `None` if it is still loading for the first time or if
+    /// the last fetch failed.
+    pub fn data(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with(|value| value.as_ref()?.as_ref().ok().cloned())
+    }
+
+    /// Returns the error of the last fetch, or `None` if it is still loading for the first time or
+    /// if the last fetch succeeded.
+    pub fn error(&self) -> Option<E>
+    where
+        E: Clone,
+    {
+        self.with(|value| value.as_ref()?.as_ref().err().cloned())
+    }
+
+    /// Returns the data of the resource, like [`data`](Self::data). If the last fetch failed, the
+    /// error is reported to the nearest [`ErrorBoundary`], if there is one.
+    ///
+    /// The error boundary is not reset automatically when the resource is refetched. Use the
+    /// `reset_on` prop of the error boundary to reset it, e.g. `reset_on=move || resource.track()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore::prelude::*;
+    /// # use sycamore_web::{create_client_resource, ErrorBoundary, Suspense};
+    /// # async fn fetch_user() -> Result<String, String> { Ok(String::new()) }
+    /// #[component]
+    /// fn User() -> View {
+    ///     let user = create_client_resource(fetch_user);
+    ///     view! {
+    ///         p { (user.data_or_throw().unwrap_or_default()) }
+    ///     }
+    /// }
+    ///
+    /// # fn App() -> View {
+    /// view! {
+    ///     ErrorBoundary(fallback=|err| view! { "Failed to load user: " (err.to_string()) }) {
+    ///         Suspense(fallback=|| view! { "Loading..." }) {
+    ///             User {}
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn data_or_throw(&self) -> Option<T>
+    where
+        T: Clone,
+        E: Clone + Into<Box<dyn std::error::Error>>,
+    {
+        if let Some(err) = self.error() {
+            if let Some(boundary) = try_use_context::<ErrorBoundaryHandle>() {
+                boundary.throw(err);
+            }
+        }
+        self.data()
+    }
 }
 
 /// Hijack deref so that we can track where the resource is being accessed.
@@ -97,7 +203,8 @@ impl<T: 'static> Deref for Resource<T> {
 ///
 /// If the resource has any dependencies, it is recommended to use [`on`] to make them explicit.
 /// This will ensure that the dependencies are tracked since reactive variables inside async
-/// contexts are not tracked automatically.
+/// contexts are not tracked automatically. The resource is refetched whenever a dependency
+/// changes. To refetch it manually, use [`Resource::refetch`].
 pub fn create_isomorphic_resource<F, Fut, T>(f: F) -> Resource<T>
 where
     F: FnMut() -> Fut + 'static,
@@ -113,7 +220,8 @@ where
 ///
 /// If the resource has any dependencies, it is recommended to use [`on`] to make them explicit.
 /// This will ensure that the dependencies are tracked since reactive variables inside async
-/// contexts are not tracked automatically.
+/// contexts are not tracked automatically. The resource is refetched whenever a dependency
+/// changes. To refetch it manually, use [`Resource::refetch`].
 pub fn create_client_resource<F, Fut, T>(f: F) -> Resource<T>
 where
     F: FnMut() -> Fut + 'static,
@@ -181,4 +289,85 @@ mod tests {
         })
         .await;
     }
+
+    #[tokio::test]
+    async fn fallible_resource() {
+        let local = tokio::task::LocalSet::new();
+        let ((ok, err), root) = local
+            .run_until(async {
+                create_root_get(|| {
+                    let ok = create_isomorphic_resource(|| async { Ok::<_, String>(1) });
+                    let err = create_isomorphic_resource(|| async { Err::<i32, _>("failed") });
+                    (ok, err)
+                })
+            })
+            .await;
+        root.run_in(|| {
+            assert_eq!(ok.data(), None);
+            assert_eq!(ok.error(), None);
+        });
+
+        local.await;
+        root.run_in(|| {
+            assert_eq!(ok.data(), Some(1));
+            assert_eq!(ok.error(), None);
+            assert_eq!(err.data(), None);
+            assert_eq!(err.error(), Some("failed"));
+            assert!(!err.is_loading());
+        });
+        root.dispose();
+    }
+
+    #[tokio::test]
+    async fn refetch_aborts_pending_fetch() {
+        use std::cell::RefCell;
+
+        let (tx0, rx0) = oneshot::channel();
+        let (tx1, rx1) = oneshot::channel();
+        let (tx2, rx2) = oneshot::channel();
+        let receivers = Rc::new(RefCell::new(vec![rx2, rx1, rx0]));
+
+        let local = tokio::task::LocalSet::new();
+        let (resource, root) = local
+            .run_until(async {
+                create_root_get(move || {
+                    create_isomorphic_resource(move || {
+                        let rx = receivers.borrow_mut().pop().unwrap();
+                        async move { rx.await.unwrap() }
+                    })
+                })
+            })
+            .await;
+
+        tx0.send(0).unwrap();
+        local.run_until(tokio::task::yield_now()).await;
+        root.run_in(|| {
+            assert_eq!(resource.get(), Some(0));
+            assert!(!resource.is_loading());
+        });
+
+        // Refetch twice. The previous value is kept while loading.
+        local
+            .run_until(async { root.run_in(|| resource.refetch()) })
+            .await;
+        root.run_in(|| {
+            assert_eq!(resource.get(), Some(0));
+            assert!(resource.is_loading());
+        });
+        local
+            .run_until(async { root.run_in(|| resource.refetch()) })
+            .await;
+        tx2.send(2).unwrap();
+        local.await;
+
+        root.run_in(|| {
+            assert_eq!(resource.get(), Some(2));
+            assert!(!resource.is_loading());
+        });
+        assert!(
+            tx1.send(1).is_err(),
+            "superseded fetch should have been aborted"
+        );
+        root.dispose();
+    }
 }
@@ -0,0 +1,307 @@
+//! Enter and exit animations when switching between routes.
+
+use std::time::Duration;
+
+use crate::*;
+
+/// The state of a page inside a [`RouteTransition`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageState {
+    /// The page is shown and is not animating.
+    Idle,
+    /// The page has just been mounted and its enter animation is running.
+    Entering,
+    /// The page is being replaced and its exit animation is running. It is unmounted once the
+    /// animation is done.
+    Exiting,
+}
+
+impl PageState {
+    /// The value of the `data-route-transition` attribute for this state.
+    fn attribute(self) -> Option<&'static str> {
+        match self {
+            Self::Idle => None,
+            Self::Entering => Some("enter"),
+            Self::Exiting => Some("exit"),
+        }
+    }
+}
+
+/// A page that is mounted inside a [`RouteTransition`].
+#[derive(Debug, Clone)]
+struct Page<T> {
+    id: u32,
+    route: T,
+    state: PageState,
+}
+
+/// Pages are identified by their id. This lets [`Keyed`] reuse the view of a page when its state
+/// changes.
+impl<T> PartialEq for Page<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/// Starts the transition to a new page. Returns the ids of the pages that are now exiting.
+///
+/// Pages that were still exiting from a previous transition are removed right away, so that at
+/// most two pages are mounted at the same time, even when navigating rapidly.
+fn start_transition<T>(pages: &mut Vec<Page<T>>, id: u32, route: T) -> Vec<u32> {
+    pages.retain(|page| page.state != PageState::Exiting);
+    for page in pages.iter_mut() {
+        page.state = PageState::Exiting;
+    }
+    let exiting = pages.iter().map(|page| page.id).collect();
+    pages.push(Page {
+        id,
+        route,
+        state: PageState::Entering,
+    });
+    exiting
+}
+
+/// Finishes the transition that was started by [`start_transition`]. Exiting pages that have not
+/// been removed yet are unmounted, and the entering page is marked as idle unless it is already
+/// exiting because of a newer transition.
+fn finish_transition<T>(pages: &mut Vec<Page<T>>, id: u32, exiting: &[u32]) {
+    pages.retain(|page| !exiting.contains(&page.id));
+    if let Some(page) = pages.iter_mut().find(|page| page.id == id) {
+        if page.state == PageState::Entering {
+            page.state = PageState::Idle;
+        }
+    }
+}
+
+/// Returns the pending state of the enclosing [`Transition`], if any.
+fn transition_pending() -> ReadSignal<bool> {
+    #[cfg(feature = "suspense")]
+    return use_transition_pending();
+    #[cfg(not(feature = "suspense"))]
+    return *create_signal(false);
+}
+
+/// Props for [`RouteTransition`].
+#[derive(Props)]
+pub struct RouteTransitionProps<T: 'static> {
+    /// The current route, e.g. the signal passed to the `view` function of the router.
+    route: ReadSignal<T>,
+    /// The function that creates the page for a route.
+    #[prop(setter(transform = |f: impl Fn(T) -> View + 'static| Box::new(f) as Box<dyn Fn(T) -> View>))]
+    view: Box<dyn Fn(T) -> View>,
+    /// How long the enter and exit animations take. The outgoing page is unmounted once this
+    /// duration has elapsed. Defaults to 300ms.
+    #[prop(default = Duration::from_millis(300))]
+    duration: Duration,
+    /// The classes of the container element of each page.
+    #[prop(default, setter(into))]
+    class: String,
+}
+
+/// Animates between pages when the route changes.
+///
+/// Each page is rendered inside a `<div>` container. When the route changes, the container of
+/// the new page gets a `data-route-transition="enter"` attribute and the container of the old
+/// page gets a `data-route-transition="exit"` attribute. The old page stays mounted until the
+/// exit animation is done, after which it is removed and the attribute of the new page is
+/// removed. The animations themselves are defined in CSS, e.g.:
+///
+/// ```css
+/// [data-route-transition="enter"] { animation: fade-in 300ms; }
+/// [data-route-transition="exit"] { animation: fade-out 300ms; position: absolute; }
+/// ```
+///
+/// If the route changes again while a transition is still running, the page that was exiting is
+/// removed right away and the page that was entering starts exiting instead. Setting the route to
+/// a value that is equal to the current route does not start a transition.
+///
+/// Inside a [`Transition`], route changes are deferred while the transition is pending (see
+/// [`use_transition_pending`]). The current page stays visible while the new content is loading,
+/// e.g. the data that a router preloads for the new route, and the exit animation only starts
+/// once it is ready. If the route changes several times in the meantime, only the last route is
+/// animated to.
+///
+/// In SSR mode, only the current page is rendered.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore_web::RouteTransition;
+/// #[derive(Clone, PartialEq)]
+/// enum Route {
+///     Home,
+///     About,
+/// }
+///
+/// # fn App() -> View {
+/// let route = create_signal(Route::Home);
+/// view! {
+///     RouteTransition(route=*route, view=|route| match route {
+///         Route::Home => view! { "Home" },
+///         Route::About => view! { "About" },
+///     })
+/// }
+/// # }
+/// ```
+#[component]
+pub fn RouteTransition<T: Clone + PartialEq + 'static>(props: RouteTransitionProps<T>) -> View {
+    let RouteTransitionProps {
+        route,
+        view,
+        duration,
+        class,
+    } = props;
+    let view = Rc::new(view);
+
+    let next_id = Rc::new(Cell::new(1));
+    let pages = create_signal(vec![Page {
+        id: 0,
+        route: route.get_clone_untracked(),
+        state: PageState::Idle,
+    }]);
+
+    let is_alive = Rc::new(Cell::new(true));
+    on_cleanup({
+        let is_alive = Rc::clone(&is_alive);
+        move || is_alive.set(false)
+    });
+
+    // While the enclosing `Transition` is loading, the current page stays visible and the
+    // transition only starts once the new content is ready.
+    let pending = transition_pending();
+    create_effect(move || {
+        route.track();
+        if pending.get() {
+            return;
+        }
+        let new_route = route.get_clone_untracked();
+        let is_current = pages.with_untracked(|pages| {
+            pages
+                .iter()
+                .rfind(|page| page.state != PageState::Exiting)
+                .is_some_and(|page| page.route == new_route)
+        });
+        if is_current {
+            return;
+        }
+
+        let id = next_id.get();
+        next_id.set(id + 1);
+        if is_ssr!() {
+            pages.set(vec![Page {
+                id,
+                route: new_route,
+                state: PageState::Idle,
+            }]);
+            return;
+        }
+
+        let exiting = pages.update(|pages| start_transition(pages, id, new_route));
+        let is_alive = Rc::clone(&is_alive);
+        let on_timeout = Closure::once_into_js(move || {
+            if is_alive.get() {
+                pages.update(|pages| finish_transition(pages, id, &exiting));
+            }
+        });
+        let timeout = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+        window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                on_timeout.unchecked_ref(),
+                timeout,
+            )
+            .unwrap_throw();
+    });
+
+    view! {
+        Keyed(
+            list=pages,
+            view=move |page| {
+                let id = page.id;
+                let state = move || {
+                    pages.with(|pages| {
+                        pages
+                            .iter()
+                            .find(|page| page.id == id)
+                            .and_then(|page| page.state.attribute())
+                    })
+                };
+                let content = view(page.route);
+                let class = class.clone();
+                view! {
+                    div(class=class, data-route-transition=state) {
+                        (content)
+                    }
+                }
+            },
+            key=|page| page.id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states(pages: &[Page<&'static str>]) -> Vec<(&'static str, PageState)> {
+        pages.iter().map(|page| (page.route, page.state)).collect()
+    }
+
+    #[test]
+    fn transition_between_pages() {
+        let mut pages = vec![Page {
+            id: 0,
+            route: "home",
+            state: PageState::Idle,
+        }];
+        let exiting = start_transition(&mut pages, 1, "about");
+        assert_eq!(exiting, [0]);
+        assert_eq!(
+            states(&pages),
+            [("home", PageState::Exiting), ("about", PageState::Entering)]
+        );
+
+        finish_transition(&mut pages, 1, &exiting);
+        assert_eq!(states(&pages), [("about", PageState::Idle)]);
+    }
+
+    #[test]
+    fn rapid_navigation_cancels_transition() {
+        let mut pages = vec![Page {
+            id: 0,
+            route: "home",
+            state: PageState::Idle,
+        }];
+        let first = start_transition(&mut pages, 1, "about");
+        // Navigate again before the first transition is done.
+        let second = start_transition(&mut pages, 2, "blog");
+        assert_eq!(second, [1]);
+        assert_eq!(
+            states(&pages),
+            [("about", PageState::Exiting), ("blog", PageState::Entering)]
+        );
+
+        // Finishing the first transition does not affect the second one.
+        finish_transition(&mut pages, 1, &first);
+        assert_eq!(
+            states(&pages),
+            [("about", PageState::Exiting), ("blog", PageState::Entering)]
+        );
+
+        finish_transition(&mut pages, 2, &second);
+        assert_eq!(states(&pages), [("blog", PageState::Idle)]);
+    }
+
+    #[test]
+    fn route_transition_ssr() {
+        let res = render_to_string(|| {
+            let route = create_signal("home");
+            view! {
+                RouteTransition(route=*route, class="page", view=|route| view! { p { (route) } })
+            }
+        });
+        assert_eq!(
+            res,
+            r#"<div class="page" data-hk="0.1"><p data-hk="0.0">home</p></div>"#
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Signals that are persisted to `localStorage`.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::{Storage, StorageEvent};
+
+use crate::*;
+
+/// Options for [`use_local_storage_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalStorageOptions {
+    /// Whether to update the signal when the value is changed in another tab or window, using
+    /// the `storage` event.
+    pub sync_tabs: bool,
+}
+
+impl Default for LocalStorageOptions {
+    /// `sync_tabs` is enabled by default.
+    fn default() -> Self {
+        Self { sync_tabs: true }
+    }
+}
+
+/// Creates a signal that is persisted to `localStorage` under `key`.
+///
+/// The signal is initialized with the value stored under `key`. If there is no such value or if
+/// it cannot be deserialized, `default` is used instead. Whenever the signal changes, the new value
+/// is serialized to JSON and written back. The signal is also updated when the value is changed
+/// in another tab. To disable this, use [`use_local_storage_with_options`].
+///
+/// If `localStorage` is not available, e.g. because the user has disabled it, the signal is not
+/// persisted. In SSR mode, the signal just holds `default`. While hydrating, the signal also
+/// starts out as `default` so that the hydrated content matches the server-rendered HTML, and is
+/// updated with the stored value once the component is mounted.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_local_storage;
+/// #[component]
+/// fn Settings() -> View {
+///     let font_size = use_local_storage("font-size", 16.0);
+///     view! {
+///         input(r#type="number", bind:valueAsNumber=font_size)
+///     }
+/// }
+/// ```
+pub fn use_local_storage<T>(key: &str, default: T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    use_local_storage_with_options(key, default, LocalStorageOptions::default())
+}
+
+/// Like [`use_local_storage`], but with additional [`LocalStorageOptions`].
+pub fn use_local_storage_with_options<T>(
+    key: &str,
+    default: T,
+    options: LocalStorageOptions,
+) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    if is_ssr!() {
+        let _ = (key, options);
+        return create_signal(default);
+    }
+
+    let Some(storage) = window().local_storage().ok().flatten() else {
+        return create_signal(default);
+    };
+    let key = key.to_string();
+
+    let read_stored = {
+        let (storage, key) = (storage.clone(), key.clone());
+        move || -> Option<T> {
+            storage
+                .get_item(&key)
+                .ok()
+                .flatten()
+                .and_then(|value| serde_json::from_str(&value).ok())
+        }
+    };
+    // While hydrating, start out with `default` so that the hydrated content matches the
+    // server-rendered HTML, and read the stored value once the component is mounted.
+    let is_hydrating = IS_HYDRATING.get();
+    let signal = create_signal(if is_hydrating {
+        default
+    } else {
+        read_stored().unwrap_or(default)
+    });
+
+    if options.sync_tabs {
+        let on_storage: Closure<dyn Fn(StorageEvent)> = Closure::new({
+            let key = key.clone();
+            move |event: StorageEvent| {
+                if event.key().as_deref() != Some(&key) {
+                    return;
+                }
+                // If the value was removed or is invalid, keep the current value.
+                let value = event
+                    .new_value()
+                    .and_then(|value| serde_json::from_str(&value).ok());
+                if let Some(value) = value {
+                    signal.set(value);
+                }
+            }
+        });
+        window()
+            .add_event_listener_with_callback("storage", on_storage.as_ref().unchecked_ref())
+            .unwrap_throw();
+        on_cleanup(move || {
+            window()
+                .remove_event_listener_with_callback("storage", on_storage.as_ref().unchecked_ref())
+                .unwrap_throw();
+        });
+    }
+
+    let persist = move || {
+        create_effect(move || signal.with(|value| write_to_storage(&storage, &key, value)));
+    };
+    if is_hydrating {
+        // The effect is only created after reading the stored value so that it is not
+        // overwritten with `default`.
+        on_mount(move || {
+            if let Some(value) = read_stored() {
+                signal.set(value);
+            }
+            persist();
+        });
+    } else {
+        persist();
+    }
+
+    signal
+}
+
+/// Serializes `value` and writes it to `storage`, unless the stored value is already the same.
+fn write_to_storage<T: Serialize>(storage: &Storage, key: &str, value: &T) {
+    let value = match serde_json::to_string(value) {
+        Ok(value) => value,
+        Err(err) => {
+            console_warn!("could not serialize value for localStorage key `{key}`: {err}");
+            return;
+        }
+    };
+    if storage.get_item(key).ok().flatten().as_ref() == Some(&value) {
+        return;
+    }
+    if storage.set_item(key, &value).is_err() {
+        console_warn!("could not write to localStorage key `{key}`");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_storage_holds_default_in_ssr() {
+        let _ = create_root(|| {
+            let signal = use_local_storage("key", vec![1, 2, 3]);
+            assert_eq!(signal.get_clone(), [1, 2, 3]);
+            signal.set(vec![4]);
+            assert_eq!(signal.get_clone(), [4]);
+        });
+    }
+}
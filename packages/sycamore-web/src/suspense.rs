@@ -1,7 +1,9 @@
 //! Components for suspense.
 
+use std::cell::RefCell;
 use std::future::Future;
 use std::num::NonZeroU32;
+use std::time::Duration;
 
 use sycamore_futures::{
     create_detached_suspense_scope, create_suspense_scope, create_suspense_task,
@@ -10,7 +12,7 @@ use sycamore_macro::{component, Props};
 
 use crate::*;
 
-/// Props for [`Suspense`] and [`Transition`].
+/// Props for [`Suspense`].
 #[derive(Props)]
 pub struct SuspenseProps {
     /// The fallback [`View`] to display while the child nodes are being awaited.
@@ -22,6 +24,334 @@ pub struct SuspenseProps {
     /// This is only updated in non-SSR mode.
     #[prop(default = Box::new(|_| {}), setter(transform = |f: impl FnMut(bool) + 'static| Box::new(f) as Box<dyn FnMut(bool)>))]
     set_is_loading: Box<dyn FnMut(bool) + 'static>,
+    /// Called every time the children finish loading after having been suspended, including when
+    /// new async work started after the initial load has resolved. The callback runs in the scope
+    /// of the component.
+    ///
+    /// This is only called in non-SSR mode.
+    #[prop(default = Box::new(|| {}), setter(transform = |f: impl FnMut() + 'static| Box::new(f) as Box<dyn FnMut()>))]
+    on_resolve: Box<dyn FnMut() + 'static>,
+    /// How long the children may stay suspended before the boundary gives up waiting. The timer
+    /// starts whenever the boundary suspends and is cancelled once it resolves.
+    ///
+    /// When the timer fires, `on_timeout` is displayed instead of the fallback. If `on_timeout` is
+    /// not set, a [`SuspenseTimeoutError`] is reported to the nearest [`ErrorBoundary`] instead.
+    /// Without an error boundary, the fallback is kept.
+    ///
+    /// This is only used in non-SSR mode.
+    timeout: Option<Duration>,
+    /// The view to display instead of the fallback once `timeout` has passed. The children stay
+    /// alive and replace this view if they resolve later on.
+    #[prop(!optional, default, setter(transform = |f: impl Fn() -> View + 'static| Some(Box::new(f) as Box<dyn Fn() -> View>)))]
+    on_timeout: Option<Box<dyn Fn() -> View>>,
+}
+
+/// The error reported to the nearest [`ErrorBoundary`] when a [`Suspense`] with a `timeout` and
+/// without an `on_timeout` view has been suspended for too long.
+#[derive(Debug, Clone, Copy)]
+pub struct SuspenseTimeoutError {
+    timeout: Duration,
+}
+
+impl SuspenseTimeoutError {
+    /// Returns the timeout that was exceeded.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl std::fmt::Display for SuspenseTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "suspense boundary did not resolve within {:?}",
+            self.timeout
+        )
+    }
+}
+
+impl std::error::Error for SuspenseTimeoutError {}
+
+/// Props for [`Transition`].
+#[derive(Props)]
+pub struct TransitionProps {
+    /// The fallback [`View`] to display while the child nodes are being awaited for the first
+    /// time.
+    #[prop(default = Box::new(|| view! {}), setter(transform = |f: impl Fn() -> View + 'static| Box::new(f) as Box<dyn Fn() -> View>))]
+    fallback: Box<dyn Fn() -> View>,
+    children: Children,
+    /// The component will automatically update this signal with the `is_loading` state.
+    ///
+    /// This is only updated in non-SSR mode.
+    #[prop(default = Box::new(|_| {}), setter(transform = |f: impl FnMut(bool) + 'static| Box::new(f) as Box<dyn FnMut(bool)>))]
+    set_is_loading: Box<dyn FnMut(bool) + 'static>,
+    /// Called every time the children finish loading after having been suspended. See
+    /// [`SuspenseProps`].
+    #[prop(default = Box::new(|| {}), setter(transform = |f: impl FnMut() + 'static| Box::new(f) as Box<dyn FnMut()>))]
+    on_resolve: Box<dyn FnMut() + 'static>,
+    /// The minimum time that the pending state returned by [`use_transition_pending`] stays
+    /// `true` once a transition starts. This prevents a loading indicator from flickering when the
+    /// new content loads quickly. Defaults to zero.
+    #[prop(default)]
+    min_duration: Duration,
+}
+
+/// The suspended state of the nearest [`Suspense`] or [`Transition`], provided as a context.
+#[derive(Clone, Copy)]
+struct IsSuspended(ReadSignal<bool>);
+
+/// Returns a signal that is `true` while the nearest [`Suspense`] or [`Transition`] boundary is
+/// waiting for async work to complete.
+///
+/// Outside of a suspense boundary, the signal is always `false`. This is only updated in non-SSR
+/// mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_is_suspended;
+/// #[component]
+/// fn Spinner() -> View {
+///     let is_suspended = use_is_suspended();
+///     view! {
+///         (if is_suspended.get() { "Loading..." } else { "" })
+///     }
+/// }
+/// ```
+pub fn use_is_suspended() -> ReadSignal<bool> {
+    match try_use_context::<IsSuspended>() {
+        Some(IsSuspended(is_suspended)) => is_suspended,
+        None => *create_signal(false),
+    }
+}
+
+/// The pending state of the nearest [`Transition`], provided as a context.
+#[derive(Clone, Copy)]
+struct TransitionPending(ReadSignal<bool>);
+
+/// Returns a signal that is `true` while the nearest [`Transition`] is loading new content and
+/// still showing the previous content. It stays `false` during the initial load, while the
+/// fallback is shown.
+///
+/// Once the transition completes, the signal stays `true` until the `min_duration` of the
+/// [`Transition`] has passed since the transition started. Outside of a [`Transition`], the signal
+/// is always `false`. This is only updated in non-SSR mode.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_transition_pending;
+/// #[component]
+/// fn Page() -> View {
+///     let pending = use_transition_pending();
+///     view! {
+///         div(class=move || if pending.get() { "page stale" } else { "page" }) {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+pub fn use_transition_pending() -> ReadSignal<bool> {
+    match try_use_context::<TransitionPending>() {
+        Some(TransitionPending(pending)) => pending,
+        None => *create_signal(false),
+    }
+}
+
+/// Keeps `pending` in sync with `is_loading`, except that once `pending` becomes `true`, it only
+/// becomes `false` again after at least `min_duration` has passed.
+///
+/// The initial load is ignored since the fallback is shown instead of any previous content. The
+/// timer is cleared when the current reactive scope is disposed.
+fn sync_pending(is_loading: ReadSignal<bool>, pending: Signal<bool>, min_duration: Duration) {
+    let mut has_loaded = false;
+    let is_loading = create_selector(move || {
+        let is_loading = is_loading.get();
+        let is_reloading = is_loading && has_loaded;
+        has_loaded |= !is_loading;
+        is_reloading
+    });
+
+    if is_ssr!() {
+        let _ = min_duration;
+        create_effect(move || pending.set(is_loading.get()));
+        return;
+    }
+
+    let min_duration = min_duration.as_secs_f64() * 1000.0;
+    // The time at which the current transition started.
+    let started_at = Rc::new(Cell::new(0.0));
+    // The id of the timer that ends the pending state, if any.
+    let timer_id = Rc::new(Cell::new(None::<i32>));
+    let on_timeout = Rc::new(RefCell::new(Some(Closure::<dyn FnMut()>::new({
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            timer_id.set(None);
+            pending.set(false);
+        }
+    }))));
+
+    let clear_timer = {
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            if let Some(id) = timer_id.take() {
+                window().clear_timeout_with_handle(id);
+            }
+        }
+    };
+
+    create_effect({
+        let timer_id = Rc::clone(&timer_id);
+        let on_timeout = Rc::clone(&on_timeout);
+        let clear_timer = clear_timer.clone();
+        move || {
+            let is_loading = is_loading.get();
+            if is_loading {
+                // The transition was restarted before the pending state ended.
+                clear_timer();
+                if !pending.get_untracked() {
+                    started_at.set(js_sys::Date::now());
+                    pending.set(true);
+                }
+            } else if pending.get_untracked() && timer_id.get().is_none() {
+                let remaining = min_duration - (js_sys::Date::now() - started_at.get());
+                if remaining <= 0.0 {
+                    pending.set(false);
+                } else if let Some(on_timeout) = on_timeout.borrow().as_ref() {
+                    let id = window()
+                        .set_timeout_with_callback_and_timeout_and_arguments_0(
+                            on_timeout.as_ref().unchecked_ref(),
+                            remaining.ceil() as i32,
+                        )
+                        .unwrap_throw();
+                    timer_id.set(Some(id));
+                }
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        clear_timer();
+        on_timeout.borrow_mut().take();
+    });
+}
+
+/// Returns a signal that becomes `true` once `is_loading` has stayed `true` for longer than
+/// `timeout`, and `false` again once it resolves. The timer starts whenever `is_loading` becomes
+/// `true` and is cleared when it becomes `false` or when the current reactive scope is disposed.
+///
+/// If `report_error` is `true` and there is an [`ErrorBoundary`], a [`SuspenseTimeoutError`] is
+/// reported to it instead of updating the signal.
+#[cfg_not_ssr]
+fn track_timeout(
+    is_loading: ReadSignal<bool>,
+    timeout: Option<Duration>,
+    report_error: bool,
+) -> ReadSignal<bool> {
+    let timed_out = create_signal(false);
+    let Some(timeout) = timeout else {
+        return *timed_out;
+    };
+
+    let boundary = report_error
+        .then(try_use_context::<ErrorBoundaryHandle>)
+        .flatten();
+    // The id of the pending timer, if any.
+    let timer_id = Rc::new(Cell::new(None::<i32>));
+    let on_timeout = Rc::new(RefCell::new(Some(Closure::<dyn FnMut()>::new({
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            timer_id.set(None);
+            match boundary {
+                Some(boundary) => boundary.throw(SuspenseTimeoutError { timeout }),
+                None => timed_out.set(true),
+            }
+        }
+    }))));
+
+    let clear_timer = {
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            if let Some(id) = timer_id.take() {
+                window().clear_timeout_with_handle(id);
+            }
+        }
+    };
+
+    create_effect({
+        let timer_id = Rc::clone(&timer_id);
+        let on_timeout = Rc::clone(&on_timeout);
+        let clear_timer = clear_timer.clone();
+        move || {
+            if !is_loading.get() {
+                clear_timer();
+                timed_out.set(false);
+            } else if timer_id.get().is_none() && !timed_out.get_untracked() {
+                if let Some(on_timeout) = on_timeout.borrow().as_ref() {
+                    let id = window()
+                        .set_timeout_with_callback_and_timeout_and_arguments_0(
+                            on_timeout.as_ref().unchecked_ref(),
+                            i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX),
+                        )
+                        .unwrap_throw();
+                    timer_id.set(Some(id));
+                }
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        clear_timer();
+        on_timeout.borrow_mut().take();
+    });
+
+    *timed_out
+}
+
+/// Provides the [`IsSuspended`] context for the children of a suspense boundary. Returns a
+/// function that should be called with the loading state of the suspense scope, which updates the
+/// context and calls `set_is_loading` and `on_resolve`.
+fn provide_is_suspended(
+    mut set_is_loading: Box<dyn FnMut(bool)>,
+    mut on_resolve: Box<dyn FnMut()>,
+) -> impl FnOnce(ReadSignal<bool>) {
+    let is_suspended = create_signal(false);
+    provide_context(IsSuspended(*is_suspended));
+    move |is_loading| {
+        create_effect(move || {
+            let is_loading = is_loading.get();
+            set_is_loading(is_loading);
+            let was_loading = is_suspended.get_untracked();
+            is_suspended.set(is_loading);
+            if was_loading && !is_loading {
+                untrack(&mut on_resolve);
+            }
+        });
+    }
+}
+
+/// Creates the view that is shown while the children of a [`Suspense`] are loading.
+///
+/// If both `timeout` and `on_timeout` are set, the fallback is swapped for `on_timeout` once
+/// `timed_out` becomes `true`. Both are called untracked, so that they are only re-rendered when
+/// `timed_out` changes. Otherwise, the fallback is only rendered once.
+#[cfg_not_ssr]
+fn fallback_view(
+    fallback: Box<dyn Fn() -> View>,
+    timeout: Option<Duration>,
+    on_timeout: Option<Box<dyn Fn() -> View>>,
+    timed_out: ReadSignal<bool>,
+) -> View {
+    match on_timeout {
+        Some(on_timeout) if timeout.is_some() => View::from_dynamic(move || {
+            if timed_out.get() {
+                untrack(&on_timeout)
+            } else {
+                untrack(&fallback)
+            }
+        }),
+        _ => fallback(),
+    }
 }
 
 /// `Suspense` lets you wait for `async` tasks to complete before rendering the UI. This is useful
@@ -33,6 +363,10 @@ pub struct SuspenseProps {
 /// be rendered once all individual async components are rendered. This is useful for showing a
 /// loading indicator while the data is being loaded.
 ///
+/// To stop waiting after a while, set `timeout`. Once the boundary has been suspended for longer
+/// than `timeout`, the `on_timeout` view replaces the fallback, or, if `on_timeout` is not set, a
+/// [`SuspenseTimeoutError`] is reported to the nearest [`ErrorBoundary`].
+///
 /// # Example
 /// ```
 /// use sycamore::prelude::*;
@@ -57,13 +391,16 @@ pub fn Suspense(props: SuspenseProps) -> View {
     let SuspenseProps {
         fallback,
         children,
-        mut set_is_loading,
+        set_is_loading,
+        on_resolve,
+        timeout,
+        on_timeout,
     } = props;
 
     is_ssr! {
         use futures::FutureExt;
 
-        let _ = &mut set_is_loading;
+        let _ = (set_is_loading, on_resolve, timeout, on_timeout);
 
         let mode = use_context::<SsrMode>();
         match mode {
@@ -106,7 +443,7 @@ pub fn Suspense(props: SuspenseProps) -> View {
                     let (tx, rx) = futures::channel::oneshot::channel();
                     let mut tx = Some(tx);
                     create_effect(move || {
-                        if !suspense_scope.sent.get() && suspense_scope.parent.as_ref().map_or(true, |parent| parent.get().sent.get()) {
+                        if !suspense_scope.sent.get_untracked() && suspense_scope.parent.as_ref().is_none_or(|parent| parent.get().sent.get()) {
                             suspense_scope.sent.set(true);
                             tx.take().unwrap().send(()).unwrap();
                         }
@@ -136,16 +473,16 @@ pub fn Suspense(props: SuspenseProps) -> View {
         };
         match mode {
             SsrMode::Sync => {
+                let track_loading = provide_is_suspended(set_is_loading, on_resolve);
                 let (view, suspense_scope) = create_suspense_scope(move || children.call());
                 let is_loading = suspense_scope.is_loading();
-
-                create_effect(move || {
-                    set_is_loading(is_loading.get());
-                });
+                track_loading(is_loading);
+                let timed_out = track_timeout(is_loading, timeout, on_timeout.is_none());
+                let fallback = fallback_view(fallback, timeout, on_timeout, timed_out);
 
                 view! {
                     Show(when=is_loading) {
-                        (fallback())
+                        (fallback)
                     }
                     Show(when=move || !is_loading.get()) {
                         (view)
@@ -165,15 +502,17 @@ pub fn Suspense(props: SuspenseProps) -> View {
                 let node = start.nodes[0].as_web_sys().unchecked_ref::<web_sys::Element>();
                 let key: NonZeroU32 = node.get_attribute("data-key").unwrap().parse().unwrap();
 
+                let track_loading = provide_is_suspended(set_is_loading, on_resolve);
                 let (view, suspense_scope) = HydrationRegistry::in_suspense_scope(key, move || create_suspense_scope(move || children.call()));
                 let is_loading = suspense_scope.is_loading();
-
-                create_effect(move || set_is_loading(is_loading.get()));
+                track_loading(is_loading);
+                let timed_out = track_timeout(is_loading, timeout, on_timeout.is_none());
+                let fallback = fallback_view(fallback, timeout, on_timeout, timed_out);
 
                 view! {
                     NoSsr {
                         Show(when=move || is_loading.get()) {
-                            (fallback())
+                            (fallback)
                         }
                     }
                     Show(when=move || !is_loading.get()) {
@@ -187,14 +526,24 @@ pub fn Suspense(props: SuspenseProps) -> View {
 
 /// `Transition` is like [`Suspense`] except that it keeps the previous content visible until the
 /// new content is ready.
+///
+/// The pending state of the transition can be accessed by the children with
+/// [`use_transition_pending`], e.g. to dim the previous content or show a loading indicator.
 #[component]
-pub fn Transition(props: SuspenseProps) -> View {
+pub fn Transition(props: TransitionProps) -> View {
     /// Only trigger outer suspense on initial render. In subsequent renders, capture the suspense
     /// scope.
     #[component(inline_props)]
-    fn TransitionInner(children: Children, set_is_loading: Box<dyn FnMut(bool)>) -> View {
-        // TODO: Workaround for https://github.com/sycamore-rs/sycamore/issues/718.
-        let mut set_is_loading = set_is_loading;
+    fn TransitionInner(
+        children: Children,
+        set_is_loading: Box<dyn FnMut(bool)>,
+        on_resolve: Box<dyn FnMut()>,
+        min_duration: Duration,
+    ) -> View {
+        // The inner suspense scope shadows the suspended state of the outer `Suspense`.
+        let track_loading = provide_is_suspended(set_is_loading, on_resolve);
+        let pending = create_signal(false);
+        provide_context(TransitionPending(*pending));
 
         // We create a detached suspense scope here to not create a deadlock with the outer
         // suspense.
@@ -203,10 +552,8 @@ pub fn Transition(props: SuspenseProps) -> View {
         // and future renders will be captured by the inner suspense scope.
         create_suspense_task(scope.until_finished());
 
-        let is_loading = scope.is_loading();
-        create_effect(move || {
-            set_is_loading(is_loading.get());
-        });
+        track_loading(scope.is_loading());
+        sync_pending(scope.is_loading(), pending, min_duration);
 
         view! {
             (children)
@@ -215,11 +562,85 @@ pub fn Transition(props: SuspenseProps) -> View {
 
     view! {
         Suspense(fallback=props.fallback, children=Children::new(move || {
-            view! { TransitionInner(children=props.children, set_is_loading=props.set_is_loading) }
+            view! { TransitionInner(children=props.children, set_is_loading=props.set_is_loading, on_resolve=props.on_resolve, min_duration=props.min_duration) }
         }))
     }
 }
 
+/// Props for [`SkeletonSuspense`].
+#[derive(Props)]
+pub struct SkeletonSuspenseProps {
+    /// The skeleton [`View`] to display in place of the content while it is being awaited. This
+    /// should mirror the layout of the content to avoid layout shift.
+    #[prop(default = Box::new(|| view! {}), setter(transform = |f: impl Fn() -> View + 'static| Box::new(f) as Box<dyn Fn() -> View>))]
+    skeleton: Box<dyn Fn() -> View>,
+    /// The classes of the container element.
+    #[prop(default, setter(into))]
+    class: String,
+    children: Children,
+}
+
+/// A [`Suspense`] that renders the skeleton and the content inside the same container element.
+///
+/// The container is a `<div>` with an `aria-busy` attribute that is `"true"` while the skeleton
+/// is shown and `"false"` once the content is ready. Since the content replaces the skeleton in
+/// place, the container keeps its position and size, and the attribute can be used to fade between
+/// the two with CSS, e.g.
+///
+/// ```css
+/// .card[aria-busy="false"] > * { animation: fade-in 150ms; }
+/// ```
+///
+/// With streaming SSR, the skeleton is rendered inside the container and the streamed content
+/// replaces it there. The `aria-busy` attribute is only updated once the page is hydrated. In
+/// blocking SSR mode, the content is rendered directly and `aria-busy` is `"false"`.
+///
+/// # Example
+/// ```
+/// use sycamore::prelude::*;
+/// use sycamore::web::SkeletonSuspense;
+///
+/// #[component]
+/// async fn Profile() -> View {
+///     view! { img(src="avatar.png") p { "Name" } }
+/// }
+///
+/// #[component]
+/// fn App() -> View {
+///     view! {
+///         SkeletonSuspense(
+///             class="profile",
+///             skeleton=|| view! { div(class="avatar-skeleton") div(class="line-skeleton") },
+///         ) {
+///             Profile {}
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn SkeletonSuspense(props: SkeletonSuspenseProps) -> View {
+    let SkeletonSuspenseProps {
+        skeleton,
+        class,
+        children,
+    } = props;
+
+    // `Suspense` only reports the loading state on the client. On the server, the skeleton is
+    // rendered unless the content is awaited before rendering (blocking mode).
+    let is_loading =
+        create_signal(is_ssr!() && !matches!(use_context::<SsrMode>(), SsrMode::Blocking));
+
+    view! {
+        div(class=class, aria-busy=move || is_loading.get().to_string()) {
+            Suspense(
+                fallback=skeleton,
+                set_is_loading=move |loading| is_loading.set(loading),
+                children=children,
+            )
+        }
+    }
+}
+
 /// Convert an async component to a regular sync component. Also wraps the async component inside a
 /// suspense scope so that content is properly suspended.
 #[component]
@@ -261,6 +682,62 @@ pub fn WrapAsync<F: Future<Output = View>>(f: impl FnOnce() -> F + 'static) -> V
     }
 }
 
+/// Create a lazily loaded component.
+///
+/// `loader` is an async function that resolves to the component, e.g. after loading the code for
+/// it with a dynamic `import()`. It is not called until the returned function is first called to
+/// render the component. Until the component is loaded, the enclosing [`Suspense`] or
+/// [`Transition`] displays its fallback. Once loaded, the component is cached, so later renders
+/// (e.g. when navigating back to a route) render it directly without suspending again.
+///
+/// If the component is rendered again while it is still loading, `loader` is called again.
+///
+/// # Example
+/// ```
+/// use sycamore::prelude::*;
+/// use sycamore::web::{lazy, Suspense};
+///
+/// #[component]
+/// fn Editor() -> View {
+///     view! { textarea {} }
+/// }
+///
+/// #[component]
+/// fn App() -> View {
+///     // In a real app, this would load the code for the editor first.
+///     let editor = lazy(|| async { Editor });
+///     view! {
+///         Suspense(fallback=|| view! { "Loading editor..." }) {
+///             (editor())
+///         }
+///     }
+/// }
+/// ```
+pub fn lazy<L, Fut, C>(loader: L) -> impl Fn() -> View + Clone + 'static
+where
+    L: Fn() -> Fut + 'static,
+    Fut: Future<Output = C> + 'static,
+    C: Fn() -> View + Clone + 'static,
+{
+    let loader = Rc::new(loader);
+    let component = Rc::new(RefCell::new(None::<C>));
+    move || {
+        let loaded = component.borrow().clone();
+        match loaded {
+            Some(component) => untrack(component),
+            None => {
+                let loader = Rc::clone(&loader);
+                let component = Rc::clone(&component);
+                WrapAsync(move || async move {
+                    let loaded = loader().await;
+                    *component.borrow_mut() = Some(loaded.clone());
+                    untrack(loaded)
+                })
+            }
+        }
+    }
+}
+
 /// Represents a streamed suspense view fragment.
 #[cfg_ssr]
 pub(crate) struct SuspenseFragment {
@@ -311,3 +788,85 @@ pub fn use_suspense_key() -> NonZeroU32 {
     counter.next.set(next.checked_add(1).unwrap());
     next
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn on_resolve_called_after_each_suspension() {
+        let _ = create_root(|| {
+            let resolved = Rc::new(Cell::new(0));
+            let is_loading = create_signal(false);
+            let track_loading = provide_is_suspended(Box::new(|_| {}), {
+                let resolved = Rc::clone(&resolved);
+                Box::new(move || resolved.set(resolved.get() + 1))
+            });
+            track_loading(*is_loading);
+            let is_suspended = use_is_suspended();
+
+            // Not called if the children were never suspended.
+            assert_eq!(resolved.get(), 0);
+
+            is_loading.set(true);
+            assert!(is_suspended.get());
+            assert_eq!(resolved.get(), 0);
+            is_loading.set(false);
+            assert!(!is_suspended.get());
+            assert_eq!(resolved.get(), 1);
+
+            // Suspended again.
+            is_loading.set(true);
+            is_loading.set(false);
+            assert_eq!(resolved.get(), 2);
+        });
+    }
+
+    #[test]
+    fn use_is_suspended_outside_suspense() {
+        let _ = create_root(|| {
+            assert!(!use_is_suspended().get());
+        });
+    }
+
+    #[test]
+    fn transition_pending_follows_loading_in_ssr() {
+        let _ = create_root(|| {
+            assert!(!use_transition_pending().get());
+
+            let is_loading = create_signal(false);
+            let pending = create_signal(false);
+            provide_context(TransitionPending(*pending));
+            // The minimum duration only applies on the client.
+            sync_pending(*is_loading, pending, Duration::from_secs(1));
+            let pending = use_transition_pending();
+
+            is_loading.set(true);
+            assert!(pending.get());
+            is_loading.set(false);
+            assert!(!pending.get());
+        });
+    }
+
+    #[test]
+    fn transition_not_pending_during_initial_load() {
+        let _ = create_root(|| {
+            let is_loading = create_signal(true);
+            let pending = create_signal(false);
+            sync_pending(*is_loading, pending, Duration::ZERO);
+
+            // The fallback is shown during the initial load.
+            assert!(!pending.get());
+            is_loading.set(false);
+            assert!(!pending.get());
+
+            is_loading.set(true);
+            assert!(pending.get());
+            is_loading.set(false);
+            assert!(!pending.get());
+        });
+    }
+}
@@ -0,0 +1,316 @@
+//! Timers and signals that are derived from other signals over time.
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use crate::*;
+
+/// Options for [`create_throttled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottleOptions {
+    /// Whether to emit the first change immediately, at the start of the interval.
+    pub leading: bool,
+    /// Whether to emit the latest value at the end of the interval if the source changed during
+    /// the interval.
+    pub trailing: bool,
+}
+
+impl Default for ThrottleOptions {
+    /// Both `leading` and `trailing` are enabled by default.
+    fn default() -> Self {
+        Self {
+            leading: true,
+            trailing: true,
+        }
+    }
+}
+
+/// Creates a signal that follows `source` but is updated at most once per `interval`.
+///
+/// When `source` changes and no interval is running, an interval is started. If
+/// [`leading`](ThrottleOptions::leading) is enabled, the new value is emitted immediately. Further
+/// changes during the interval are not emitted. If [`trailing`](ThrottleOptions::trailing) is
+/// enabled and `source` changed during the interval, its latest value is emitted at the end of the
+/// interval, which also starts a new interval.
+///
+/// Pending timers are cleared when the current reactive scope is disposed. In SSR mode, `source`
+/// is returned as is.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use sycamore::prelude::*;
+/// # use sycamore_web::{create_throttled, ThrottleOptions};
+/// # fn Comp() -> View {
+/// let scroll_y = create_signal(0.0);
+/// // Update `scroll_y` from a `scroll` event listener here.
+/// let throttled = create_throttled(*scroll_y, Duration::from_millis(100), ThrottleOptions::default());
+///
+/// create_effect(move || console_log!("scrolled to {}", throttled.get()));
+/// # view! {}
+/// # }
+/// ```
+pub fn create_throttled<T: Clone + 'static>(
+    source: ReadSignal<T>,
+    interval: Duration,
+    options: ThrottleOptions,
+) -> ReadSignal<T> {
+    if is_ssr!() {
+        return source;
+    }
+
+    let output = create_signal(source.get_clone_untracked());
+    let timeout = i32::try_from(interval.as_millis()).unwrap_or(i32::MAX);
+
+    // The id of the timer for the current interval, or `None` if no interval is running.
+    let timer_id = Rc::new(Cell::new(None::<i32>));
+    // Whether `source` changed during the current interval.
+    let is_pending = Rc::new(Cell::new(false));
+    let on_timeout = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+
+    let start_interval = {
+        let timer_id = Rc::clone(&timer_id);
+        let on_timeout = Rc::clone(&on_timeout);
+        move || {
+            if let Some(on_timeout) = on_timeout.borrow().as_ref() {
+                let id = window()
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        on_timeout.as_ref().unchecked_ref(),
+                        timeout,
+                    )
+                    .unwrap_throw();
+                timer_id.set(Some(id));
+            }
+        }
+    };
+
+    *on_timeout.borrow_mut() = Some(Closure::new({
+        let timer_id = Rc::clone(&timer_id);
+        let is_pending = Rc::clone(&is_pending);
+        let start_interval = start_interval.clone();
+        move || {
+            timer_id.set(None);
+            if is_pending.replace(false) && options.trailing {
+                output.set(source.get_clone_untracked());
+                start_interval();
+            }
+        }
+    }));
+
+    let mut is_initial = true;
+    create_effect({
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            source.track();
+            if std::mem::replace(&mut is_initial, false) {
+                return;
+            }
+            if timer_id.get().is_some() {
+                is_pending.set(true);
+            } else {
+                if options.leading {
+                    untrack(|| output.set(source.get_clone_untracked()));
+                } else {
+                    is_pending.set(true);
+                }
+                start_interval();
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(id) = timer_id.take() {
+            window().clear_timeout_with_handle(id);
+        }
+        // Drop the timeout callback to break the reference cycle with `start_interval`.
+        on_timeout.borrow_mut().take();
+    });
+
+    *output
+}
+
+/// A handle to an interval created with [`use_interval`].
+///
+/// The handle can be used to pause and resume the interval. The interval is only cleared for good
+/// once the enclosing reactive scope is disposed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    running: Signal<bool>,
+}
+
+impl Interval {
+    /// Pause the interval. The callback will not be called until [`resume`](Self::resume) is
+    /// called.
+    pub fn pause(&self) {
+        self.running.set(false);
+    }
+
+    /// Resume the interval if it was previously paused. The next call happens one full period
+    /// after resuming.
+    pub fn resume(&self) {
+        self.running.set(true);
+    }
+
+    /// Returns `true` if the interval has not been paused. This value is reactive and can be
+    /// tracked.
+    pub fn is_running(&self) -> bool {
+        self.running.get()
+    }
+}
+
+/// Calls `f` repeatedly, every `period`, until the current reactive scope is disposed.
+///
+/// The period can also be reactive, e.g. a `ReadSignal<Duration>`. Whenever it changes, the
+/// interval is restarted with the new period. The returned [`Interval`] can be used to pause and
+/// resume the interval.
+///
+/// In SSR mode, `f` is never called.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_interval;
+/// #[component]
+/// fn Clock() -> View {
+///     let mut seconds = create_signal(0);
+///     let interval = use_interval(Duration::from_secs(1), move || seconds += 1);
+///     view! {
+///         p { (seconds) "s" }
+///         button(on:click=move |_| interval.pause()) { "Stop" }
+///     }
+/// }
+/// ```
+pub fn use_interval(
+    period: impl Into<MaybeDyn<Duration>>,
+    mut f: impl FnMut() + 'static,
+) -> Interval {
+    let running = create_signal(true);
+    if is_ssr!() {
+        let _ = (period, f);
+        return Interval { running };
+    }
+
+    let period = period.into();
+    let timer_id = Rc::new(Cell::new(None::<i32>));
+    let callback: Closure<dyn FnMut()> = Closure::new(move || untrack(&mut f));
+    let clear_timer = {
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            if let Some(id) = timer_id.take() {
+                window().clear_interval_with_handle(id);
+            }
+        }
+    };
+
+    create_effect({
+        let clear_timer = clear_timer.clone();
+        move || {
+            clear_timer();
+            if running.get() {
+                let timeout = i32::try_from(period.get().as_millis()).unwrap_or(i32::MAX);
+                let id = window()
+                    .set_interval_with_callback_and_timeout_and_arguments_0(
+                        callback.as_ref().unchecked_ref(),
+                        timeout,
+                    )
+                    .unwrap_throw();
+                timer_id.set(Some(id));
+            }
+        }
+    });
+
+    on_cleanup(clear_timer);
+
+    Interval { running }
+}
+
+/// Calls `f` once after `delay`, unless the current reactive scope is disposed before that.
+///
+/// In SSR mode, `f` is never called.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::use_timeout;
+/// #[component]
+/// fn Toast() -> View {
+///     let visible = create_signal(true);
+///     use_timeout(Duration::from_secs(3), move || visible.set(false));
+///     view! {
+///         (if visible.get() { view! { div(class="toast") { "Saved!" } } } else { view! {} })
+///     }
+/// }
+/// ```
+pub fn use_timeout(delay: Duration, f: impl FnOnce() + 'static) {
+    if is_ssr!() {
+        let _ = (delay, f);
+        return;
+    }
+
+    let timer_id = Rc::new(Cell::new(None::<i32>));
+    let mut f = Some(f);
+    let callback: Closure<dyn FnMut()> = Closure::new({
+        let timer_id = Rc::clone(&timer_id);
+        move || {
+            timer_id.set(None);
+            if let Some(f) = f.take() {
+                untrack(f);
+            }
+        }
+    });
+    let timeout = i32::try_from(delay.as_millis()).unwrap_or(i32::MAX);
+    let id = window()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.as_ref().unchecked_ref(),
+            timeout,
+        )
+        .unwrap_throw();
+    timer_id.set(Some(id));
+
+    on_cleanup(move || {
+        if let Some(id) = timer_id.take() {
+            window().clear_timeout_with_handle(id);
+        }
+        // The callback must live until the timeout has fired or has been cleared.
+        drop(callback);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttled_is_pass_through_in_ssr() {
+        let _ = create_root(|| {
+            let source = create_signal(0);
+            let throttled = create_throttled(
+                *source,
+                Duration::from_millis(100),
+                ThrottleOptions::default(),
+            );
+            assert_eq!(throttled.get(), 0);
+
+            source.set(1);
+            source.set(2);
+            assert_eq!(throttled.get(), 2);
+        });
+    }
+
+    #[test]
+    fn timers_are_noop_in_ssr() {
+        let _ = create_root(|| {
+            let interval =
+                use_interval(Duration::from_millis(10), || panic!("should not be called"));
+            assert!(interval.is_running());
+            interval.pause();
+            assert!(!interval.is_running());
+
+            let period = create_signal(Duration::from_millis(10));
+            use_interval(*period, || panic!("should not be called"));
+            use_timeout(Duration::ZERO, || panic!("should not be called"));
+        });
+    }
+}
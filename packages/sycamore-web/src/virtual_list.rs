@@ -0,0 +1,262 @@
+//! Virtualized rendering of large lists.
+
+#![allow(non_snake_case)]
+
+use std::cell::RefCell;
+use std::hash::Hash;
+
+use sycamore_macro::{component, Props};
+
+use crate::*;
+
+/// Props for [`VirtualList`].
+#[derive(Props)]
+pub struct VirtualListProps<T, K, U, List, F, Key>
+where
+    List: Into<MaybeDyn<Vec<T>>> + 'static,
+    F: Fn(T) -> U + 'static,
+    Key: Fn(&T) -> K + 'static,
+    T: 'static,
+{
+    /// The items of the list.
+    list: List,
+    /// The function that creates the view of an item.
+    view: F,
+    /// The function that returns the key of an item. See [`Keyed`].
+    key: Key,
+    /// The height of every item, in CSS pixels.
+    item_height: f64,
+    /// How many items to render above and below the visible items, to avoid flickering while
+    /// scrolling quickly. Defaults to 3.
+    #[prop(default = 3)]
+    overscan: usize,
+    /// The classes of the scroll container. The container must have a fixed height for the list
+    /// to be virtualized, e.g. by setting the `height` or `max-height` in CSS.
+    #[prop(default, setter(into))]
+    class: String,
+    #[prop(default)]
+    _phantom: std::marker::PhantomData<(T, K, U)>,
+}
+
+/// Keyed iteration that only renders the items that are visible.
+///
+/// This is useful for lists with thousands of items, where rendering all of them would be slow.
+/// The items are rendered inside a scrollable `<div>` container. Only the items that are
+/// scrolled into view, plus an overscan buffer of [`overscan`](VirtualListPropsBuilder::overscan)
+/// items above and below, are rendered with [`Keyed`]. The rendered items are updated when the
+/// container is scrolled or resized.
+///
+/// Every item must have the same height, which is given by `item_height`.
+///
+/// When items are inserted or removed above the visible items, e.g. when older messages are
+/// prepended to a chat, the scroll offset is adjusted so that the same items stay in view.
+///
+/// In SSR mode, only the first `overscan` items are rendered since the height of the container is
+/// not known.
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::VirtualList;
+/// # fn App() -> View {
+/// let rows = create_signal((0..10_000).collect::<Vec<u32>>());
+/// view! {
+///     VirtualList(
+///         list=rows,
+///         view=|row| view! {
+///             div(style="height: 24px;") { "Row " (row) }
+///         },
+///         key=|row| *row,
+///         item_height=24.0,
+///         class="rows",
+///     )
+/// }
+/// # }
+/// ```
+#[component]
+pub fn VirtualList<T, K, U, List, F, Key>(props: VirtualListProps<T, K, U, List, F, Key>) -> View
+where
+    T: PartialEq + Clone + 'static,
+    K: Hash + Eq + 'static,
+    U: Into<View>,
+    List: Into<MaybeDyn<Vec<T>>> + 'static,
+    F: Fn(T) -> U + 'static,
+    Key: Fn(&T) -> K + 'static,
+{
+    let VirtualListProps {
+        list,
+        view,
+        key,
+        item_height,
+        overscan,
+        class,
+        ..
+    } = props;
+    assert!(item_height > 0.0, "item_height must be positive");
+
+    let list = list.into();
+    let items = create_memo(move || list.get_clone());
+    let key = Rc::new(key);
+
+    let container = create_node_ref();
+    let scroll_top = create_signal(0.0);
+    let container_size = use_element_size(container);
+
+    let range = create_memo(move || {
+        visible_range(
+            items.with(Vec::len),
+            scroll_top.get(),
+            container_size.get().1,
+            item_height,
+            overscan,
+        )
+    });
+    let visible = create_memo(move || {
+        let (start, end) = range.get();
+        items.with(|items| items[start..end].to_vec())
+    });
+
+    if !is_ssr!() {
+        // The key and index of the first visible item, used to keep it in view when the items
+        // before it change.
+        let anchor = Rc::new(RefCell::new(None::<(K, usize)>));
+        let set_anchor = {
+            let anchor = Rc::clone(&anchor);
+            let key = Rc::clone(&key);
+            move |items: &[T], scroll: f64| {
+                let first = (scroll / item_height) as usize;
+                *anchor.borrow_mut() = items.get(first).map(|item| (key(item), first));
+            }
+        };
+        create_effect({
+            let set_anchor = set_anchor.clone();
+            move || {
+                let scroll = scroll_top.get();
+                items.with_untracked(|items| set_anchor(items, scroll));
+            }
+        });
+        // The scroll offset is read untracked because this effect also updates it.
+        let key = Rc::clone(&key);
+        create_effect(move || {
+            items.with(|items| {
+                let mut scroll = scroll_top.get_untracked();
+                if let Some((anchor_key, index)) = anchor.take() {
+                    let new_index = find_anchor(items, &*key, &anchor_key, index);
+                    if let Some(new_index) = new_index.filter(|new_index| *new_index != index) {
+                        scroll += (new_index as f64 - index as f64) * item_height;
+                        if let Some(container) = untrack(|| container.get_as::<web_sys::Element>())
+                        {
+                            container.set_scroll_top(scroll.round() as _);
+                        }
+                        scroll_top.set(scroll);
+                    }
+                }
+                set_anchor(items, scroll);
+            });
+        });
+    }
+
+    let on_scroll = move |_| {
+        if let Some(container) = container.get_as::<web_sys::Element>() {
+            scroll_top.set(container.scroll_top().into());
+        }
+    };
+    let height = move || {
+        format!(
+            "position: relative; height: {}px;",
+            items.with(Vec::len) as f64 * item_height
+        )
+    };
+    let offset = move || {
+        format!(
+            "position: absolute; top: 0; left: 0; right: 0; transform: translateY({}px);",
+            range.get().0 as f64 * item_height
+        )
+    };
+
+    view! {
+        div(r#ref=container, class=class, style="overflow-y: auto;", on:scroll=on_scroll) {
+            div(style=height) {
+                div(style=offset) {
+                    Keyed(list=visible, view=view, key=move |item| key(item))
+                }
+            }
+        }
+    }
+}
+
+/// Returns the range of items to render, given the scroll offset and the height of the container.
+fn visible_range(
+    len: usize,
+    scroll_top: f64,
+    container_height: f64,
+    item_height: f64,
+    overscan: usize,
+) -> (usize, usize) {
+    // Float to integer casts saturate, so a negative scroll offset (e.g. from overscrolling on
+    // macOS) results in 0.
+    let first = (scroll_top / item_height).floor() as usize;
+    let last = ((scroll_top + container_height) / item_height).ceil() as usize;
+    let start = first.saturating_sub(overscan).min(len);
+    let end = last.saturating_add(overscan).min(len);
+    (start, end)
+}
+
+/// Finds the new index of the item with key `anchor`, which was at `index` before the list
+/// changed.
+fn find_anchor<T, K: PartialEq>(
+    items: &[T],
+    key: impl Fn(&T) -> K,
+    anchor: &K,
+    index: usize,
+) -> Option<usize> {
+    // Fast path for when the items before the anchor did not change.
+    if items.get(index).is_some_and(|item| key(item) == *anchor) {
+        return Some(index);
+    }
+    items.iter().position(|item| key(item) == *anchor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_range_includes_overscan() {
+        assert_eq!(visible_range(100, 0.0, 100.0, 10.0, 3), (0, 13));
+        assert_eq!(visible_range(100, 205.0, 100.0, 10.0, 3), (17, 34));
+        assert_eq!(visible_range(100, 950.0, 100.0, 10.0, 3), (92, 100));
+        assert_eq!(visible_range(100, -20.0, 100.0, 10.0, 0), (0, 8));
+        assert_eq!(visible_range(0, 0.0, 100.0, 10.0, 3), (0, 0));
+        assert_eq!(visible_range(100, 5000.0, 100.0, 10.0, 3), (100, 100));
+    }
+
+    #[test]
+    fn find_anchor_after_prepend() {
+        let id = |item: &u32| *item;
+        assert_eq!(find_anchor(&[1, 2, 3], id, &2, 1), Some(1));
+        assert_eq!(find_anchor(&[-1i32 as u32, 0, 1, 2, 3], id, &2, 1), Some(3));
+        assert_eq!(find_anchor(&[2, 3], id, &2, 1), Some(0));
+        assert_eq!(find_anchor(&[1, 3], id, &2, 1), None);
+    }
+
+    #[test]
+    fn virtual_list_ssr_renders_first_items() {
+        let rows = (0..100).collect::<Vec<u32>>();
+        let html = render_to_string(move || {
+            view! {
+                VirtualList(
+                    list=rows,
+                    view=|row| view! { p { (row) } },
+                    key=|row| *row,
+                    item_height=20.0,
+                    overscan=2,
+                    class="rows",
+                )
+            }
+        });
+        assert!(html.contains(">0</p>") && html.contains(">1</p>"));
+        assert!(!html.contains(">2</p>"));
+        assert!(html.contains("height: 2000px;"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Reactive WebSocket connections.
+
+use std::cell::RefCell;
+use std::rc::Weak;
+use std::time::Duration;
+
+use web_sys::{MessageEvent, WebSocket};
+
+use crate::*;
+
+/// The state of a WebSocket connection created with [`use_websocket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsState {
+    /// The connection is being established.
+    Connecting,
+    /// The connection is open and messages can be sent.
+    Open,
+    /// The connection has been closed or could not be established.
+    Closed,
+}
+
+/// Options for [`use_websocket_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebSocketOptions {
+    /// Whether to reconnect when the connection is closed. Disabled by default.
+    pub reconnect: bool,
+    /// The delay before the first reconnection attempt. The delay is doubled after every failed
+    /// attempt, up to `max_reconnect_delay`, and is reset once the connection is open again.
+    /// Defaults to 1 second.
+    pub reconnect_delay: Duration,
+    /// The maximum delay between reconnection attempts. Defaults to 30 seconds.
+    pub max_reconnect_delay: Duration,
+}
+
+impl Default for WebSocketOptions {
+    fn default() -> Self {
+        Self {
+            reconnect: false,
+            reconnect_delay: Duration::from_secs(1),
+            max_reconnect_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A handle to a WebSocket connection created with [`use_websocket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebSocketHandle {
+    /// The last text message that was received, or `None` if no message has been received yet.
+    pub message: ReadSignal<Option<String>>,
+    /// The state of the connection.
+    pub state: ReadSignal<WsState>,
+    socket: Signal<Option<WebSocket>>,
+}
+
+impl WebSocketHandle {
+    /// Sends a text message.
+    ///
+    /// Messages can only be sent while the connection is [`Open`](WsState::Open). Otherwise, the
+    /// message is dropped and a warning is logged.
+    pub fn send(&self, data: &str) {
+        let sent = self.socket.with_untracked(|socket| match socket {
+            Some(socket) if socket.ready_state() == WebSocket::OPEN => {
+                socket.send_with_str(data).is_ok()
+            }
+            _ => false,
+        });
+        if !sent {
+            console_warn!("WebSocket is not open, message was dropped");
+        }
+    }
+}
+
+/// Opens a WebSocket connection to `url`.
+///
+/// The returned [`WebSocketHandle`] contains a signal with the last received text message and a
+/// signal with the state of the connection. Binary messages are ignored. The connection is closed
+/// when the current reactive scope is disposed. To reconnect automatically when the connection is
+/// lost, use [`use_websocket_with_options`].
+///
+/// In SSR mode, no connection is opened and the state is always [`WsState::Connecting`].
+///
+/// # Example
+/// ```
+/// # use sycamore::prelude::*;
+/// # use sycamore::web::{use_websocket, WsState};
+/// #[component]
+/// fn Chat() -> View {
+///     let ws = use_websocket("wss://example.com/chat");
+///     view! {
+///         p { (ws.message.get_clone().unwrap_or_default()) }
+///         button(
+///             disabled=move || ws.state.get() != WsState::Open,
+///             on:click=move |_| ws.send("Hello!"),
+///         ) {
+///             "Say hello"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_websocket(url: &str) -> WebSocketHandle {
+    use_websocket_with_options(url, WebSocketOptions::default())
+}
+
+/// Like [`use_websocket`], but with additional [`WebSocketOptions`].
+pub fn use_websocket_with_options(url: &str, options: WebSocketOptions) -> WebSocketHandle {
+    let message = create_signal(None);
+    let state = create_signal(WsState::Connecting);
+    let socket = create_signal(None);
+    let handle = WebSocketHandle {
+        message: *message,
+        state: *state,
+        socket,
+    };
+    if is_ssr!() {
+        let _ = (url, options);
+        return handle;
+    }
+
+    let connection = Rc::new(Connection {
+        url: url.to_string(),
+        options,
+        message,
+        state,
+        socket,
+        delay: Cell::new(options.reconnect_delay),
+        timer_id: Cell::new(None),
+        handlers: RefCell::new(Vec::new()),
+        on_reconnect: RefCell::new(None),
+    });
+    let weak = Rc::downgrade(&connection);
+    *connection.on_reconnect.borrow_mut() = Some(Closure::new(move || {
+        if let Some(connection) = weak.upgrade() {
+            connection.timer_id.set(None);
+            Connection::connect(&connection);
+        }
+    }));
+    Connection::connect(&connection);
+
+    on_cleanup(move || connection.dispose());
+
+    handle
+}
+
+type EventHandler = Closure<dyn FnMut(JsValue)>;
+
+/// The state that is shared between the event handlers of a connection.
+struct Connection {
+    url: String,
+    options: WebSocketOptions,
+    message: Signal<Option<String>>,
+    state: Signal<WsState>,
+    socket: Signal<Option<WebSocket>>,
+    /// The delay before the next reconnection attempt.
+    delay: Cell<Duration>,
+    /// The id of the timer for the next reconnection attempt, if any.
+    timer_id: Cell<Option<i32>>,
+    /// The event handlers of the current socket.
+    handlers: RefCell<Vec<EventHandler>>,
+    on_reconnect: RefCell<Option<Closure<dyn FnMut()>>>,
+}
+
+impl Connection {
+    fn connect(this: &Rc<Self>) {
+        this.state.set(WsState::Connecting);
+        let socket = match WebSocket::new(&this.url) {
+            Ok(socket) => socket,
+            Err(err) => {
+                console_error!("could not open WebSocket to `{}`: {err:?}", this.url);
+                this.state.set(WsState::Closed);
+                this.schedule_reconnect();
+                return;
+            }
+        };
+
+        let handler = |f: fn(&Self, JsValue)| {
+            let weak: Weak<Self> = Rc::downgrade(this);
+            EventHandler::new(move |event| {
+                if let Some(connection) = weak.upgrade() {
+                    f(&connection, event);
+                }
+            })
+        };
+        let on_open = handler(|this, _| {
+            this.delay.set(this.options.reconnect_delay);
+            this.state.set(WsState::Open);
+        });
+        let on_message = handler(|this, event| {
+            let data = event.unchecked_into::<MessageEvent>().data();
+            if let Some(text) = data.as_string() {
+                this.message.set(Some(text));
+            }
+        });
+        let on_error = handler(|this, _| this.state.set(WsState::Closed));
+        let on_close = handler(|this, _| {
+            this.state.set(WsState::Closed);
+            this.schedule_reconnect();
+        });
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+        *this.handlers.borrow_mut() = vec![on_open, on_message, on_error, on_close];
+        this.socket.set(Some(socket));
+    }
+
+    fn schedule_reconnect(&self) {
+        if !self.options.reconnect || self.timer_id.get().is_some() {
+            return;
+        }
+        let delay = self.delay.get();
+        self.delay
+            .set((delay * 2).min(self.options.max_reconnect_delay));
+        if let Some(on_reconnect) = self.on_reconnect.borrow().as_ref() {
+            let timeout = i32::try_from(delay.as_millis()).unwrap_or(i32::MAX);
+            let id = window()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    on_reconnect.as_ref().unchecked_ref(),
+                    timeout,
+                )
+                .unwrap_throw();
+            self.timer_id.set(Some(id));
+        }
+    }
+
+    /// Closes the socket and stops reconnecting.
+    fn dispose(&self) {
+        if let Some(id) = self.timer_id.take() {
+            window().clear_timeout_with_handle(id);
+        }
+        if let Some(socket) = self.socket.get_clone_untracked() {
+            socket.set_onopen(None);
+            socket.set_onmessage(None);
+            socket.set_onerror(None);
+            socket.set_onclose(None);
+            let _ = socket.close();
+        }
+        self.handlers.borrow_mut().clear();
+        self.on_reconnect.borrow_mut().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn websocket_does_not_connect_in_ssr() {
+        let _ = create_root(|| {
+            let ws = use_websocket("ws://localhost:1234");
+            assert_eq!(ws.state.get(), WsState::Connecting);
+            assert_eq!(ws.message.get_clone(), None);
+        });
+    }
+}
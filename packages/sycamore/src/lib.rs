@@ -11,7 +11,8 @@
 //!   reduce binary size.
 //!
 //! - `serde` - Enables serializing and deserializing `Signal`s and other wrapper types using
-//!   `serde`.
+//!   `serde`. Also enables deserializing the values of a `<form>` into a struct and signals that are
+//!   persisted to `localStorage`.
 //!
 //! - `suspense` - Enables suspense and resources. Also enables wrappers around
 //!   `wasm-bindgen-futures` to make it easier to extend a reactive scope into an `async` function.
@@ -70,10 +71,14 @@ pub mod futures {
 #[cfg(feature = "hydrate")]
 pub use sycamore_web::{hydrate, hydrate_in_scope, hydrate_to};
 pub use sycamore_web::{
-    render, render_in_scope, render_to, render_to_string, render_to_string_in_scope,
+    render, render_in_scope, render_to, render_to_string, render_to_string_document,
+    render_to_string_in_scope,
 };
 #[cfg(feature = "suspense")]
-pub use sycamore_web::{render_to_string_await_suspense, render_to_string_stream};
+pub use sycamore_web::{
+    render_to_string_await_suspense, render_to_string_await_suspense_document,
+    render_to_string_stream,
+};
 
 /// The Sycamore prelude.
 ///
@@ -90,12 +95,15 @@ pub mod prelude {
     #[cfg(feature = "web")]
     pub use sycamore_web::tags::html_attributes::*;
     #[cfg(feature = "web")]
+    pub use sycamore_web::tags::mathml_attributes::*;
+    #[cfg(feature = "web")]
     pub use sycamore_web::tags::svg_attributes::*;
     #[cfg(feature = "web")]
     pub use sycamore_web::{
-        console_dbg, console_log, create_node_ref, document, is_not_ssr, is_ssr, on_mount, window,
-        Attributes, Children, GlobalAttributes, GlobalProps, HtmlGlobalAttributes, Indexed, Keyed,
-        NodeRef, SvgGlobalAttributes, View,
+        console_dbg, console_log, create_effect_deferred, create_node_ref, document, is_not_ssr,
+        is_ssr, on_mount, on_unmount, window, Attributes, Children, GlobalAttributes, GlobalProps,
+        HtmlGlobalAttributes, Indexed, IndexedWithNeighbors, Keyed, KeyedWithIndex,
+        MathMlGlobalAttributes, NodeRef, SvgGlobalAttributes, View,
     };
 
     pub use crate::reactive::*;
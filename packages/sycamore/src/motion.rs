@@ -8,6 +8,14 @@ use crate::reactive::*;
 /// Type returned by [`create_raf`] and [`create_raf_loop`].
 type RafState = (Signal<bool>, Rc<dyn Fn() + 'static>, Rc<dyn Fn() + 'static>);
 
+/// Type returned by [`create_raf_with_delta`].
+type RafWithDeltaState = (
+    Signal<bool>,
+    Rc<dyn Fn() + 'static>,
+    Rc<dyn Fn() + 'static>,
+    Rc<dyn Fn() + 'static>,
+);
+
 /// Schedule a callback to be called on each animation frame.
 /// Does nothing if not on `wasm32` target.
 ///
@@ -16,55 +24,180 @@ type RafState = (Signal<bool>, Rc<dyn Fn() + 'static>, Rc<dyn Fn() + 'static>);
 /// third item is a function to stop the raf.
 ///
 /// The raf is not started by default. Call the `start` function to initiate the raf.
+///
+/// The browser does not run animation frames while the tab is hidden, so `running` is `false`
+/// while the tab is hidden. The raf is resumed once the tab is visible again, unless `stop` has
+/// been called. Calling `stop` cancels the pending frame, so the callback is never called after
+/// the raf was stopped.
+///
+/// For a raf whose callback receives the time between frames, see [`create_raf_with_delta`]. For
+/// a raf that is tied to the lifetime of the current reactive scope, see
+/// [`create_raf_effect`](crate::web::create_raf_effect).
 pub fn create_raf(mut cb: impl FnMut() + 'static) -> RafState {
+    let (running, start, stop, _) = create_raf_with_delta(move |_| cb());
+    (running, start, stop)
+}
+
+/// Schedule a callback to be called on each animation frame, with the time elapsed since the
+/// previous frame. Does nothing if not on `wasm32` target.
+///
+/// The callback is passed the time elapsed since the previous frame, in milliseconds. The delta
+/// is `0.0` on the first frame after the raf is started or resumed after the tab was hidden, so
+/// that time spent stopped is not counted. This makes it easy to write animations that do not
+/// depend on the frame rate.
+///
+/// Returns a tuple of `(running, start, stop, tick_once)`. The first three items are the same as
+/// for [`create_raf`]. The fourth item is a function that calls the callback once on the next
+/// animation frame without starting the raf, e.g. for stepping through an animation frame by
+/// frame. Its delta is `0.0` as well. If the raf is already running, `tick_once` does nothing.
+pub fn create_raf_with_delta(mut cb: impl FnMut(f64) + 'static) -> RafWithDeltaState {
     let running = create_signal(false);
     let start: Rc<dyn Fn()>;
     let stop: Rc<dyn Fn()>;
+    let tick_once: Rc<dyn Fn()>;
     let _ = &mut cb;
 
     // Only run on wasm32 architecture.
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
     {
-        use std::cell::RefCell;
+        use std::cell::{Cell, RefCell};
 
         use wasm_bindgen::prelude::*;
-
-        use crate::web::window;
-
-        let f = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
-        let g = Rc::clone(&f);
-
-        *g.borrow_mut() = Some(Closure::new(move || {
-            if running.get() {
-                // Verified that scope is still valid. We can access `extended` in here.
-                cb();
-                // Request the next raf frame.
-                window()
-                    .request_animation_frame(
-                        f.borrow().as_ref().unwrap_throw().as_ref().unchecked_ref(),
-                    )
-                    .unwrap_throw();
+        use web_sys::js_sys::Function;
+
+        use crate::web::{document, window};
+
+        // Whether `start` has been called and `stop` has not been called since. Unlike `running`,
+        // this stays `true` while the tab is hidden.
+        let started = Rc::new(Cell::new(false));
+        // The id of the frame that has been requested but not run yet, if any.
+        let frame_id = Rc::new(Cell::new(None::<i32>));
+        let last_time = Rc::new(Cell::new(None::<f64>));
+        let on_frame = Rc::new(RefCell::new(None::<Closure<dyn FnMut(f64)>>));
+
+        let request_frame = {
+            let frame_id = Rc::clone(&frame_id);
+            let on_frame = Rc::clone(&on_frame);
+            move || {
+                if frame_id.get().is_none() {
+                    if let Some(on_frame) = on_frame.borrow().as_ref() {
+                        let id = window()
+                            .request_animation_frame(on_frame.as_ref().unchecked_ref())
+                            .unwrap_throw();
+                        frame_id.set(Some(id));
+                    }
+                }
+            }
+        };
+        let cancel_frame = {
+            let frame_id = Rc::clone(&frame_id);
+            move || {
+                if let Some(id) = frame_id.take() {
+                    window().cancel_animation_frame(id).unwrap_throw();
+                }
+            }
+        };
+
+        *on_frame.borrow_mut() = Some(Closure::new({
+            let frame_id = Rc::clone(&frame_id);
+            let last_time = Rc::clone(&last_time);
+            let request_frame = request_frame.clone();
+            move |timestamp: f64| {
+                frame_id.set(None);
+                let dt = last_time.get().map_or(0.0, |last| timestamp - last);
+                last_time.set(Some(timestamp));
+                cb(dt);
+                // Request the next raf frame, unless the raf was stopped inside the callback.
+                if running.get_untracked() {
+                    request_frame();
+                }
             }
         }));
-        start = Rc::new(move || {
-            if !running.get() {
-                running.set(true);
-                window()
-                    .request_animation_frame(
-                        g.borrow().as_ref().unwrap_throw().as_ref().unchecked_ref(),
-                    )
-                    .unwrap_throw();
+
+        let resume = {
+            let started = Rc::clone(&started);
+            let last_time = Rc::clone(&last_time);
+            let request_frame = request_frame.clone();
+            move || {
+                if started.get() && !document().hidden() {
+                    running.set(true);
+                    last_time.set(None);
+                    request_frame();
+                }
+            }
+        };
+        // Pause the raf while the tab is hidden, since animation frames are not run then anyway.
+        let on_visibility_change: Function = Closure::<dyn FnMut()>::new({
+            let resume = resume.clone();
+            let cancel_frame = cancel_frame.clone();
+            move || {
+                if document().hidden() {
+                    running.set(false);
+                    cancel_frame();
+                } else {
+                    resume();
+                }
+            }
+        })
+        .into_js_value()
+        .unchecked_into();
+
+        start = Rc::new({
+            let started = Rc::clone(&started);
+            let on_visibility_change = on_visibility_change.clone();
+            move || {
+                if !started.replace(true) {
+                    document()
+                        .add_event_listener_with_callback("visibilitychange", &on_visibility_change)
+                        .unwrap_throw();
+                    resume();
+                }
             }
         });
-        stop = Rc::new(move || running.set(false));
+        let remove_listener = {
+            let started = Rc::clone(&started);
+            move || {
+                if started.replace(false) {
+                    document()
+                        .remove_event_listener_with_callback(
+                            "visibilitychange",
+                            &on_visibility_change,
+                        )
+                        .unwrap_throw();
+                }
+            }
+        };
+        stop = Rc::new({
+            let cancel_frame = cancel_frame.clone();
+            let remove_listener = remove_listener.clone();
+            move || {
+                running.set(false);
+                cancel_frame();
+                remove_listener();
+            }
+        });
+        tick_once = Rc::new(move || {
+            if !running.get_untracked() {
+                last_time.set(None);
+                request_frame();
+            }
+        });
+
+        on_cleanup(move || {
+            cancel_frame();
+            remove_listener();
+            // Drop the frame callback to break the reference cycle with `request_frame`.
+            on_frame.borrow_mut().take();
+        });
     }
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     {
         start = Rc::new(move || running.set(true));
         stop = Rc::new(move || running.set(false));
+        tick_once = Rc::new(|| {});
     }
 
-    (running, start, stop)
+    (running, start, stop, tick_once)
 }
 
 /// Schedule a callback to be called on each animation frame.
@@ -81,7 +214,8 @@ pub fn create_raf_loop(mut f: impl FnMut() -> bool + 'static) -> RafState {
         let stop_shared = Rc::clone(&stop_shared);
         move || {
             if !f() {
-                stop_shared.get();
+                let stop: &Rc<dyn Fn()> = stop_shared.get().unwrap();
+                stop();
             }
         }
     });
@@ -90,7 +224,7 @@ pub fn create_raf_loop(mut f: impl FnMut() -> bool + 'static) -> RafState {
 }
 
 /// Create a new [`Tweened`] signal.
-pub fn create_tweened_signal<T: Lerp + Clone>(
+pub fn create_tweened_signal<T: Interpolate + Clone>(
     initial: T,
     transition_duration: std::time::Duration,
     easing_fn: impl Fn(f32) -> f32 + 'static,
@@ -98,11 +232,59 @@ pub fn create_tweened_signal<T: Lerp + Clone>(
     Tweened::new(initial, transition_duration, easing_fn)
 }
 
+/// Create a new [`Spring`] signal.
+pub fn create_spring_signal<T: Interpolate + Clone>(initial: T, config: SpringConfig) -> Spring<T> {
+    Spring::new(initial, config)
+}
+
+/// Describes a type whose values can be animated by [`Tweened`] and [`Spring`] signals.
+///
+/// This is implemented for every type that implements [`Lerp`], which includes floats, integers
+/// and fixed-size arrays of them, as well as for [`Color`]. Implement this trait for custom types
+/// that are not interpolated component-wise, or implement [`Lerp`] to also be able to use the type
+/// inside of arrays.
+///
+/// # Example
+/// ```
+/// # use sycamore::motion::Interpolate;
+/// #[derive(Clone)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl Interpolate for Position {
+///     fn interpolate(&self, target: &Self, t: f32) -> Self {
+///         Self {
+///             x: self.x.interpolate(&target.x, t),
+///             y: self.y.interpolate(&target.y, t),
+///         }
+///     }
+/// }
+/// ```
+pub trait Interpolate {
+    /// Get a value between `self` and `target` at `t`, where `0.0` is `self` and `1.0` is
+    /// `target`.
+    ///
+    /// `0.0 <= t <= 1` for tweened signals, unless the easing function overshoots. Springs can
+    /// overshoot as well and also use `t` outside this range to combine values, so the value
+    /// should be extrapolated.
+    fn interpolate(&self, target: &Self, t: f32) -> Self;
+}
+
+impl<T: Lerp> Interpolate for T {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        self.lerp(target, t)
+    }
+}
+
 /// Describes a trait that can be linearly interpolate between two points.
+///
+/// Every type that implements this trait also implements [`Interpolate`].
 pub trait Lerp {
     /// Get a value between `cx` and `other` at a `scalar`.
     ///
-    /// `0.0 <= scalar <= 1`
+    /// See [`Interpolate::interpolate`] for the range of `scalar`.
     fn lerp(&self, other: &Self, scalar: f32) -> Self;
 }
 
@@ -146,15 +328,68 @@ impl<T: Lerp + Clone, const N: usize> Lerp for [T; N] {
     }
 }
 
+/// An RGBA color that can be animated by [`Tweened`] and [`Spring`] signals.
+///
+/// The color is interpolated component-wise in the sRGB color space. It is formatted as a CSS
+/// color using [`Display`](std::fmt::Display), e.g. `rgba(255, 0, 0, 0.5)`, so it can be used
+/// directly in a `style` attribute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    /// The red component.
+    pub r: u8,
+    /// The green component.
+    pub g: u8,
+    /// The blue component.
+    pub b: u8,
+    /// The alpha component, from `0.0` (transparent) to `1.0` (opaque).
+    pub a: f32,
+}
+
+impl Color {
+    /// Creates an opaque color.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// Creates a color with the given alpha component.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        // Clamp the channels so that overshooting easing functions and springs stay in range.
+        let channel = |from: u8, to: u8| {
+            (from as f32)
+                .lerp(&(to as f32), t)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Self {
+            r: channel(self.r, target.r),
+            g: channel(self.g, target.g),
+            b: channel(self.b, target.b),
+            a: self.a.lerp(&target.a, t).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+    }
+}
+
 /// A state that is interpolated when it is set.
-pub struct Tweened<T: Lerp + Clone + 'static>(Signal<TweenedInner<T>>);
-impl<T: Lerp + Clone> std::fmt::Debug for Tweened<T> {
+pub struct Tweened<T: Interpolate + Clone + 'static>(Signal<TweenedInner<T>>);
+impl<T: Interpolate + Clone> std::fmt::Debug for Tweened<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Tweened").finish()
     }
 }
 
-struct TweenedInner<T: Lerp + Clone + 'static> {
+struct TweenedInner<T: Interpolate + Clone + 'static> {
     value: Signal<T>,
     is_tweening: Signal<bool>,
     raf_state: Option<RafState>,
@@ -162,7 +397,7 @@ struct TweenedInner<T: Lerp + Clone + 'static> {
     easing_fn: Rc<dyn Fn(f32) -> f32>,
 }
 
-impl<T: Lerp + Clone> Tweened<T> {
+impl<T: Interpolate + Clone> Tweened<T> {
     /// Create a new tweened state with the given value.
     ///
     /// End users should use [`Scope::create_tweened_signal`] instead.
@@ -204,11 +439,10 @@ impl<T: Lerp + Clone> Tweened<T> {
             let is_tweening = self.0.with(|this| this.is_tweening.clone());
             let transition_duration_ms = self.0.with(|this| this.transition_duration_ms);
 
-            // If previous raf is still running, call stop() to cancel it.
-            if let Some((running, _, stop)) = &self.0.with(|this| this.raf_state.clone()) {
-                if running.get_untracked() {
-                    stop();
-                }
+            // If previous raf is still running, call stop() to cancel it. The raf is not running
+            // while the tab is hidden, but it would be resumed afterwards, so stop it anyway.
+            if let Some((_, _, stop)) = &self.0.with(|this| this.raf_state.clone()) {
+                stop();
             }
 
             let (running, start, stop) = create_raf_loop(move || {
@@ -218,7 +452,7 @@ impl<T: Lerp + Clone> Tweened<T> {
                 let scalar = since_start as f32 / transition_duration_ms;
 
                 if now < start_time + transition_duration_ms as f64 {
-                    signal.set(start.lerp(&_new_value, easing_fn(scalar)));
+                    signal.set(start.interpolate(&_new_value, easing_fn(scalar)));
                     true
                 } else {
                     signal.set(_new_value.clone());
@@ -261,14 +495,14 @@ impl<T: Lerp + Clone> Tweened<T> {
     }
 }
 
-impl<T: Lerp + Clone + 'static> Clone for Tweened<T> {
+impl<T: Interpolate + Clone + 'static> Clone for Tweened<T> {
     fn clone(&self) -> Self {
         *self
     }
 }
-impl<T: Lerp + Clone + 'static> Copy for Tweened<T> {}
+impl<T: Interpolate + Clone + 'static> Copy for Tweened<T> {}
 
-impl<T: Lerp + Clone + 'static> Clone for TweenedInner<T> {
+impl<T: Interpolate + Clone + 'static> Clone for TweenedInner<T> {
     fn clone(&self) -> Self {
         Self {
             value: self.value,
@@ -279,3 +513,463 @@ impl<T: Lerp + Clone + 'static> Clone for TweenedInner<T> {
         }
     }
 }
+
+/// The physical properties of a [`Spring`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringConfig {
+    /// How strongly the spring pulls the value towards the target. Higher values make the
+    /// animation faster.
+    pub stiffness: f32,
+    /// How strongly the motion is slowed down. Lower values make the value overshoot and bounce
+    /// around the target before settling.
+    pub damping: f32,
+    /// The spring settles once it is close enough to the target and moving slowly enough.
+    ///
+    /// Both are measured relative to the motion at the time the target was last set, so the
+    /// precision does not depend on the units of the value. `0.001` means that the remaining
+    /// distance is below 0.1% of the distance to the target at that time, and that the velocity
+    /// is below 0.1% of that distance per second. The same applies to the motion that is caused by
+    /// the velocity the spring had at that time.
+    pub precision: f32,
+}
+
+impl Default for SpringConfig {
+    /// A spring that settles quickly with barely any overshoot.
+    fn default() -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 26.0,
+            precision: 0.001,
+        }
+    }
+}
+
+// The simulation only runs on `wasm32`, where there are animation frames.
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+impl SpringConfig {
+    /// The size of a single integration step, in seconds. Frames are split up into steps of this
+    /// size so that the simulation is stable regardless of the frame rate.
+    const STEP: f32 = 0.001;
+
+    /// Advances the response of the spring by `dt` seconds.
+    fn advance(&self, mut response: SpringResponse, mut dt: f32) -> SpringResponse {
+        while dt > 0.0 {
+            let step = dt.min(Self::STEP);
+            for (position, velocity) in [
+                (
+                    &mut response.displacement,
+                    &mut response.displacement_velocity,
+                ),
+                (&mut response.momentum, &mut response.momentum_velocity),
+            ] {
+                let force = -self.stiffness * *position - self.damping * *velocity;
+                *velocity += force * step;
+                *position += *velocity * step;
+            }
+            dt -= step;
+        }
+        response
+    }
+
+    /// Returns `true` if the spring has come to rest at the target.
+    fn is_settled(&self, response: SpringResponse) -> bool {
+        [
+            response.displacement,
+            response.displacement_velocity,
+            response.momentum,
+            response.momentum_velocity,
+        ]
+        .iter()
+        .all(|x| x.abs() < self.precision)
+    }
+}
+
+/// How the motion of a spring since the target was last set depends on its state at that time.
+///
+/// The motion of a spring is linear in its initial state. It is the sum of the motion caused by
+/// the initial distance to the target and the motion caused by the initial velocity. Each of
+/// them is the initial value scaled by a factor that only depends on the time and the
+/// [`SpringConfig`], which is what this stores. This allows simulating the spring in value space
+/// with only [`Interpolate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SpringResponse {
+    /// The distance to the target, relative to the initial distance to the target.
+    displacement: f32,
+    /// The velocity caused by the initial distance, relative to that distance, per second.
+    displacement_velocity: f32,
+    /// The distance covered due to the initial velocity, relative to the distance covered in one
+    /// second at the initial velocity.
+    momentum: f32,
+    /// The velocity caused by the initial velocity, relative to that velocity.
+    momentum_velocity: f32,
+}
+
+impl SpringResponse {
+    /// The time over which the velocity of a spring is stored as a distance, in seconds. See
+    /// [`SpringMotion::momentum`]. This is kept short so that the stored value stays close to the
+    /// actual values, e.g. within the range of a color channel.
+    const VELOCITY_TIME: f32 = 1.0 / 60.0;
+
+    /// The response at the time the target is set.
+    const START: Self = Self {
+        displacement: 1.0,
+        displacement_velocity: 0.0,
+        momentum: 0.0,
+        momentum_velocity: 1.0,
+    };
+
+    /// The response of a spring that is at rest at the target.
+    const REST: Self = Self {
+        displacement: 0.0,
+        displacement_velocity: 0.0,
+        momentum: 0.0,
+        momentum_velocity: 0.0,
+    };
+}
+
+/// A state that follows its target value with spring physics when it is set.
+///
+/// Unlike [`Tweened`], the animation does not have a fixed duration. Instead, the value moves
+/// towards the target like a mass attached to a spring, as described by a [`SpringConfig`]. When
+/// the target changes while the value is still moving, the current velocity is kept, which makes
+/// springs a good fit for interactions such as drag and drop.
+pub struct Spring<T: Interpolate + Clone + 'static> {
+    value: Signal<T>,
+    is_animating: Signal<bool>,
+    motion: Signal<SpringMotion<T>>,
+    config: SpringConfig,
+}
+
+impl<T: Interpolate + Clone> std::fmt::Debug for Spring<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Spring")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+/// The state of the animation of a [`Spring`].
+#[derive(Clone)]
+struct SpringMotion<T: Interpolate + Clone + 'static> {
+    /// The value at the time the target was last set.
+    start: T,
+    /// The value that would have been reached [`SpringResponse::VELOCITY_TIME`] after the target
+    /// was last set at the velocity of that time. This stores the velocity in value space.
+    momentum: T,
+    target: T,
+    response: SpringResponse,
+}
+
+// The simulation only runs on `wasm32`, where there are animation frames.
+#[cfg_attr(not(all(target_arch = "wasm32", feature = "web")), allow(dead_code))]
+impl<T: Interpolate + Clone> SpringMotion<T> {
+    fn new(value: T) -> Self {
+        Self {
+            start: value.clone(),
+            momentum: value.clone(),
+            target: value,
+            response: SpringResponse::REST,
+        }
+    }
+
+    /// Returns `base + (a - base) + (b - base)`.
+    fn add(base: &T, a: &T, b: &T) -> T {
+        base.interpolate(&a.interpolate(b, 0.5), 2.0)
+    }
+
+    /// Returns the current value of the spring.
+    fn value(&self) -> T {
+        let response = self.response;
+        let from_displacement = self
+            .start
+            .interpolate(&self.target, 1.0 - response.displacement);
+        let from_momentum = self.start.interpolate(
+            &self.momentum,
+            response.momentum / SpringResponse::VELOCITY_TIME,
+        );
+        Self::add(&self.start, &from_displacement, &from_momentum)
+    }
+
+    /// Returns the value that is reached after [`SpringResponse::VELOCITY_TIME`] at the current
+    /// velocity, starting from `value`.
+    fn current_momentum(&self, value: &T) -> T {
+        let response = self.response;
+        let from_displacement = self.start.interpolate(
+            &self.target,
+            -response.displacement_velocity * SpringResponse::VELOCITY_TIME,
+        );
+        let from_momentum = self
+            .start
+            .interpolate(&self.momentum, response.momentum_velocity);
+        let moved = Self::add(&self.start, &from_displacement, &from_momentum);
+        Self::add(&self.start, &moved, value)
+    }
+
+    /// Sets a new target while keeping the current value and velocity.
+    fn set_target(&mut self, target: T) {
+        let value = self.value();
+        self.momentum = self.current_momentum(&value);
+        self.start = value;
+        self.target = target;
+        self.response = SpringResponse::START;
+    }
+
+    /// Moves the spring to its target and stops it.
+    fn settle(&mut self) {
+        self.start = self.target.clone();
+        self.momentum = self.target.clone();
+        self.response = SpringResponse::REST;
+    }
+
+    /// Advances the spring by `dt` seconds. Returns the new value and whether the spring has
+    /// settled.
+    fn advance(&mut self, config: &SpringConfig, dt: f32) -> (T, bool) {
+        self.response = config.advance(self.response, dt);
+        let settled = config.is_settled(self.response);
+        if settled {
+            self.settle();
+        }
+        (self.value(), settled)
+    }
+}
+
+impl<T: Interpolate + Clone> Spring<T> {
+    /// Create a new spring state with the given value.
+    ///
+    /// End users should use [`create_spring_signal`] instead.
+    pub(crate) fn new(initial: T, config: SpringConfig) -> Self {
+        Self {
+            value: create_signal(initial.clone()),
+            is_animating: create_signal(false),
+            motion: create_signal(SpringMotion::new(initial)),
+            config,
+        }
+    }
+
+    /// Set the target value for the `Spring`. The value will move towards the target on each
+    /// animation frame until the spring settles.
+    ///
+    /// If the spring is already moving due to a previous call to `set()`, it is redirected towards
+    /// the new target without losing its velocity.
+    ///
+    /// To immediately set the value without animating it, use `signal().set(...)` instead.
+    ///
+    /// If not running on `wasm32-unknown-unknown`, the value is set immediately.
+    pub fn set(&self, new_value: T) {
+        self.motion
+            .update_silent(|motion| motion.set_target(new_value));
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use web_sys::js_sys::Date;
+
+            if self.is_animating.get_untracked() {
+                // The running animation picks up the new target on the next frame. This is also
+                // the case if the animation is paused because the tab is hidden.
+                return;
+            }
+
+            let Self {
+                value,
+                is_animating,
+                motion,
+                config,
+            } = *self;
+            let mut last_time = Date::now();
+            let (_, start, _) = create_raf_loop(move || {
+                let now = Date::now();
+                // Clamp the time between frames so that the spring does not jump when the page was
+                // in the background.
+                let dt = ((now - last_time) / 1000.0).min(0.1) as f32;
+                last_time = now;
+
+                let (next, settled) = motion.update_silent(|motion| motion.advance(&config, dt));
+                value.set(next);
+                if settled {
+                    is_animating.set(false);
+                }
+                !settled
+            });
+            start();
+            is_animating.set(true);
+        }
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            self.motion.update_silent(SpringMotion::settle);
+            self.value
+                .set(self.motion.with_untracked(|motion| motion.target.clone()));
+        }
+    }
+
+    /// Alias for `signal().get()`.
+    pub fn get(&self) -> T
+    where
+        T: Copy,
+    {
+        self.signal().get()
+    }
+
+    /// Alias for `signal().get_untracked()`.
+    pub fn get_untracked(&self) -> T
+    where
+        T: Copy,
+    {
+        self.signal().get_untracked()
+    }
+
+    /// Get the inner signal backing the state.
+    pub fn signal(&self) -> Signal<T> {
+        self.value
+    }
+
+    /// Returns `true` if the spring is currently moving towards its target. This value is reactive
+    /// and can be tracked.
+    pub fn is_animating(&self) -> bool {
+        self.is_animating.get()
+    }
+}
+
+impl<T: Interpolate + Clone + 'static> Clone for Spring<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: Interpolate + Clone + 'static> Copy for Spring<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Simulates a spring at 60 frames per second until it settles. Returns the value of each
+    /// frame.
+    fn simulate(config: SpringConfig, motion: &mut SpringMotion<f64>) -> Vec<f64> {
+        let mut frames = Vec::new();
+        loop {
+            let (value, settled) = motion.advance(&config, 1.0 / 60.0);
+            frames.push(value);
+            if settled {
+                return frames;
+            }
+            assert!(
+                frames.len() < 600,
+                "spring did not settle within 10 seconds"
+            );
+        }
+    }
+
+    fn spring_to(target: f64) -> SpringMotion<f64> {
+        let mut motion = SpringMotion::new(0.0);
+        motion.set_target(target);
+        motion
+    }
+
+    /// Returns the velocity of the spring, per second.
+    fn velocity(config: &SpringConfig, motion: &SpringMotion<f64>) -> f64 {
+        let dt = 0.0001;
+        let mut next = SpringMotion::new(0.0);
+        (next.start, next.momentum, next.target, next.response) = (
+            motion.start,
+            motion.momentum,
+            motion.target,
+            motion.response,
+        );
+        (next.advance(config, dt as f32).0 - motion.value()) / dt
+    }
+
+    #[test]
+    fn spring_settles_at_target() {
+        let frames = simulate(SpringConfig::default(), &mut spring_to(100.0));
+        assert_eq!(*frames.last().unwrap(), 100.0);
+        assert!((frames[frames.len() - 2] - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn underdamped_spring_overshoots() {
+        let config = SpringConfig {
+            stiffness: 200.0,
+            damping: 5.0,
+            ..Default::default()
+        };
+        let frames = simulate(config, &mut spring_to(1.0));
+        assert!(frames.iter().any(|&value| value > 1.0));
+
+        let config = SpringConfig {
+            stiffness: 200.0,
+            damping: 40.0,
+            ..Default::default()
+        };
+        let frames = simulate(config, &mut spring_to(1.0));
+        assert!(frames.iter().all(|&value| value <= 1.0));
+    }
+
+    #[test]
+    fn retarget_keeps_velocity() {
+        let config = SpringConfig::default();
+        let mut motion = spring_to(100.0);
+        for _ in 0..5 {
+            motion.advance(&config, 1.0 / 60.0);
+        }
+        let value = motion.value();
+        let velocity_before = velocity(&config, &motion);
+        assert!(velocity_before > 100.0);
+
+        // The new target is much closer, so the velocity would be scaled down if it was relative
+        // to the distance to the target.
+        motion.set_target(value + 1.0);
+        assert!((motion.value() - value).abs() < 1e-3);
+        let velocity_after = velocity(&config, &motion);
+        assert!(
+            (velocity_after - velocity_before).abs() < velocity_before * 0.01,
+            "velocity changed from {velocity_before} to {velocity_after}"
+        );
+
+        // The spring overshoots the new target because of its velocity, but still settles there.
+        let frames = simulate(config, &mut motion);
+        assert!(frames.iter().any(|&frame| frame > value + 1.0));
+        assert_eq!(*frames.last().unwrap(), value + 1.0);
+    }
+
+    #[test]
+    fn retarget_keeps_direction_of_velocity() {
+        let config = SpringConfig::default();
+        let mut motion = SpringMotion::new([0.0f32, 0.0]);
+        motion.set_target([100.0, 0.0]);
+        for _ in 0..5 {
+            motion.advance(&config, 1.0 / 60.0);
+        }
+        // Moving sideways keeps moving along the x axis at first.
+        let [x, _] = motion.value();
+        motion.set_target([x, 100.0]);
+        let ([next_x, next_y], _) = motion.advance(&config, 1.0 / 60.0);
+        assert!(next_x > x + 1.0);
+        assert!(next_y > 0.0);
+    }
+
+    #[test]
+    fn interpolate_color() {
+        let from = Color::rgb(0, 100, 255);
+        let to = Color::rgba(255, 200, 255, 0.0);
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 0.5), Color::rgba(128, 150, 255, 0.5));
+        assert_eq!(from.interpolate(&to, 1.0), to);
+        // Overshooting does not wrap around.
+        assert_eq!(from.interpolate(&to, 1.5), Color::rgba(255, 250, 255, 0.0));
+        assert_eq!(to.to_string(), "rgba(255, 200, 255, 0)");
+    }
+
+    #[test]
+    fn interpolate_arrays() {
+        assert_eq!([0.0f32, 10.0].interpolate(&[1.0, 20.0], 0.5), [0.5, 15.0]);
+        assert_eq!([0u8, 10].interpolate(&[10, 20], 0.25), [3, 13]);
+    }
+
+    #[test]
+    fn set_without_raf_is_immediate() {
+        let _ = create_root(|| {
+            let spring = create_spring_signal([0.0f32, 0.0], SpringConfig::default());
+            spring.set([1.0, 2.0]);
+            assert_eq!(spring.get(), [1.0, 2.0]);
+            assert!(!spring.is_animating());
+        });
+    }
+}
@@ -0,0 +1,225 @@
+use web_sys::{HtmlOptionElement, HtmlSelectElement};
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Size {
+    Small,
+    Large,
+}
+
+impl std::str::FromStr for Size {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "small" => Ok(Self::Small),
+            "large" => Ok(Self::Large),
+            _ => Err(()),
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn bind_group_radio() {
+    let (size, _root) = create_root_get(|| {
+        let size = create_signal(Size::Small);
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    input(r#type="radio", name="size", bind:group=size, value="small")
+                    input(r#type="radio", name="size", value="large", bind:group=size)
+                }
+            },
+            &test_container(),
+        );
+        size
+    });
+    next_microtask().await;
+
+    let small: HtmlInputElement = query_into("input[value=small]");
+    let large: HtmlInputElement = query_into("input[value=large]");
+    assert!(small.checked());
+    assert!(!large.checked());
+
+    large.click();
+    assert_eq!(size.get(), Size::Large);
+    assert!(!small.checked());
+
+    size.set(Size::Small);
+    assert!(small.checked());
+    assert!(!large.checked());
+}
+
+#[wasm_bindgen_test]
+async fn bind_group_multiple_checkboxes() {
+    let (toppings, _root) = create_root_get(|| {
+        let toppings = create_signal(vec!["cheese".to_string()]);
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    input(r#type="checkbox", value="cheese", bind:groupMultiple=toppings)
+                    input(r#type="checkbox", value="ham", bind:groupMultiple=toppings)
+                }
+            },
+            &test_container(),
+        );
+        toppings
+    });
+    next_microtask().await;
+
+    let cheese: HtmlInputElement = query_into("input[value=cheese]");
+    let ham: HtmlInputElement = query_into("input[value=ham]");
+    assert!(cheese.checked());
+    assert!(!ham.checked());
+
+    ham.click();
+    assert_eq!(toppings.get_clone(), ["cheese", "ham"]);
+
+    cheese.click();
+    assert_eq!(toppings.get_clone(), ["ham"]);
+
+    toppings.set(vec!["cheese".to_string()]);
+    assert!(cheese.checked());
+    assert!(!ham.checked());
+}
+
+fn selected_values(select: &HtmlSelectElement) -> Vec<String> {
+    let options = select.selected_options();
+    (0..options.length())
+        .map(|i| {
+            options
+                .item(i)
+                .unwrap()
+                .unchecked_into::<HtmlOptionElement>()
+                .value()
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn bind_selected_values() {
+    let _ = create_root(|| {
+        let selected = create_signal(vec!["b".to_string()]);
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    select(multiple=true, bind:selectedValues=selected) {
+                        option(value="a") { "A" }
+                        option(value="b") { "B" }
+                        option(value="c") { "C" }
+                    }
+                }
+            },
+            &test_container(),
+        );
+        let select: HtmlSelectElement = query_into("select");
+        assert_eq!(selected_values(&select), ["b"]);
+
+        selected.set(vec!["a".to_string(), "c".to_string()]);
+        assert_eq!(selected_values(&select), ["a", "c"]);
+
+        select
+            .options()
+            .item(1)
+            .unwrap()
+            .unchecked_into::<HtmlOptionElement>()
+            .set_selected(true);
+        select
+            .dispatch_event(&Event::new("change").unwrap())
+            .unwrap();
+        assert_eq!(selected.get_clone(), ["a", "b", "c"]);
+    });
+}
+
+#[wasm_bindgen_test]
+async fn bind_selected_values_dynamic_options() {
+    let (options, _root) = create_root_get(|| {
+        let options = create_signal(vec!["a", "b"]);
+        let selected = create_signal(vec!["b".to_string(), "c".to_string()]);
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    select(multiple=true, bind:selectedValues=selected) {
+                        Keyed(
+                            list=options,
+                            view=|value| view! { option(value=value) { (value) } },
+                            key=|value| *value,
+                        )
+                    }
+                    p { (selected.get_clone().join(",")) }
+                }
+            },
+            &test_container(),
+        );
+        options
+    });
+    // Mutation observers are notified in a microtask.
+    next_microtask().await;
+    let select: HtmlSelectElement = query_into("select");
+    // "c" has no option, but it is kept in the signal.
+    assert_eq!(selected_values(&select), ["b"]);
+    assert_text_content!(query("p"), "b,c");
+
+    // The new option is selected from the signal.
+    options.set(vec!["a", "b", "c"]);
+    next_microtask().await;
+    assert_eq!(selected_values(&select), ["b", "c"]);
+    assert_text_content!(query("p"), "b,c");
+
+    options.set(vec!["a"]);
+    next_microtask().await;
+    assert!(selected_values(&select).is_empty());
+    assert_text_content!(query("p"), "b,c");
+
+    options.set(vec!["a", "b"]);
+    next_microtask().await;
+    assert_eq!(selected_values(&select), ["b"]);
+}
+
+#[wasm_bindgen_test]
+fn bind_value_as_number_parses_input() {
+    let _ = create_root(|| {
+        let quantity = create_signal(2u32);
+        sycamore::render_in_scope(
+            || view! { input(r#type="number", bind:valueAsNumber=quantity) },
+            &test_container(),
+        );
+        let input: HtmlInputElement = query_into("input");
+        assert_eq!(input.value(), "2");
+
+        quantity.set(5);
+        assert_eq!(input.value(), "5");
+
+        input.set_value("7");
+        input.dispatch_event(&Event::new("input").unwrap()).unwrap();
+        assert_eq!(quantity.get(), 7);
+
+        // Empty input leaves the signal unchanged and is not overwritten.
+        input.set_value("");
+        input.dispatch_event(&Event::new("input").unwrap()).unwrap();
+        assert_eq!(quantity.get(), 7);
+        assert_eq!(input.value(), "");
+    });
+}
+
+#[wasm_bindgen_test]
+fn bind_value_as_number_keeps_partial_input() {
+    let _ = create_root(|| {
+        let value = create_signal(1.0);
+        sycamore::render_in_scope(
+            || view! { input(r#type="text", bind:valueAsNumber=value) },
+            &test_container(),
+        );
+        let input: HtmlInputElement = query_into("input");
+
+        input.set_value("1.50");
+        input.dispatch_event(&Event::new("input").unwrap()).unwrap();
+        assert_eq!(value.get(), 1.5);
+        assert_eq!(input.value(), "1.50");
+
+        input.set_value("abc");
+        input.dispatch_event(&Event::new("input").unwrap()).unwrap();
+        assert_eq!(value.get(), 1.5);
+    });
+}
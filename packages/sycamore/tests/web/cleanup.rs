@@ -56,3 +56,26 @@ fn component_cleanup_on_root_destroyed() {
 
     assert_cleanup_called(move || root.dispose());
 }
+
+#[component]
+fn UnmountComp() -> View {
+    on_unmount(on_cleanup_callback);
+    view! { p { "Mounted" } }
+}
+
+#[wasm_bindgen_test]
+fn component_unmount_on_conditionally_removed() {
+    let _ = create_root(|| {
+        let show = create_signal(true);
+        let node = view! {
+            div {
+                (if show.get() { view! { UnmountComp {} } } else { view! {} })
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        assert!(query("div").inner_html().contains("Mounted"));
+
+        assert_cleanup_called(|| show.set(false));
+        assert!(!query("div").inner_html().contains("Mounted"));
+    });
+}
@@ -0,0 +1,109 @@
+use std::cell::Cell;
+
+use sycamore::web::define_custom_element;
+
+use super::*;
+
+thread_local! {
+    /// The number of times the `Greeting` component has been rendered.
+    static RENDERS: Cell<u32> = const { Cell::new(0) };
+    /// The number of times the reactive root of a `Greeting` component has been disposed.
+    static CLEANUPS: Cell<u32> = const { Cell::new(0) };
+}
+
+#[derive(Props)]
+#[prop(custom_element)]
+struct GreetingProps {
+    name: ReadSignal<String>,
+    #[prop(default = "Hello".to_string())]
+    greeting: String,
+}
+
+#[component]
+fn Greeting(props: GreetingProps) -> View {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    on_cleanup(|| CLEANUPS.with(|cleanups| cleanups.set(cleanups.get() + 1)));
+    let GreetingProps { name, greeting } = props;
+    view! { p { (greeting) ", " (name) "!" } }
+}
+
+/// Creates a `<name>` element with the given attributes inside the test container.
+fn create_element(name: &str, attributes: &[(&str, &str)]) -> Element {
+    let element = document().create_element(name).unwrap();
+    for (attribute, value) in attributes {
+        element.set_attribute(attribute, value).unwrap();
+    }
+    test_container().append_child(&element).unwrap();
+    element
+}
+
+fn shadow_text(element: &Element) -> String {
+    element.shadow_root().unwrap().text_content().unwrap()
+}
+
+#[wasm_bindgen_test]
+fn registers_element() {
+    define_custom_element("greeting-registered", Greeting);
+    let registry = window().custom_elements();
+    assert!(!registry.get("greeting-registered").is_undefined());
+    assert!(registry.get("greeting-unregistered").is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn renders_into_shadow_root() {
+    define_custom_element("greeting-shadow-root", Greeting);
+    let element = create_element("greeting-shadow-root", &[("name", "World")]);
+
+    let shadow_root = element.shadow_root().unwrap();
+    assert_eq!(shadow_root.mode(), web_sys::ShadowRootMode::Open);
+    assert_text_content!(shadow_root, "Hello, World!");
+    // Nothing is rendered into the light DOM.
+    assert_eq!(element.child_nodes().length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn connect_and_disconnect() {
+    define_custom_element("greeting-connect", Greeting);
+    let element = document().create_element("greeting-connect").unwrap();
+    element.set_attribute("name", "World").unwrap();
+    // Nothing is rendered until the element is connected.
+    assert!(element.shadow_root().is_none());
+
+    let cleanups = CLEANUPS.with(Cell::get);
+    test_container().append_child(&element).unwrap();
+    assert_eq!(shadow_text(&element), "Hello, World!");
+
+    element.remove();
+    assert_eq!(CLEANUPS.with(Cell::get), cleanups + 1);
+    assert_eq!(shadow_text(&element), "");
+
+    // Connecting the element again renders the component in a new reactive root.
+    element.set_attribute("name", "Again").unwrap();
+    test_container().append_child(&element).unwrap();
+    assert_eq!(shadow_text(&element), "Hello, Again!");
+    element.remove();
+    assert_eq!(CLEANUPS.with(Cell::get), cleanups + 2);
+}
+
+#[wasm_bindgen_test]
+fn attribute_changes_update_signals() {
+    define_custom_element("greeting-attributes", Greeting);
+    let renders = RENDERS.with(Cell::get);
+    let element = create_element(
+        "greeting-attributes",
+        &[("name", "World"), ("greeting", "Hi")],
+    );
+    assert_eq!(shadow_text(&element), "Hi, World!");
+    assert_eq!(RENDERS.with(Cell::get), renders + 1);
+
+    element.set_attribute("name", "Sycamore").unwrap();
+    assert_eq!(shadow_text(&element), "Hi, Sycamore!");
+    element.remove_attribute("name").unwrap();
+    assert_eq!(shadow_text(&element), "Hi, !");
+    // Fields that are not signals keep the value they had when the element was connected.
+    element.set_attribute("greeting", "Bye").unwrap();
+    assert_eq!(shadow_text(&element), "Hi, !");
+
+    // The component is never re-rendered.
+    assert_eq!(RENDERS.with(Cell::get), renders + 1);
+}
@@ -0,0 +1,43 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn deferred_effect_runs_after_microtask() {
+    let runs = Rc::new(Cell::new(0));
+    let (trigger, _root) = create_root_get({
+        let runs = Rc::clone(&runs);
+        move || {
+            let trigger = create_signal(());
+            create_effect_deferred(move || {
+                trigger.track();
+                runs.set(runs.get() + 1);
+            });
+            trigger
+        }
+    });
+    assert_eq!(runs.get(), 0);
+
+    next_microtask().await;
+    assert_eq!(runs.get(), 1);
+
+    // Subsequent runs are synchronous.
+    trigger.set(());
+    assert_eq!(runs.get(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn deferred_effect_not_run_if_disposed() {
+    let runs = Rc::new(Cell::new(0));
+    let root = create_root({
+        let runs = Rc::clone(&runs);
+        move || {
+            create_effect_deferred(move || runs.set(runs.get() + 1));
+        }
+    });
+    root.dispose();
+
+    next_microtask().await;
+    assert_eq!(runs.get(), 0);
+}
@@ -0,0 +1,33 @@
+use std::rc::Rc;
+
+use sycamore::web::Dynamic;
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn dynamic_cleans_up_previous_component_first() {
+    let _ = create_root(|| {
+        let log = create_signal(Vec::new());
+        let first: Rc<dyn Fn() -> View> = Rc::new(move || {
+            log.update(|log| log.push("create first"));
+            on_cleanup(move || log.update(|log| log.push("cleanup first")));
+            view! { "first" }
+        });
+        let second: Rc<dyn Fn() -> View> = Rc::new(move || {
+            log.update(|log| log.push("create second"));
+            view! { "second" }
+        });
+        let component = create_signal(first);
+        let node = view! { Dynamic(component=*component) };
+        sycamore::render_in_scope(|| node, &test_container());
+        let p = query("test-container");
+
+        assert_text_content!(p, "first");
+        component.set(second);
+        assert_text_content!(p, "second");
+        assert_eq!(
+            log.get_clone(),
+            ["create first", "cleanup first", "create second"]
+        );
+    });
+}
@@ -0,0 +1,37 @@
+use sycamore::web::{use_error_boundary, ErrorBoundary};
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn error_boundary_shows_fallback_and_resets() {
+    let _ = create_root(|| {
+        let key = create_signal(0);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    ErrorBoundary(
+                        fallback=|err| view! { p { "Error: " (err.to_string()) } },
+                        reset_on=move || key.track(),
+                    ) {
+                        ({
+                            let error_boundary = use_error_boundary();
+                            view! {
+                                button(on:click=move |_| error_boundary.throw("clicked")) {
+                                    "Click"
+                                }
+                            }
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+        assert_text_content!(query("#test-container"), "Click");
+
+        query_into::<HtmlElement>("button").click();
+        assert_text_content!(query("#test-container"), "Error: clicked");
+
+        key.set(1);
+        assert_text_content!(query("#test-container"), "Click");
+    });
+}
@@ -195,3 +195,185 @@ mod top_level_dynamic_with_siblings {
         });
     }
 }
+
+mod dynamic_class_and_style {
+    use super::*;
+    fn v(state: ReadSignal<bool>) -> View {
+        view! {
+            p(
+                class=move || if state.get() { "active" } else { "inactive" },
+                style=move || if state.get() { "color: red" } else { "color: blue" },
+            ) { "Text" }
+        }
+    }
+    static EXPECT: Expect =
+        expect![[r#"<p class="inactive" style="color: blue" data-hk="0.0">Text</p>"#]];
+    #[test]
+    fn ssr() {
+        check(|| v(*create_signal(false)), &EXPECT);
+    }
+    #[wasm_bindgen_test]
+    fn test() {
+        let c = test_container();
+        c.set_inner_html(EXPECT.data());
+
+        let _ = create_root(|| {
+            let state = create_signal(false);
+
+            sycamore::hydrate_in_scope(|| v(*state), &c);
+
+            // Attributes should keep the SSR-ed value.
+            assert_eq!(
+                query("p").get_attribute("class").as_deref(),
+                Some("inactive")
+            );
+            assert_eq!(
+                query("p").get_attribute("style").as_deref(),
+                Some("color: blue")
+            );
+
+            // Reactivity should work normally.
+            state.set(true);
+            assert_eq!(query("p").get_attribute("class").as_deref(), Some("active"));
+            assert_eq!(
+                query("p").get_attribute("style").as_deref(),
+                Some("color: red")
+            );
+        });
+    }
+}
+
+mod tag_mismatch {
+    use super::*;
+    fn v() -> View {
+        div().children(p().children("Client")).into()
+    }
+    #[wasm_bindgen_test]
+    fn test() {
+        let c = test_container();
+        // The server rendered a `<span>` instead of a `<p>`.
+        c.set_inner_html(r#"<div data-hk="0.0"><span data-hk="0.1">Client</span></div>"#);
+
+        // A mismatch is reported to the console but does not abort hydration.
+        sycamore::hydrate_to(v, &c);
+
+        assert!(query("span").has_attribute("data-hydrated"));
+    }
+}
+
+mod key_mismatch {
+    use wasm_bindgen::prelude::*;
+
+    use super::*;
+
+    #[wasm_bindgen(inline_js = r#"
+    let errors = [];
+    const consoleError = console.error;
+    export function capture_console_errors() {
+        errors = [];
+        console.error = (message) => errors.push(String(message));
+    }
+    export function take_console_errors() {
+        console.error = consoleError;
+        return errors;
+    }
+    "#)]
+    extern "C" {
+        /// Records the messages passed to `console.error` instead of logging them.
+        fn capture_console_errors();
+        /// Stops recording and returns the recorded messages.
+        fn take_console_errors() -> js_sys::Array;
+    }
+
+    fn v() -> View {
+        div().children(p().children("Client")).into()
+    }
+    #[wasm_bindgen_test]
+    fn test() {
+        let c = test_container();
+        // The hydration key of the `<p>` is parsed as `0.1` but is not the key that the client
+        // expects.
+        c.set_inner_html(r#"<div data-hk="0.0"><p data-hk="0.01">Client</p></div>"#);
+
+        capture_console_errors();
+        sycamore::hydrate_to(v, &c);
+        let errors = take_console_errors();
+
+        assert_eq!(errors.length(), 1);
+        assert_eq!(
+            errors.get(0).as_string().unwrap(),
+            r#"hydration mismatch at `0.1`: expected element `<p data-hk="0.1">`, got `<p data-hk="0.01">`"#
+        );
+    }
+}
+
+mod no_ssr_fallback {
+    use sycamore::web::NoSsr;
+
+    use super::*;
+    fn v() -> View {
+        view! {
+            div {
+                NoSsr(fallback=|| view! { span { "Loading" } }) {
+                    p { "Client" }
+                }
+                p { "After" }
+            }
+        }
+    }
+    static EXPECT: Expect = expect![[
+        r#"<div data-hk="0.0"><no-ssr data-hk="0.1"><span>Loading</span></no-ssr><p data-hk="0.2">After</p></div>"#
+    ]];
+    #[test]
+    fn ssr() {
+        check(v, &EXPECT);
+    }
+    #[wasm_bindgen_test]
+    async fn test() {
+        let c = test_container();
+        c.set_inner_html(EXPECT.data());
+
+        let _ = create_root(|| sycamore::hydrate_in_scope(v, &c));
+        assert_text_content!(query("div"), "LoadingAfter");
+
+        // The fallback is replaced once mounted.
+        next_microtask().await;
+        assert_text_content!(query("div"), "ClientAfter");
+    }
+}
+
+#[cfg(feature = "serde")]
+mod hydrated_state {
+    use sycamore::web::{serialize_state, use_hydrated_state, HydrationScript};
+
+    use super::*;
+    fn v() -> View {
+        let count = use_hydrated_state("count").unwrap_or_else(|| {
+            // Only the server can fetch the value.
+            let count = if cfg!(target_arch = "wasm32") { 0 } else { 5 };
+            serialize_state("count", &count);
+            count
+        });
+        view! {
+            HydrationScript {}
+            p { (count) }
+        }
+    }
+    static EXPECT: Expect = expect![[
+        r#"<script></script><script type="application/json" id="__sycamore_state">{"count":5}</script><p data-hk="0.0">5</p>"#
+    ]];
+    #[test]
+    fn ssr() {
+        check(v, &EXPECT);
+    }
+    #[wasm_bindgen_test]
+    fn test() {
+        let c = test_container();
+        c.set_inner_html(EXPECT.data());
+
+        sycamore::hydrate_to(v, &c);
+
+        // The value that was serialized on the server is used on the client.
+        assert_text_content!(query("p"), "5");
+    }
+}
@@ -419,3 +419,38 @@ fn template_with_other_nodes_at_same_level() {
         assert_text_content!(elem, "before145after");
     });
 }
+
+#[wasm_bindgen_test]
+fn indexed_with_neighbors() {
+    let _ = create_root(|| {
+        let count = create_signal(vec![1, 1, 2]);
+
+        let view = move || {
+            view! {
+                ul {
+                    IndexedWithNeighbors(
+                        list=count,
+                        view=|item, prev, next| {
+                            let prev = prev.copied().unwrap_or(0);
+                            let next = next.copied().unwrap_or(0);
+                            view! {
+                                li { (prev) (item) (next) }
+                            }
+                        },
+                    )
+                }
+            }
+        };
+
+        sycamore::render_in_scope(view, &test_container());
+
+        let p = query("ul");
+        assert_text_content!(p, "011112120");
+
+        count.update(|count| count.push(3));
+        assert_text_content!(p, "011112123230");
+
+        count.update(|count| count.remove(0));
+        assert_text_content!(p, "012123230");
+    });
+}
@@ -0,0 +1,124 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use sycamore::web::{on_intersect, use_intersection, IntersectionOptions};
+
+use super::*;
+
+/// Renders a scroll container of 50px height with `target` placed 100px below its top, so that
+/// the target is only visible once the container is scrolled down.
+fn render_scroll_container(root: NodeRef, target: NodeRef, show_target: ReadSignal<bool>) {
+    sycamore::render_in_scope(
+        move || {
+            view! {
+                div(r#ref=root, style="display: block; height: 50px; overflow: scroll") {
+                    div(style="height: 100px")
+                    (if show_target.get() {
+                        view! { div(r#ref=target, style="height: 10px") }
+                    } else {
+                        view! {}
+                    })
+                }
+            }
+        },
+        &test_container(),
+    );
+}
+
+fn options(root: NodeRef) -> IntersectionOptions {
+    IntersectionOptions {
+        root: Some(root),
+        ..Default::default()
+    }
+}
+
+/// Waits until the observers have been notified of the latest layout.
+async fn next_observation() {
+    sleep(100).await;
+}
+
+#[wasm_bindgen_test]
+async fn visible_element_is_intersecting() {
+    let (visible, _root) = create_root_get(|| {
+        let node = create_node_ref();
+        let visible = use_intersection(node, IntersectionOptions::default());
+        sycamore::render_in_scope(
+            move || view! { div(r#ref=node, style="height: 10px") },
+            &test_container(),
+        );
+        visible
+    });
+    assert!(!visible.get());
+
+    next_observation().await;
+    assert!(visible.get());
+}
+
+#[wasm_bindgen_test]
+async fn intersection_with_root() {
+    let ((root, visible), _root) = create_root_get(|| {
+        let (root, target) = (create_node_ref(), create_node_ref());
+        let visible = use_intersection(target, options(root));
+        render_scroll_container(root, target, *create_signal(true));
+        (root, visible)
+    });
+    next_observation().await;
+    assert!(!visible.get());
+
+    root.get().unchecked_into::<Element>().set_scroll_top(100);
+    next_observation().await;
+    assert!(visible.get());
+
+    root.get().unchecked_into::<Element>().set_scroll_top(0);
+    next_observation().await;
+    assert!(!visible.get());
+}
+
+#[wasm_bindgen_test]
+async fn observes_node_once_it_is_set() {
+    let ((root, show_target, visible), _root) = create_root_get(|| {
+        let (root, target) = (create_node_ref(), create_node_ref());
+        let show_target = create_signal(false);
+        let visible = use_intersection(target, options(root));
+        render_scroll_container(root, target, *show_target);
+        (root, show_target, visible)
+    });
+    root.get().unchecked_into::<Element>().set_scroll_top(100);
+    next_observation().await;
+    assert!(!visible.get());
+
+    show_target.set(true);
+    next_observation().await;
+    assert!(visible.get());
+}
+
+#[wasm_bindgen_test]
+async fn on_intersect_is_called_when_entering() {
+    let calls = Rc::new(Cell::new(0));
+    let (root, handle) = create_root_get({
+        let calls = Rc::clone(&calls);
+        move || {
+            let (root, target) = (create_node_ref(), create_node_ref());
+            on_intersect(target, options(root), move || calls.set(calls.get() + 1));
+            render_scroll_container(root, target, *create_signal(true));
+            root.get().unchecked_into::<Element>()
+        }
+    });
+    next_observation().await;
+    assert_eq!(calls.get(), 0);
+
+    root.set_scroll_top(100);
+    next_observation().await;
+    assert_eq!(calls.get(), 1);
+
+    // Not called when leaving.
+    root.set_scroll_top(0);
+    next_observation().await;
+    assert_eq!(calls.get(), 1);
+
+    // The observer is disconnected when the scope is disposed.
+    handle.dispose();
+    root.set_scroll_top(100);
+    next_observation().await;
+    assert_eq!(calls.get(), 1);
+}
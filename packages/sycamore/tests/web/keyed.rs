@@ -465,3 +465,35 @@ fn template_with_other_nodes_at_same_level() {
         assert_text_content!(elem, "before145after");
     });
 }
+
+#[wasm_bindgen_test]
+fn with_index_updates_on_reorder() {
+    let _ = create_root(|| {
+        let count = create_signal(vec![1, 2, 3]);
+
+        let view = move || {
+            view! {
+                ul {
+                    KeyedWithIndex(
+                        list=count,
+                        view=|item, index| view! {
+                            li { (index.get()) ":" (item) " " }
+                        },
+                        key=|item| *item,
+                    )
+                }
+            }
+        };
+
+        sycamore::render_in_scope(view, &test_container());
+
+        let p = query("ul");
+        assert_text_content!(p, "0:1 1:2 2:3 ");
+        let first = p.first_child().unwrap();
+
+        count.update(|count| count.reverse());
+        assert_text_content!(p, "0:3 1:2 2:1 ");
+        // The view of the moved item is reused.
+        assert_eq!(p.last_child(), Some(first));
+    });
+}
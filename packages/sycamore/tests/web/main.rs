@@ -1,10 +1,29 @@
+pub mod bind;
 pub mod cleanup;
+pub mod custom_element;
+pub mod deferred;
+pub mod dynamic;
+pub mod error_boundary;
 pub mod hydrate;
 pub mod indexed;
+pub mod intersection;
 pub mod keyed;
 pub mod portal;
 pub mod render;
+pub mod resize;
+pub mod route_transition;
+pub mod shadow;
+pub mod show;
+#[cfg(feature = "serde")]
+pub mod storage;
+#[cfg(feature = "suspense")]
+pub mod suspense;
 pub mod svg;
+pub mod switch;
+pub mod timers;
+#[cfg(feature = "suspense")]
+pub mod transition;
+pub mod websocket;
 
 mod utils;
 
@@ -250,6 +269,150 @@ fn optional_attribute() {
     });
 }
 
+#[wasm_bindgen_test]
+fn bool_attributes() {
+    let _ = create_root(|| {
+        let on = create_signal(true);
+
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    input(disabled=true, checked=true, readonly=true, required=true, hidden=true)
+                    input(disabled=false, checked=false, readonly=false, required=false, hidden=false)
+                    input(disabled=on, checked=on, readonly=on, required=on, hidden=on)
+                }
+            },
+            &test_container(),
+        );
+        let inputs = document().query_selector_all("input").unwrap();
+        let input = |i: u32| inputs.get(i).unwrap().unchecked_into::<Element>();
+        let names = ["disabled", "checked", "readonly", "required", "hidden"];
+
+        for name in names {
+            assert_eq!(input(0).get_attribute(name).as_deref(), Some(""), "{name}");
+            assert!(!input(1).has_attribute(name), "{name}");
+            assert_eq!(input(2).get_attribute(name).as_deref(), Some(""), "{name}");
+        }
+
+        on.set(false);
+        for name in names {
+            assert!(!input(2).has_attribute(name), "{name}");
+        }
+    });
+}
+
+#[wasm_bindgen_test]
+fn class_list() {
+    let _ = create_root(|| {
+        let is_active = create_signal(true);
+        let show_extra = create_signal(true);
+
+        let node = view! {
+            div(class="static", class_list=move || {
+                let mut classes = vec![("active", is_active.get())];
+                if show_extra.get() {
+                    classes.push(("extra", true));
+                }
+                classes
+            })
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        let div = query("div");
+        assert_eq!(div.class_name(), "static active extra");
+
+        // Classes added in other ways are kept.
+        div.class_list().add_1("external").unwrap();
+        is_active.set(false);
+        assert_eq!(div.class_name(), "static extra external");
+
+        // Classes that are no longer returned are removed.
+        show_extra.set(false);
+        assert_eq!(div.class_name(), "static external");
+    });
+}
+
+#[wasm_bindgen_test]
+fn styles() {
+    let _ = create_root(|| {
+        let width = create_signal(10);
+        let show_color = create_signal(true);
+
+        let node = view! {
+            div(styles=move || {
+                let mut styles = vec![("width", format!("{}px", width.get()))];
+                if show_color.get() {
+                    styles.push(("color", "red".to_string()));
+                }
+                styles
+            })
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        let style = query_into::<HtmlElement>("div").style();
+        assert_eq!(style.get_property_value("width").unwrap(), "10px");
+        assert_eq!(style.get_property_value("color").unwrap(), "red");
+
+        // Properties set in other ways are kept.
+        style.set_property("margin", "1px").unwrap();
+        width.set(20);
+        assert_eq!(style.get_property_value("width").unwrap(), "20px");
+        assert_eq!(style.get_property_value("margin").unwrap(), "1px");
+
+        // Properties that are no longer returned are removed.
+        show_color.set(false);
+        assert_eq!(style.get_property_value("color").unwrap(), "");
+    });
+}
+
+#[wasm_bindgen_test]
+fn typed_styles() {
+    let _ = create_root(|| {
+        let width = create_signal(10);
+        let is_hidden = create_signal(false);
+        let styles = sycamore::web::Style::new()
+            .color("red")
+            .width(move || format!("{}px", width.get()))
+            .display(move || is_hidden.get().then_some("none"));
+
+        let node = view! {
+            div(style="margin: 1px", styles=styles)
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        let style = query_into::<HtmlElement>("div").style();
+        assert_eq!(style.get_property_value("color").unwrap(), "red");
+        assert_eq!(style.get_property_value("width").unwrap(), "10px");
+        assert_eq!(style.get_property_value("display").unwrap(), "");
+        // The static `style` attribute is kept.
+        assert_eq!(style.get_property_value("margin").unwrap(), "1px");
+
+        width.set(20);
+        is_hidden.set(true);
+        assert_eq!(style.get_property_value("width").unwrap(), "20px");
+        assert_eq!(style.get_property_value("display").unwrap(), "none");
+        assert_eq!(style.get_property_value("margin").unwrap(), "1px");
+
+        // Declarations without a value are removed.
+        is_hidden.set(false);
+        assert_eq!(style.get_property_value("display").unwrap(), "");
+    });
+}
+
+#[wasm_bindgen_test]
+fn reactive_inner_html() {
+    let _ = create_root(|| {
+        let html = create_signal("<b>bold</b>");
+
+        sycamore::render_in_scope(
+            || view! { div(dangerously_set_inner_html=html.get()) },
+            &test_container(),
+        );
+        let div = query("div");
+        assert_eq!(div.inner_html(), "<b>bold</b>");
+
+        html.set("<i>italic</i>");
+        assert_eq!(div.inner_html(), "<i>italic</i>");
+    });
+}
+
 #[wasm_bindgen_test]
 fn reactive_property() {
     let _ = create_root(|| {
@@ -344,6 +507,54 @@ fn noderefs() {
     });
 }
 
+#[wasm_bindgen_test]
+fn noderef_typed_accessors() {
+    let _ = create_root(|| {
+        let noderef = create_node_ref();
+        assert!(noderef.get_as::<HtmlInputElement>().is_none());
+
+        let node = view! {
+            input(r#ref=noderef)
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let input = noderef.get_as::<HtmlInputElement>().unwrap();
+        assert_eq!(input, query_into::<HtmlInputElement>("input"));
+        assert_eq!(noderef.get_unchecked_as::<HtmlInputElement>(), input);
+        // The node is not a text node.
+        assert!(noderef.get_as::<web_sys::Text>().is_none());
+    });
+}
+
+#[wasm_bindgen_test]
+async fn use_dataset_tracks_data_attributes() {
+    use sycamore::web::use_dataset;
+
+    let ((state, user_id), _root) = create_root_get(|| {
+        let state = create_signal("closed");
+        let node = create_node_ref();
+        let dataset = use_dataset(node);
+        let user_id = create_memo(move || dataset.get("userId"));
+        sycamore::render_in_scope(
+            || view! { div(r#ref=node, data-user-id="1", data-state=state) },
+            &test_container(),
+        );
+        (state, user_id)
+    });
+    next_microtask().await;
+    assert_eq!(user_id.get_clone().as_deref(), Some("1"));
+
+    query("div").set_attribute("data-user-id", "2").unwrap();
+    next_microtask().await;
+    assert_eq!(user_id.get_clone().as_deref(), Some("2"));
+
+    state.set("open");
+    assert_eq!(
+        query("div").get_attribute("data-state").as_deref(),
+        Some("open")
+    );
+}
+
 #[wasm_bindgen_test]
 fn noderef_reactivity_test() {
     let _ = create_root(|| {
@@ -396,3 +607,102 @@ fn fragments_text_nodes() {
         assert_text_content!(query("#test-container"), "123");
     });
 }
+
+#[wasm_bindgen_test]
+fn event_modifiers() {
+    use sycamore::web::events::{once, prevent_default, stop_propagation, MouseEvent};
+
+    let _ = create_root(|| {
+        let mut outer_clicks = create_signal(0);
+        let mut inner_clicks = create_signal(0);
+        let mut once_clicks = create_signal(0);
+
+        let node = view! {
+            input(
+                r#type="checkbox",
+                on:click=prevent_default(|e: MouseEvent| assert!(e.default_prevented())),
+            )
+            div(on:click=move |_| outer_clicks += 1) {
+                button(id="inner", on:click=stop_propagation(move |_| inner_clicks += 1))
+                button(id="once", on:click=once(move |_| once_clicks += 1))
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        let input: HtmlInputElement = query_into("input");
+        input.click();
+        assert!(!input.checked());
+
+        query_into::<HtmlElement>("#inner").click();
+        assert_eq!(inner_clicks.get(), 1);
+        assert_eq!(outer_clicks.get(), 0);
+
+        let button = query_into::<HtmlElement>("#once");
+        button.click();
+        button.click();
+        assert_eq!(once_clicks.get(), 1);
+        assert_eq!(outer_clicks.get(), 2);
+    });
+}
+
+#[wasm_bindgen_test]
+fn custom_events() {
+    use sycamore::web::events::{self, detail, CustomEvent};
+    use sycamore::web::js_sys::JsString;
+    use web_sys::CustomEventInit;
+
+    let _ = create_root(|| {
+        let hyphenated = create_signal(0);
+        let unknown = create_signal(0);
+        let runtime = create_signal(0);
+        let color = create_signal(String::new());
+
+        let node = view! {
+            div(id="hyphenated", on:my-event=move |_| hyphenated.set(hyphenated.get() + 1))
+            div(id="unknown", on:myevent=move |_| unknown.set(unknown.get() + 1))
+        };
+        let name = format!("event-{}", 1);
+        let runtime_node = sycamore::web::tags::div()
+            .id("runtime")
+            .on(events::custom::<events::Event>(name), move |_| {
+                runtime.set(runtime.get() + 1)
+            });
+        let typed = sycamore::web::tags::div().id("typed").on(
+            events::custom::<CustomEvent>("color-changed"),
+            move |ev: CustomEvent| {
+                let detail = detail::<JsString>(&ev).unwrap();
+                color.set(String::from(detail));
+            },
+        );
+        sycamore::render_in_scope(
+            || view! { (node) (typed) (runtime_node) },
+            &test_container(),
+        );
+
+        let el = query("#hyphenated");
+        el.dispatch_event(&Event::new("my-event").unwrap()).unwrap();
+        assert_eq!(hyphenated.get(), 1);
+
+        let el = query("#unknown");
+        el.dispatch_event(&Event::new("myevent").unwrap()).unwrap();
+        assert_eq!(unknown.get(), 1);
+
+        let el = query("#runtime");
+        el.dispatch_event(&Event::new("event-1").unwrap()).unwrap();
+        assert_eq!(runtime.get(), 1);
+
+        let init = CustomEventInit::new();
+        init.set_detail(&"red".into());
+        let ev = CustomEvent::new_with_event_init_dict("color-changed", &init).unwrap();
+        query("#typed").dispatch_event(&ev).unwrap();
+        assert_eq!(color.get_clone(), "red");
+    });
+}
+
+#[wasm_bindgen_test]
+fn media_query_signal() {
+    let _ = create_root(|| {
+        assert!(sycamore::web::use_media_query("(min-width: 0px)").get());
+        assert!(!sycamore::web::use_media_query("(max-width: 0px)").get());
+    });
+}
@@ -1,4 +1,4 @@
-use sycamore::web::Portal;
+use sycamore::web::{Portal, PortalMount};
 
 use super::*;
 
@@ -38,3 +38,101 @@ fn test_portal() {
         assert_text_content!(portal_target, "");
     });
 }
+
+#[wasm_bindgen_test]
+fn multiple_portals_to_same_target() {
+    let test_container = test_container();
+
+    let portal_target = document().create_element("div").unwrap();
+    portal_target.set_id("portal-target");
+    test_container.append_child(&portal_target).unwrap();
+
+    let root = document().create_element("div").unwrap();
+    test_container.append_child(&root).unwrap();
+
+    let _ = create_root(|| {
+        let show_first = create_signal(true);
+        let show_second = create_signal(true);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    (if show_first.get() {
+                        view! {
+                            Portal(selector="#portal-target") { "first" }
+                        }
+                    } else {
+                        view! { }
+                    })
+                    (if show_second.get() {
+                        view! {
+                            Portal(selector="#portal-target") { "second" }
+                        }
+                    } else {
+                        view! { }
+                    })
+                }
+            },
+            &root,
+        );
+        // Portals are appended in order.
+        assert_text_content!(portal_target, "firstsecond");
+
+        // Destroying one portal should not remove the nodes of the other.
+        show_first.set(false);
+        assert_text_content!(portal_target, "second");
+
+        // A new portal is appended after the existing ones.
+        show_first.set(true);
+        assert_text_content!(portal_target, "secondfirst");
+
+        show_second.set(false);
+        assert_text_content!(portal_target, "first");
+    });
+}
+
+#[wasm_bindgen_test]
+fn portal_mount_targets() {
+    let test_container = test_container();
+
+    let selector_target = document().create_element("div").unwrap();
+    selector_target.set_id("modal-root");
+    test_container.append_child(&selector_target).unwrap();
+
+    let element_target = document().create_element("div").unwrap();
+    test_container.append_child(&element_target).unwrap();
+
+    let root = document().create_element("div").unwrap();
+    test_container.append_child(&root).unwrap();
+
+    let _ = create_root(|| {
+        let show = create_signal(true);
+        let target = element_target.clone();
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    (if show.get() {
+                        let element_mount = PortalMount::Element(target.clone());
+                        view! {
+                            Portal(mount="#modal-root") { "selector" }
+                            Portal(mount=element_mount) { "element" }
+                            Portal(mount=PortalMount::Body) { span(id="in-body") { "body" } }
+                        }
+                    } else {
+                        view! { }
+                    })
+                }
+            },
+            &root,
+        );
+        assert_text_content!(selector_target, "selector");
+        assert_text_content!(element_target, "element");
+        let in_body = query("#in-body");
+        assert_eq!(in_body.parent_element(), document().body().map(Into::into));
+
+        // Nodes are removed from every target on cleanup.
+        show.set(false);
+        assert_text_content!(selector_target, "");
+        assert_text_content!(element_target, "");
+        assert!(document().query_selector("#in-body").unwrap().is_none());
+    });
+}
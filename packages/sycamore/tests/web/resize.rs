@@ -0,0 +1,96 @@
+use sycamore::web::{use_element_size, use_element_size_with_default};
+
+use super::*;
+
+/// Waits until the observers have been notified of the latest layout.
+async fn next_observation() {
+    sleep(100).await;
+}
+
+#[wasm_bindgen_test]
+async fn measures_element() {
+    let ((size, size_with_default), _root) = create_root_get(|| {
+        let node = create_node_ref();
+        let size = use_element_size(node);
+        let size_with_default = use_element_size_with_default(node, (1.0, 2.0));
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    div(r#ref=node, style="width: 40px; height: 20px; padding: 5px; display: block")
+                }
+            },
+            &test_container(),
+        );
+        (size, size_with_default)
+    });
+    // The default is used until the element is measured.
+    assert_eq!(size.get(), (0.0, 0.0));
+    assert_eq!(size_with_default.get(), (1.0, 2.0));
+
+    next_observation().await;
+    // The padding is not included.
+    assert_eq!(size.get(), (40.0, 20.0));
+    assert_eq!(size_with_default.get(), (40.0, 20.0));
+}
+
+#[wasm_bindgen_test]
+async fn updates_when_resized() {
+    let ((size, width, node), root) = create_root_get(|| {
+        let node = create_node_ref();
+        let width = create_signal(40);
+        let size = use_element_size(node);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    div(
+                        r#ref=node,
+                        style=move || format!("width: {}px; height: 20px; display: block", width.get()),
+                    )
+                }
+            },
+            &test_container(),
+        );
+        (size, width, node)
+    });
+    next_observation().await;
+    assert_eq!(size.get(), (40.0, 20.0));
+
+    width.set(60);
+    next_observation().await;
+    assert_eq!(size.get(), (60.0, 20.0));
+
+    // The observer is disconnected when the scope is disposed.
+    let element = node.get().unchecked_into::<Element>();
+    root.dispose();
+    // Updating the disposed signal would panic.
+    element.set_attribute("style", "width: 80px").unwrap();
+    next_observation().await;
+}
+
+#[wasm_bindgen_test]
+async fn observes_node_once_it_is_set() {
+    let ((size, show), _root) = create_root_get(|| {
+        let node = create_node_ref();
+        let show = create_signal(false);
+        let size = use_element_size(node);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    (if show.get() {
+                        view! { div(r#ref=node, style="width: 30px; height: 10px; display: block") }
+                    } else {
+                        view! {}
+                    })
+                }
+            },
+            &test_container(),
+        );
+        (size, show)
+    });
+    next_observation().await;
+    assert_eq!(size.get(), (0.0, 0.0));
+
+    show.set(true);
+    next_observation().await;
+    assert_eq!(size.get(), (30.0, 10.0));
+}
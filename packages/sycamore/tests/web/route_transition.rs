@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use sycamore::web::RouteTransition;
+
+use super::*;
+
+/// Returns the text and the `data-route-transition` attribute of each page.
+fn pages() -> Vec<(String, Option<String>)> {
+    let pages = document().query_selector_all(".page").unwrap();
+    (0..pages.length())
+        .map(|i| {
+            let page: Element = pages.item(i).unwrap().unchecked_into();
+            (
+                page.text_content().unwrap(),
+                page.get_attribute("data-route-transition"),
+            )
+        })
+        .collect()
+}
+
+fn page(text: &str, state: Option<&str>) -> (String, Option<String>) {
+    (text.to_string(), state.map(ToString::to_string))
+}
+
+#[wasm_bindgen_test]
+async fn route_transition_keeps_exiting_page_mounted() {
+    let (route, _root) = create_root_get(|| {
+        let route = create_signal("home");
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    RouteTransition(
+                        route=*route,
+                        class="page",
+                        duration=Duration::from_millis(20),
+                        view=|route| view! { (route) },
+                    )
+                }
+            },
+            &test_container(),
+        );
+        route
+    });
+    assert_eq!(pages(), [page("home", None)]);
+
+    route.set("about");
+    assert_eq!(
+        pages(),
+        [page("home", Some("exit")), page("about", Some("enter"))]
+    );
+
+    sleep(50).await;
+    assert_eq!(pages(), [page("about", None)]);
+}
+
+#[wasm_bindgen_test]
+async fn route_transition_rapid_navigation() {
+    let (route, _root) = create_root_get(|| {
+        let route = create_signal("home");
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    RouteTransition(
+                        route=*route,
+                        class="page",
+                        duration=Duration::from_millis(20),
+                        view=|route| view! { (route) },
+                    )
+                }
+            },
+            &test_container(),
+        );
+        route
+    });
+
+    route.set("about");
+    route.set("blog");
+    // The page that was exiting is removed right away.
+    assert_eq!(
+        pages(),
+        [page("about", Some("exit")), page("blog", Some("enter"))]
+    );
+
+    sleep(50).await;
+    assert_eq!(pages(), [page("blog", None)]);
+}
+
+#[cfg(feature = "suspense")]
+#[wasm_bindgen_test]
+async fn route_transition_waits_for_pending_transition() {
+    use sycamore::futures::create_suspense_task;
+    use sycamore::web::Transition;
+
+    let ((route, load), _root) = create_root_get(|| {
+        let route = create_signal("home");
+        let load = create_signal(false);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Transition {
+                        RouteTransition(
+                            route=*route,
+                            class="page",
+                            duration=Duration::from_millis(20),
+                            view=|route| view! { (route) },
+                        )
+                        ({
+                            create_effect(move || {
+                                if load.get() {
+                                    create_suspense_task(sleep(30));
+                                }
+                            });
+                            view! {}
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+        (route, load)
+    });
+    sleep(0).await;
+    assert_eq!(pages(), [page("home", None)]);
+
+    // The route changes while the transition is loading.
+    load.set(true);
+    route.set("about");
+    assert_eq!(pages(), [page("home", None)]);
+
+    // The route transition starts once the transition is no longer pending.
+    sleep(40).await;
+    assert_eq!(
+        pages(),
+        [page("home", Some("exit")), page("about", Some("enter"))]
+    );
+
+    sleep(40).await;
+    assert_eq!(pages(), [page("about", None)]);
+}
@@ -0,0 +1,39 @@
+use web_sys::{EventInit, ShadowRootInit, ShadowRootMode};
+
+use super::*;
+
+/// Creates a host element in the test container and attaches an open shadow root to it.
+fn shadow_root() -> web_sys::ShadowRoot {
+    let host = document().create_element("div").unwrap();
+    test_container().append_child(&host).unwrap();
+    host.attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .unwrap()
+}
+
+fn click_event() -> Event {
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    init.set_composed(true);
+    Event::new_with_event_init_dict("click", &init).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn events_inside_shadow_root() {
+    let shadow_root = shadow_root();
+    let _ = create_root(|| {
+        let clicks = create_signal(0);
+        let outer_clicks = create_signal(0);
+        let node = view! {
+            div(on:click=move |_| outer_clicks.set(outer_clicks.get() + 1)) {
+                button(on:click=move |_| clicks.set(clicks.get() + 1)) { "Click" }
+            }
+        };
+        sycamore::render_in_scope(|| node, &shadow_root);
+
+        let button = shadow_root.query_selector("button").unwrap().unwrap();
+        button.dispatch_event(&click_event()).unwrap();
+        assert_eq!(clicks.get(), 1);
+        // The event bubbles to ancestors inside the shadow root.
+        assert_eq!(outer_clicks.get(), 1);
+    });
+}
@@ -0,0 +1,46 @@
+use sycamore::web::Show;
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn show_toggles_children_and_fallback() {
+    let _ = create_root(|| {
+        let when = create_signal(true);
+        let node = view! {
+            Show(when=when, fallback=|| view! { "fallback" }) {
+                "content"
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        let p = query("test-container");
+
+        assert_text_content!(p, "content");
+        when.set(false);
+        assert_text_content!(p, "fallback");
+        when.set(true);
+        assert_text_content!(p, "content");
+    });
+}
+
+#[wasm_bindgen_test]
+fn show_keeps_children_alive_while_hidden() {
+    let _ = create_root(|| {
+        let when = create_signal(true);
+        let count = create_signal(0);
+        let node = view! {
+            Show(when=when) {
+                (count.get())
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        let p = query("test-container");
+
+        assert_text_content!(p, "0");
+        when.set(false);
+        assert_text_content!(p, "");
+        // The hidden children are still updated.
+        count.set(1);
+        when.set(true);
+        assert_text_content!(p, "1");
+    });
+}
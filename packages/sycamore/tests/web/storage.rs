@@ -0,0 +1,134 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use sycamore::web::{use_local_storage, use_local_storage_with_options, LocalStorageOptions};
+use web_sys::{Storage, StorageEvent, StorageEventInit};
+
+use super::*;
+
+/// Returns `localStorage` with `key` set to `value`, or removed if `value` is `None`.
+fn local_storage(key: &str, value: Option<&str>) -> Storage {
+    let storage = window().local_storage().unwrap().unwrap();
+    match value {
+        Some(value) => storage.set_item(key, value).unwrap(),
+        None => storage.remove_item(key).unwrap(),
+    }
+    storage
+}
+
+/// Dispatches a `storage` event, as if `key` was changed in another tab.
+fn dispatch_storage_event(key: &str, new_value: &str) {
+    let init = StorageEventInit::new();
+    init.set_key(Some(key));
+    init.set_new_value(Some(new_value));
+    let event = StorageEvent::new_with_event_init_dict("storage", &init).unwrap();
+    window().dispatch_event(&event).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn reads_and_writes_stored_value() {
+    let storage = local_storage("storage-read-write", Some("[1,2]"));
+    let _ = create_root(|| {
+        let signal = use_local_storage("storage-read-write", vec![0]);
+        assert_eq!(signal.get_clone(), [1, 2]);
+
+        signal.set(vec![3]);
+        assert_eq!(
+            storage.get_item("storage-read-write").unwrap().as_deref(),
+            Some("[3]")
+        );
+    });
+}
+
+#[wasm_bindgen_test]
+fn missing_or_invalid_value_uses_default() {
+    let storage = local_storage("storage-missing", None);
+    let _ = create_root(|| {
+        let signal = use_local_storage("storage-missing", 5);
+        assert_eq!(signal.get(), 5);
+        // The default value is written to the storage.
+        assert_eq!(
+            storage.get_item("storage-missing").unwrap().as_deref(),
+            Some("5")
+        );
+    });
+
+    local_storage("storage-invalid", Some("not json"));
+    let _ = create_root(|| {
+        let signal = use_local_storage("storage-invalid", 5);
+        assert_eq!(signal.get(), 5);
+    });
+}
+
+#[wasm_bindgen_test]
+fn syncs_with_other_tabs() {
+    local_storage("storage-sync", Some("1"));
+    let _ = create_root(|| {
+        let signal = use_local_storage("storage-sync", 0);
+        let unsynced = use_local_storage_with_options(
+            "storage-sync",
+            0,
+            LocalStorageOptions { sync_tabs: false },
+        );
+
+        dispatch_storage_event("storage-sync", "2");
+        assert_eq!(signal.get(), 2);
+        assert_eq!(unsynced.get(), 1);
+
+        // Changes to other keys and invalid values are ignored.
+        dispatch_storage_event("storage-other", "3");
+        dispatch_storage_event("storage-sync", "not json");
+        assert_eq!(signal.get(), 2);
+    });
+}
+
+#[wasm_bindgen_test]
+fn listener_is_removed_on_dispose() {
+    local_storage("storage-dispose", Some("1"));
+    let value = Rc::new(Cell::new(0));
+    let root = create_root({
+        let value = Rc::clone(&value);
+        move || {
+            let signal = use_local_storage("storage-dispose", 0);
+            create_effect(move || value.set(signal.get()));
+        }
+    });
+    assert_eq!(value.get(), 1);
+
+    root.dispose();
+    // Updating the disposed signal would panic.
+    dispatch_storage_event("storage-dispose", "2");
+    assert_eq!(value.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn uses_default_while_hydrating() {
+    let storage = local_storage("storage-hydrate", Some("1"));
+    let signal = Rc::new(Cell::new(None));
+    sycamore::hydrate_to(
+        {
+            let signal = Rc::clone(&signal);
+            move || {
+                signal.set(Some(use_local_storage("storage-hydrate", 0)));
+                view! {}
+            }
+        },
+        &test_container(),
+    );
+    let signal = signal.get().unwrap();
+    // The signal starts out with the default value so that it matches the server-rendered HTML.
+    assert_eq!(signal.get(), 0);
+    // The stored value is not overwritten with the default value.
+    assert_eq!(
+        storage.get_item("storage-hydrate").unwrap().as_deref(),
+        Some("1")
+    );
+
+    next_microtask().await;
+    assert_eq!(signal.get(), 1);
+    signal.set(2);
+    assert_eq!(
+        storage.get_item("storage-hydrate").unwrap().as_deref(),
+        Some("2")
+    );
+}
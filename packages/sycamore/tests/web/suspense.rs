@@ -0,0 +1,134 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use sycamore::futures::create_suspense_task;
+use sycamore::web::{ErrorBoundary, Suspense};
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn on_timeout_replaces_fallback() {
+    let _ = create_root(|| {
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    Suspense(
+                        fallback=|| view! { "Loading" },
+                        timeout=Duration::from_millis(20),
+                        on_timeout=|| view! { "Timed out" },
+                    ) {
+                        ({
+                            create_suspense_task(sleep(60));
+                            view! { "Loaded" }
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+    });
+    assert_text_content!(query("#test-container"), "Loading");
+
+    sleep(40).await;
+    assert_text_content!(query("#test-container"), "Timed out");
+
+    // The children stay alive and replace the timeout view once they resolve.
+    sleep(40).await;
+    assert_text_content!(query("#test-container"), "Loaded");
+}
+
+#[wasm_bindgen_test]
+async fn timeout_is_cancelled_when_resolved() {
+    let _ = create_root(|| {
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    Suspense(
+                        fallback=|| view! { "Loading" },
+                        timeout=Duration::from_millis(40),
+                        on_timeout=|| view! { "Timed out" },
+                    ) {
+                        ({
+                            create_suspense_task(sleep(10));
+                            view! { "Loaded" }
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+    });
+    sleep(20).await;
+    assert_text_content!(query("#test-container"), "Loaded");
+
+    sleep(40).await;
+    assert_text_content!(query("#test-container"), "Loaded");
+}
+
+#[wasm_bindgen_test]
+async fn fallback_is_not_reactive() {
+    let renders = Rc::new(Cell::new(0));
+    let (signal, _root) = create_root_get(|| {
+        let signal = create_signal(0);
+        let renders = renders.clone();
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Suspense(
+                        fallback=move || {
+                            renders.set(renders.get() + 1);
+                            view! { "Loading " (signal.get()) }
+                        },
+                    ) {
+                        ({
+                            create_suspense_task(sleep(20));
+                            view! { "Loaded" }
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+        signal
+    });
+    assert_eq!(renders.get(), 1);
+
+    signal.set(1);
+    assert_eq!(renders.get(), 1);
+
+    sleep(40).await;
+    assert_text_content!(query("#test-container"), "Loaded");
+    assert_eq!(renders.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn timeout_reports_error_to_error_boundary() {
+    let _ = create_root(|| {
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    ErrorBoundary(fallback=|err| view! { p { "Error: " (err.to_string()) } }) {
+                        Suspense(
+                            fallback=|| view! { "Loading" },
+                            timeout=Duration::from_millis(20),
+                        ) {
+                            ({
+                                create_suspense_task(sleep(60));
+                                view! { "Loaded" }
+                            })
+                        }
+                    }
+                }
+            },
+            &test_container(),
+        );
+    });
+    assert_text_content!(query("#test-container"), "Loading");
+
+    sleep(40).await;
+    assert_text_content!(
+        query("#test-container"),
+        "Error: suspense boundary did not resolve within 20ms"
+    );
+}
@@ -15,3 +15,92 @@ fn issue_391_svg_with_class_should_not_use_classname() {
 
     assert_eq!(svg.get_attribute("class").unwrap(), "my-class");
 }
+
+const SVG_NS: &str = "http://www.w3.org/2000/svg";
+const MATHML_NS: &str = "http://www.w3.org/1998/Math/MathML";
+const HTML_NS: &str = "http://www.w3.org/1999/xhtml";
+
+#[wasm_bindgen_test]
+fn svg_children_use_svg_namespace() {
+    sycamore::render_to(
+        || {
+            view! {
+                svg {
+                    a(href="#") { title { "SVG title" } }
+                    foreignObject { p { "HTML" } }
+                }
+            }
+        },
+        &test_container(),
+    );
+
+    assert_eq!(query("svg").namespace_uri().as_deref(), Some(SVG_NS));
+    assert_eq!(query("a").namespace_uri().as_deref(), Some(SVG_NS));
+    assert_eq!(query("title").namespace_uri().as_deref(), Some(SVG_NS));
+    assert_eq!(query("p").namespace_uri().as_deref(), Some(HTML_NS));
+}
+
+#[wasm_bindgen_test]
+fn dynamic_svg_children_use_svg_namespace() {
+    let _ = create_root(|| {
+        let show = create_signal(false);
+        let node = view! {
+            svg {
+                (if show.get() { view! { circle(r="10") } } else { view! {} })
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        show.set(true);
+        assert_eq!(query("circle").namespace_uri().as_deref(), Some(SVG_NS));
+    });
+}
+
+#[wasm_bindgen_test]
+fn nested_view_starts_in_html_namespace() {
+    let _ = create_root(|| {
+        let show = create_signal(false);
+        let node = view! {
+            svg {
+                (if show.get() {
+                    view! {
+                        a(id="html") { title { "HTML" } }
+                        svg_a(id="svg") { svg_title { "SVG" } }
+                    }
+                } else {
+                    view! {}
+                })
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+
+        show.set(true);
+        assert_eq!(query("#html").namespace_uri().as_deref(), Some(HTML_NS));
+        assert_eq!(
+            query("#html > title").namespace_uri().as_deref(),
+            Some(HTML_NS)
+        );
+        assert_eq!(query("#svg").namespace_uri().as_deref(), Some(SVG_NS));
+        assert_eq!(
+            query("#svg > title").namespace_uri().as_deref(),
+            Some(SVG_NS)
+        );
+    });
+}
+
+#[wasm_bindgen_test]
+fn math_children_use_mathml_namespace() {
+    sycamore::render_to(
+        || {
+            view! {
+                math {
+                    mfrac { mi { "x" } mn { "2" } }
+                }
+            }
+        },
+        &test_container(),
+    );
+
+    assert_eq!(query("math").namespace_uri().as_deref(), Some(MATHML_NS));
+    assert_eq!(query("mi").namespace_uri().as_deref(), Some(MATHML_NS));
+}
@@ -0,0 +1,39 @@
+use sycamore::web::{Match, Switch};
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn switch_changes_branch() {
+    let _ = create_root(|| {
+        let state = create_signal(0);
+        let created = create_signal(0);
+        let disposed = create_signal(0);
+        let node = view! {
+            Switch(fallback=|| view! { "fallback" }) {
+                Match(when=move || state.get() >= 1, view=move || {
+                    created.set(created.get_untracked() + 1);
+                    on_cleanup(move || disposed.set(disposed.get_untracked() + 1));
+                    view! { "one" }
+                })
+                Match(when=move || state.get() >= 2, view=|| view! { "two" })
+            }
+        };
+        sycamore::render_in_scope(|| node, &test_container());
+        let p = query("test-container");
+
+        assert_text_content!(p, "fallback");
+        state.set(1);
+        assert_text_content!(p, "one");
+        // The first branch is still selected, so it is not re-created.
+        state.set(2);
+        assert_text_content!(p, "one");
+        assert_eq!(created.get(), 1);
+
+        state.set(0);
+        assert_text_content!(p, "fallback");
+        assert_eq!(disposed.get(), 1);
+        state.set(1);
+        assert_text_content!(p, "one");
+        assert_eq!(created.get(), 2);
+    });
+}
@@ -0,0 +1,100 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use sycamore::web::{use_interval, use_timeout};
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn interval_calls_repeatedly() {
+    let count = Rc::new(Cell::new(0));
+    let ((), root) = create_root_get(|| {
+        let count = Rc::clone(&count);
+        use_interval(Duration::from_millis(10), move || {
+            count.set(count.get() + 1)
+        });
+    });
+    assert_eq!(count.get(), 0);
+
+    sleep(55).await;
+    assert!(
+        count.get() >= 3,
+        "interval was called {} times",
+        count.get()
+    );
+
+    // The interval is cleared when the scope is disposed.
+    root.dispose();
+    let calls = count.get();
+    sleep(30).await;
+    assert_eq!(count.get(), calls);
+}
+
+#[wasm_bindgen_test]
+async fn interval_pause_and_resume() {
+    let ((count, interval), _root) = create_root_get(|| {
+        let count = create_signal(0);
+        let interval = use_interval(Duration::from_millis(10), move || {
+            count.set(count.get() + 1)
+        });
+        (count, interval)
+    });
+    assert!(interval.is_running());
+
+    interval.pause();
+    assert!(!interval.is_running());
+    sleep(30).await;
+    assert_eq!(count.get(), 0);
+
+    interval.resume();
+    assert!(interval.is_running());
+    sleep(35).await;
+    assert!(count.get() >= 1);
+}
+
+#[wasm_bindgen_test]
+async fn interval_restarts_when_period_changes() {
+    let ((count, period), _root) = create_root_get(|| {
+        let count = create_signal(0);
+        let period = create_signal(Duration::from_secs(60));
+        use_interval(*period, move || count.set(count.get() + 1));
+        (count, period)
+    });
+    sleep(20).await;
+    assert_eq!(count.get(), 0);
+
+    period.set(Duration::from_millis(10));
+    sleep(35).await;
+    assert!(count.get() >= 1);
+}
+
+#[wasm_bindgen_test]
+async fn timeout_calls_once() {
+    let (count, _root) = create_root_get(|| {
+        let count = create_signal(0);
+        use_timeout(Duration::from_millis(10), move || {
+            count.set(count.get() + 1)
+        });
+        count
+    });
+    assert_eq!(count.get(), 0);
+
+    sleep(20).await;
+    assert_eq!(count.get(), 1);
+    sleep(20).await;
+    assert_eq!(count.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn timeout_is_cleared_on_dispose() {
+    let called = Rc::new(Cell::new(false));
+    let ((), root) = create_root_get(|| {
+        let called = Rc::clone(&called);
+        use_timeout(Duration::from_millis(10), move || called.set(true));
+    });
+    root.dispose();
+
+    sleep(20).await;
+    assert!(!called.get());
+}
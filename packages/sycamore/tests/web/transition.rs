@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use sycamore::futures::create_suspense_task;
+use sycamore::web::{use_transition_pending, Transition};
+
+use super::*;
+
+/// Renders a [`Transition`] whose children suspend for `load_ms` every time `load` is set to
+/// `true`. Returns the `load` signal and the pending state seen by the children.
+fn render_transition(
+    min_duration: Duration,
+    load_ms: i32,
+) -> (Signal<bool>, ReadSignal<bool>, RootHandle) {
+    let ((load, pending), root) = create_root_get(|| {
+        let load = create_signal(false);
+        let pending = create_signal(None);
+        sycamore::render_in_scope(
+            move || {
+                view! {
+                    Transition(min_duration=min_duration) {
+                        ({
+                            pending.set(Some(use_transition_pending()));
+                            create_effect(move || {
+                                if load.get() {
+                                    create_suspense_task(sleep(load_ms));
+                                }
+                            });
+                            view! {}
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+        (load, pending.get().unwrap())
+    });
+    (load, pending, root)
+}
+
+#[wasm_bindgen_test]
+async fn pending_while_loading() {
+    let (load, pending, _root) = render_transition(Duration::ZERO, 20);
+    sleep(0).await;
+    assert!(!pending.get());
+
+    load.set(true);
+    assert!(pending.get());
+    sleep(40).await;
+    assert!(!pending.get());
+}
+
+#[wasm_bindgen_test]
+async fn not_pending_during_initial_load() {
+    let ((), _root) = create_root_get(|| {
+        sycamore::render_in_scope(
+            || {
+                view! {
+                    Transition(fallback=|| view! { "Loading" }) {
+                        ({
+                            let pending = use_transition_pending();
+                            create_suspense_task(sleep(20));
+                            view! { p { (if pending.get() { "pending" } else { "idle" }) } }
+                        })
+                    }
+                }
+            },
+            &test_container(),
+        );
+    });
+    sleep(40).await;
+    assert_text_content!(query("p"), "idle");
+}
+
+#[wasm_bindgen_test]
+async fn pending_lasts_at_least_min_duration() {
+    let (load, pending, _root) = render_transition(Duration::from_millis(60), 10);
+    sleep(0).await;
+
+    load.set(true);
+    assert!(pending.get());
+    // The content has loaded but the minimum duration has not passed yet.
+    sleep(30).await;
+    assert!(pending.get());
+    sleep(60).await;
+    assert!(!pending.get());
+}
+
+#[wasm_bindgen_test]
+async fn min_duration_timer_is_cancelled_on_cleanup() {
+    let (load, pending, root) = render_transition(Duration::from_millis(30), 0);
+    sleep(0).await;
+
+    load.set(true);
+    sleep(10).await;
+    // The content has loaded and the timer that ends the pending state is running.
+    assert!(pending.get_untracked());
+    root.dispose();
+    // The timer would panic when setting the disposed signal if it was not cancelled.
+    sleep(50).await;
+}
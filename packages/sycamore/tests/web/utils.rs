@@ -1,5 +1,6 @@
 use sycamore::prelude::*;
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
 use web_sys::{Element, HtmlElement};
 
 /// Query the `Document` for the first `Element` that matches the selectors.
@@ -36,6 +37,23 @@ pub(crate) fn query_into<T: AsRef<HtmlElement> + JsCast>(selectors: &str) -> T {
         .expect("element found should be of the same type as used for the generic T")
 }
 
+/// Wait until all the microtasks queued so far have run.
+pub(crate) async fn next_microtask() {
+    JsFuture::from(js_sys::Promise::resolve(&JsValue::UNDEFINED))
+        .await
+        .unwrap();
+}
+
+/// Wait until `ms` milliseconds have passed.
+pub(crate) async fn sleep(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
 /// Asserts that the text content of a `web_sys::Node` is equal to the
 /// right expression.
 macro_rules! assert_text_content {
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use sycamore::web::{use_websocket, use_websocket_with_options, WebSocketOptions, WsState};
+use wasm_bindgen::prelude::*;
+
+use super::*;
+
+#[wasm_bindgen(inline_js = r#"
+export function install_mock_websocket() {
+    const sockets = [];
+    globalThis.WebSocket = class {
+        constructor(url) {
+            this.url = url;
+            this.readyState = 0;
+            this.sent = [];
+            sockets.push(this);
+        }
+        send(data) {
+            this.sent.push(data);
+        }
+        close() {
+            this.readyState = 3;
+        }
+        open() {
+            this.readyState = 1;
+            if (this.onopen) this.onopen(new Event("open"));
+        }
+        receive(data) {
+            if (this.onmessage) this.onmessage(new MessageEvent("message", { data }));
+        }
+        serverClose() {
+            this.readyState = 3;
+            if (this.onclose) this.onclose(new CloseEvent("close"));
+        }
+    };
+    return sockets;
+}
+"#)]
+extern "C" {
+    /// A fake `WebSocket` that is controlled by the test instead of a server.
+    type MockWebSocket;
+
+    /// Replaces the global `WebSocket` class with [`MockWebSocket`]. Returns the array that every
+    /// socket created afterwards is pushed to.
+    fn install_mock_websocket() -> js_sys::Array;
+
+    #[wasm_bindgen(method, getter)]
+    fn url(this: &MockWebSocket) -> String;
+    #[wasm_bindgen(method, getter, js_name = readyState)]
+    fn ready_state(this: &MockWebSocket) -> u16;
+    #[wasm_bindgen(method, getter)]
+    fn sent(this: &MockWebSocket) -> js_sys::Array;
+    #[wasm_bindgen(method)]
+    fn open(this: &MockWebSocket);
+    #[wasm_bindgen(method)]
+    fn receive(this: &MockWebSocket, data: &JsValue);
+    #[wasm_bindgen(method, js_name = serverClose)]
+    fn server_close(this: &MockWebSocket);
+}
+
+fn socket(sockets: &js_sys::Array, index: u32) -> MockWebSocket {
+    sockets.get(index).unchecked_into()
+}
+
+#[wasm_bindgen_test]
+fn connects_and_receives_messages() {
+    let sockets = install_mock_websocket();
+    let _ = create_root(|| {
+        let ws = use_websocket("ws://localhost/chat");
+        assert_eq!(ws.state.get(), WsState::Connecting);
+        assert_eq!(sockets.length(), 1);
+        let socket = socket(&sockets, 0);
+        assert_eq!(socket.url(), "ws://localhost/chat");
+
+        socket.open();
+        assert_eq!(ws.state.get(), WsState::Open);
+
+        socket.receive(&"Hello".into());
+        assert_eq!(ws.message.get_clone().as_deref(), Some("Hello"));
+        // Binary messages are ignored.
+        socket.receive(&js_sys::Uint8Array::new_with_length(1));
+        assert_eq!(ws.message.get_clone().as_deref(), Some("Hello"));
+
+        socket.server_close();
+        assert_eq!(ws.state.get(), WsState::Closed);
+    });
+}
+
+#[wasm_bindgen_test]
+fn send_only_while_open() {
+    let sockets = install_mock_websocket();
+    let _ = create_root(|| {
+        let ws = use_websocket("ws://localhost/chat");
+        let socket = socket(&sockets, 0);
+
+        // The message is dropped while connecting.
+        ws.send("dropped");
+        assert_eq!(socket.sent().length(), 0);
+
+        socket.open();
+        ws.send("Hi");
+        assert_eq!(socket.sent().to_vec(), [JsValue::from("Hi")]);
+    });
+}
+
+#[wasm_bindgen_test]
+fn closes_on_dispose() {
+    let sockets = install_mock_websocket();
+    let root = create_root(|| {
+        let ws = use_websocket("ws://localhost/chat");
+        socket(&sockets, 0).open();
+        assert_eq!(ws.state.get(), WsState::Open);
+    });
+    let socket = socket(&sockets, 0);
+
+    root.dispose();
+    assert_eq!(socket.ready_state(), 3);
+    // The event handlers were removed, so this does not update the disposed signals.
+    socket.server_close();
+}
+
+#[wasm_bindgen_test]
+async fn does_not_reconnect_by_default() {
+    let sockets = install_mock_websocket();
+    let (ws, _root) = create_root_get(|| use_websocket("ws://localhost/chat"));
+    socket(&sockets, 0).server_close();
+    assert_eq!(ws.state.get(), WsState::Closed);
+
+    sleep(30).await;
+    assert_eq!(sockets.length(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn reconnects_with_backoff() {
+    let sockets = install_mock_websocket();
+    let options = WebSocketOptions {
+        reconnect: true,
+        reconnect_delay: Duration::from_millis(10),
+        max_reconnect_delay: Duration::from_millis(40),
+    };
+    let (ws, root) = create_root_get(|| use_websocket_with_options("ws://localhost/chat", options));
+    socket(&sockets, 0).open();
+    socket(&sockets, 0).server_close();
+    assert_eq!(ws.state.get(), WsState::Closed);
+
+    // The first attempt happens after `reconnect_delay`.
+    sleep(20).await;
+    assert_eq!(sockets.length(), 2);
+    assert_eq!(ws.state.get(), WsState::Connecting);
+
+    // The delay is doubled after a failed attempt.
+    socket(&sockets, 1).server_close();
+    sleep(10).await;
+    assert_eq!(sockets.length(), 2);
+    sleep(20).await;
+    assert_eq!(sockets.length(), 3);
+
+    socket(&sockets, 2).open();
+    assert_eq!(ws.state.get(), WsState::Open);
+
+    // No reconnection attempts are made once the scope is disposed.
+    socket(&sockets, 2).server_close();
+    root.dispose();
+    sleep(30).await;
+    assert_eq!(sockets.length(), 3);
+}
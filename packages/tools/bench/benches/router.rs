@@ -0,0 +1,167 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use sycamore_router::Route;
+
+#[derive(Route)]
+#[allow(dead_code)]
+enum Routes {
+    #[to("/section0")]
+    Section0,
+    #[to("/section0/<id>")]
+    Section0Item(u32),
+    #[to("/section0/<id>/edit")]
+    Section0Edit(u32),
+    #[to("/section1")]
+    Section1,
+    #[to("/section1/<id>")]
+    Section1Item(u32),
+    #[to("/section1/<id>/edit")]
+    Section1Edit(u32),
+    #[to("/section2")]
+    Section2,
+    #[to("/section2/<id>")]
+    Section2Item(u32),
+    #[to("/section2/<id>/edit")]
+    Section2Edit(u32),
+    #[to("/section3")]
+    Section3,
+    #[to("/section3/<id>")]
+    Section3Item(u32),
+    #[to("/section3/<id>/edit")]
+    Section3Edit(u32),
+    #[to("/section4")]
+    Section4,
+    #[to("/section4/<id>")]
+    Section4Item(u32),
+    #[to("/section4/<id>/edit")]
+    Section4Edit(u32),
+    #[to("/section5")]
+    Section5,
+    #[to("/section5/<id>")]
+    Section5Item(u32),
+    #[to("/section5/<id>/edit")]
+    Section5Edit(u32),
+    #[to("/section6")]
+    Section6,
+    #[to("/section6/<id>")]
+    Section6Item(u32),
+    #[to("/section6/<id>/edit")]
+    Section6Edit(u32),
+    #[to("/section7")]
+    Section7,
+    #[to("/section7/<id>")]
+    Section7Item(u32),
+    #[to("/section7/<id>/edit")]
+    Section7Edit(u32),
+    #[to("/section8")]
+    Section8,
+    #[to("/section8/<id>")]
+    Section8Item(u32),
+    #[to("/section8/<id>/edit")]
+    Section8Edit(u32),
+    #[to("/section9")]
+    Section9,
+    #[to("/section9/<id>")]
+    Section9Item(u32),
+    #[to("/section9/<id>/edit")]
+    Section9Edit(u32),
+    #[to("/section10")]
+    Section10,
+    #[to("/section10/<id>")]
+    Section10Item(u32),
+    #[to("/section10/<id>/edit")]
+    Section10Edit(u32),
+    #[to("/section11")]
+    Section11,
+    #[to("/section11/<id>")]
+    Section11Item(u32),
+    #[to("/section11/<id>/edit")]
+    Section11Edit(u32),
+    #[to("/section12")]
+    Section12,
+    #[to("/section12/<id>")]
+    Section12Item(u32),
+    #[to("/section12/<id>/edit")]
+    Section12Edit(u32),
+    #[to("/section13")]
+    Section13,
+    #[to("/section13/<id>")]
+    Section13Item(u32),
+    #[to("/section13/<id>/edit")]
+    Section13Edit(u32),
+    #[to("/section14")]
+    Section14,
+    #[to("/section14/<id>")]
+    Section14Item(u32),
+    #[to("/section14/<id>/edit")]
+    Section14Edit(u32),
+    #[to("/section15")]
+    Section15,
+    #[to("/section15/<id>")]
+    Section15Item(u32),
+    #[to("/section15/<id>/edit")]
+    Section15Edit(u32),
+    #[to("/section16")]
+    Section16,
+    #[to("/section16/<id>")]
+    Section16Item(u32),
+    #[to("/section16/<id>/edit")]
+    Section16Edit(u32),
+    #[to("/section17")]
+    Section17,
+    #[to("/section17/<id>")]
+    Section17Item(u32),
+    #[to("/section17/<id>/edit")]
+    Section17Edit(u32),
+    #[to("/section18")]
+    Section18,
+    #[to("/section18/<id>")]
+    Section18Item(u32),
+    #[to("/section18/<id>/edit")]
+    Section18Edit(u32),
+    #[to("/section19")]
+    Section19,
+    #[to("/section19/<id>")]
+    Section19Item(u32),
+    #[to("/section19/<id>/edit")]
+    Section19Edit(u32),
+    #[not_found]
+    NotFound,
+}
+
+/// Generates `n` pseudo-random paths that match the routes above (and a few that do not).
+fn random_paths(n: usize) -> Vec<String> {
+    // Simple LCG so that the benchmark is deterministic and does not need extra dependencies.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as u32
+    };
+    (0..n)
+        .map(|_| {
+            let section = next() % 21; // Section 20 does not exist.
+            let id = next() % 1000;
+            match next() % 3 {
+                0 => format!("/section{section}"),
+                1 => format!("/section{section}/{id}"),
+                _ => format!("/section{section}/{id}/edit"),
+            }
+        })
+        .collect()
+}
+
+pub fn bench(c: &mut Criterion) {
+    let paths = random_paths(1000);
+
+    c.bench_function("router_match_path", |b| {
+        b.iter(|| {
+            for path in &paths {
+                black_box(Routes::default().match_path(path));
+            }
+        });
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);